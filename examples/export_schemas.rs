@@ -0,0 +1,19 @@
+//! Regenerate the published JSON Schema files
+//!
+//! Usage: `cargo run --example export_schemas [output-dir]` (defaults to `schemas/`)
+
+use opentrustprotocol::schema::{published_schemas, write_schema_files};
+use std::path::PathBuf;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("schemas"));
+
+    write_schema_files(&dir)?;
+    for (name, _) in published_schemas() {
+        println!("wrote {}", dir.join(name).display());
+    }
+    Ok(())
+}
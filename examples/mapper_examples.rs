@@ -26,6 +26,8 @@ fn create_base_params(id: &str, mapper_type: MapperType) -> BaseMapperParams {
             );
             metadata
         }),
+        input_path: None,
+        deprecated: false,
     }
 }
 
@@ -333,16 +335,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         } else {
             "❌ Invalid"
         };
-        println!(
-            "{}. {} - {}",
-            i + 1,
-            match config {
-                opentrustprotocol::MapperParams::Numerical(p) => &p.base.id,
-                opentrustprotocol::MapperParams::Categorical(p) => &p.base.id,
-                opentrustprotocol::MapperParams::Boolean(p) => &p.base.id,
-            },
-            status
-        );
+        println!("{}. {} - {}", i + 1, config.id(), status);
 
         if !result.valid {
             for error in &result.errors {
@@ -0,0 +1,221 @@
+#ifndef OPENTRUSTPROTOCOL_H
+#define OPENTRUSTPROTOCOL_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Status codes returned by every `otp_*` function
+//
+// Values are part of the ABI and never change meaning; new codes may be
+// added.
+typedef enum OtpStatus {
+  // Success
+  OTP_STATUS_OK = 0,
+  // A required pointer argument was null
+  OTP_STATUS_NULL_POINTER = 1,
+  // A string argument was not valid UTF-8
+  OTP_STATUS_INVALID_UTF8 = 2,
+  // A JSON argument could not be parsed
+  OTP_STATUS_INVALID_JSON = 3,
+  // T, I or F out of range, or T + I + F > 1
+  OTP_STATUS_INVALID_JUDGMENT = 4,
+  // The provenance chain is empty or has an invalid entry
+  OTP_STATUS_INVALID_PROVENANCE = 5,
+  // Fusion inputs are invalid (empty list, weight mismatch, zero weights)
+  OTP_STATUS_INVALID_FUSION_INPUT = 6,
+  // The fusion operator ID is not supported
+  OTP_STATUS_UNKNOWN_OPERATOR = 7,
+  // The fused judgment has no conformance seal
+  OTP_STATUS_MISSING_SEAL = 8,
+  // The conformance seal does not match the inputs
+  OTP_STATUS_SEAL_MISMATCH = 9,
+  // No mapper is registered under the requested ID
+  OTP_STATUS_MAPPER_NOT_FOUND = 10,
+  // The mapper rejected its input
+  OTP_STATUS_INVALID_MAPPER_INPUT = 11,
+  // A mapper configuration is invalid or duplicated
+  OTP_STATUS_INVALID_MAPPER_CONFIG = 12,
+  // A file could not be read
+  OTP_STATUS_IO = 13,
+  // A Rust panic was caught at the boundary
+  OTP_STATUS_PANIC = 98,
+  // Any other error
+  OTP_STATUS_OTHER = 99,
+} OtpStatus;
+
+// Opaque handle to a [`NeutrosophicJudgment`]
+typedef struct OtpJudgment OtpJudgment;
+
+// Opaque handle to a mapper registry
+typedef struct OtpRegistry OtpRegistry;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Copy the message of the last error on this thread into `buffer`
+//
+// Writes at most `buffer_len` bytes including the terminating NUL, truncating
+// if needed, and returns the full message length plus one (the buffer size
+// needed), or 0 if the last call on this thread succeeded. `buffer` may be
+// null to query the length.
+//
+// # Safety
+// `buffer` must be null or valid for `buffer_len` bytes of writes.
+size_t otp_last_error_message(char *buffer, size_t buffer_len);
+
+// Static, NUL-terminated name of a status code (e.g. `"OTP_STATUS_SEAL_MISMATCH"`)
+//
+// Takes the code as a plain `int`, so any value is safe to pass; codes this
+// library does not define yield `"OTP_STATUS_UNKNOWN"`.
+const char *otp_status_name(int status);
+
+// Release a string returned by this library
+//
+// # Safety
+// `string` must be null or a string returned through a `char **out`
+// parameter of this library, not yet freed.
+void otp_string_free(char *string);
+
+// Create a judgment with a single provenance entry timestamped now
+//
+// # Safety
+// `source_id` must be a NUL-terminated string and `out` valid for writes.
+enum OtpStatus otp_judgment_new(double t,
+                                double i,
+                                double f,
+                                const char *source_id,
+                                struct OtpJudgment **out);
+
+// Parse and validate a judgment from JSON
+//
+// # Safety
+// `json` must be a NUL-terminated string and `out` valid for writes.
+enum OtpStatus otp_judgment_from_json(const char *json, struct OtpJudgment **out);
+
+// Serialize a judgment as JSON; free the result with [`otp_string_free`]
+//
+// # Safety
+// `judgment` must be a valid handle and `out` valid for writes.
+enum OtpStatus otp_judgment_to_json(const struct OtpJudgment *judgment, char **out);
+
+// Read the T, I and F components of a judgment
+//
+// # Safety
+// `judgment` must be a valid handle and `t`, `i`, `f` valid for writes.
+enum OtpStatus otp_judgment_components(const struct OtpJudgment *judgment,
+                                       double *t,
+                                       double *i,
+                                       double *f);
+
+// Release a judgment handle
+//
+// # Safety
+// `judgment` must be null or a handle returned by this library, not yet freed.
+void otp_judgment_free(struct OtpJudgment *judgment);
+
+// Compute the judgment ID; free the result with [`otp_string_free`]
+//
+// # Safety
+// `judgment` must be a valid handle and `out` valid for writes.
+enum OtpStatus otp_judgment_id(const struct OtpJudgment *judgment, char **out);
+
+// Conflict-aware weighted average of `count` judgments
+//
+// # Safety
+// `judgments` and `weights` must point to `count` elements and `out` must
+// be valid for writes.
+enum OtpStatus otp_fuse_cawa(const struct OtpJudgment *const *judgments,
+                             const double *weights,
+                             size_t count,
+                             struct OtpJudgment **out);
+
+// Optimistic fusion of `count` judgments
+//
+// # Safety
+// `judgments` must point to `count` handles and `out` must be valid for writes.
+enum OtpStatus otp_fuse_optimistic(const struct OtpJudgment *const *judgments,
+                                   size_t count,
+                                   struct OtpJudgment **out);
+
+// Pessimistic fusion of `count` judgments
+//
+// # Safety
+// `judgments` must point to `count` handles and `out` must be valid for writes.
+enum OtpStatus otp_fuse_pessimistic(const struct OtpJudgment *const *judgments,
+                                    size_t count,
+                                    struct OtpJudgment **out);
+
+// Generate the conformance seal for fusing `count` judgments with `operator_id`
+//
+// `weights` may be null for unweighted operators (all weights 1.0). Free the
+// result with [`otp_string_free`].
+//
+// # Safety
+// `judgments` (and `weights` unless null) must point to `count` elements,
+// `operator_id` must be a NUL-terminated string and `out` valid for writes.
+enum OtpStatus otp_generate_conformance_seal(const struct OtpJudgment *const *judgments,
+                                             const double *weights,
+                                             size_t count,
+                                             const char *operator_id,
+                                             char **out);
+
+// Verify a fused judgment's conformance seal against its `count` inputs
+//
+// Returns `OTP_STATUS_OK` if the seal matches and `OTP_STATUS_SEAL_MISMATCH` if it does
+// not. `weights` may be null to use the weights recorded in the fusion
+// metadata.
+//
+// # Safety
+// `fused` must be a valid handle; `inputs` (and `weights` unless null) must
+// point to `count` elements.
+enum OtpStatus otp_verify_conformance_seal(const struct OtpJudgment *fused,
+                                           const struct OtpJudgment *const *inputs,
+                                           const double *weights,
+                                           size_t count);
+
+// Create an empty mapper registry
+//
+// # Safety
+// `out` must be valid for writes.
+enum OtpStatus otp_registry_new(struct OtpRegistry **out);
+
+// Release a registry handle
+//
+// # Safety
+// `registry` must be null or a handle returned by [`otp_registry_new`], not
+// yet freed.
+void otp_registry_free(struct OtpRegistry *registry);
+
+// Register mappers from JSON: one mapper configuration or an array of them
+//
+// # Safety
+// `registry` must be a valid handle and `json` a NUL-terminated string.
+enum OtpStatus otp_registry_load_json(const struct OtpRegistry *registry, const char *json);
+
+// Register every mapper configuration file in a directory
+//
+// # Safety
+// `registry` must be a valid handle and `dir` a NUL-terminated path.
+enum OtpStatus otp_registry_load_dir(const struct OtpRegistry *registry, const char *dir);
+
+// Apply a registered mapper (`id` or `id@version`) to a JSON input
+//
+// # Safety
+// `registry` must be a valid handle, `mapper_id` and `input_json`
+// NUL-terminated strings and `out` valid for writes.
+enum OtpStatus otp_registry_apply(const struct OtpRegistry *registry,
+                                  const char *mapper_id,
+                                  const char *input_json,
+                                  struct OtpJudgment **out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* OPENTRUSTPROTOCOL_H */
@@ -0,0 +1,398 @@
+//! Neutrosophic algebra on judgments
+//!
+//! Set operators on [`NeutrosophicJudgment`]: complement, union and
+//! intersection under a selectable [`NormFamily`], inclusion, and scalar
+//! multiplication and power. Every derived judgment carries the provenance
+//! of its operands plus one sealed entry for the operation, recording the
+//! operand judgment IDs, so compositions such as "A and not B" stay
+//! auditable:
+//!
+//! ```rust
+//! use opentrustprotocol::{NeutrosophicJudgment, NormFamily};
+//!
+//! let a = NeutrosophicJudgment::new(0.8, 0.1, 0.1, vec![("kyc".to_string(), "2023-01-01T00:00:00Z".to_string())])?;
+//! let b = NeutrosophicJudgment::new(0.2, 0.1, 0.6, vec![("fraud".to_string(), "2023-01-01T00:00:00Z".to_string())])?;
+//!
+//! let a_and_not_b = a.intersection(&b.complement()?, NormFamily::Minimum)?;
+//! assert_eq!(a_and_not_b.t, 0.6);
+//! assert_eq!(a_and_not_b.provenance_chain.last().unwrap().source_id, "otp-intersection-minimum-v1");
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+//!
+//! Union and intersection follow the usual single-valued neutrosophic
+//! definitions (T combined with the t-conorm / t-norm, I and F with the
+//! dual). These and the power operator can leave T + I + F above 1; such
+//! results are scaled down proportionally and the operation entry records
+//! it under `normalization`, as the point fusion operators do.
+
+use crate::conformance::{create_fusion_provenance_entry, generate_conformance_seal};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::note_normalization;
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::{ensure_judgment_id, generate_judgment_id};
+use crate::paraconsistent::Normalization;
+use serde::{Deserialize, Serialize};
+
+/// Operator ID recorded by [`NeutrosophicJudgment::complement`]
+pub const COMPLEMENT_OPERATOR_ID: &str = "otp-complement-v1";
+/// Operator ID recorded by [`NeutrosophicJudgment::scale`]
+pub const SCALE_OPERATOR_ID: &str = "otp-scale-v1";
+/// Operator ID recorded by [`NeutrosophicJudgment::power`]
+pub const POWER_OPERATOR_ID: &str = "otp-power-v1";
+
+/// A t-norm and its dual t-conorm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormFamily {
+    /// Gödel: min(a, b) / max(a, b)
+    #[default]
+    Minimum,
+    /// Algebraic: a * b / a + b - a * b
+    Product,
+    /// Łukasiewicz: max(0, a + b - 1) / min(1, a + b)
+    Lukasiewicz,
+}
+
+impl NormFamily {
+    /// Name used in operator IDs and provenance
+    pub fn name(self) -> &'static str {
+        match self {
+            NormFamily::Minimum => "minimum",
+            NormFamily::Product => "product",
+            NormFamily::Lukasiewicz => "lukasiewicz",
+        }
+    }
+
+    /// The t-norm (conjunction)
+    pub fn t_norm(self, a: f64, b: f64) -> f64 {
+        match self {
+            NormFamily::Minimum => a.min(b),
+            NormFamily::Product => a * b,
+            NormFamily::Lukasiewicz => (a + b - 1.0).max(0.0),
+        }
+    }
+
+    /// The t-conorm (disjunction)
+    pub fn t_conorm(self, a: f64, b: f64) -> f64 {
+        match self {
+            NormFamily::Minimum => a.max(b),
+            NormFamily::Product => a + b - a * b,
+            NormFamily::Lukasiewicz => (a + b).min(1.0),
+        }
+    }
+}
+
+/// Operator ID recorded by [`NeutrosophicJudgment::union`] under `family`
+pub fn union_operator_id(family: NormFamily) -> String {
+    format!("otp-union-{}-v1", family.name())
+}
+
+/// Operator ID recorded by [`NeutrosophicJudgment::intersection`] under `family`
+pub fn intersection_operator_id(family: NormFamily) -> String {
+    format!("otp-intersection-{}-v1", family.name())
+}
+
+impl NeutrosophicJudgment {
+    /// Negation: swaps truth and falsity, (T, I, F) -> (F, I, T)
+    ///
+    /// This keeps T + I + F unchanged, so the result is always a valid
+    /// judgment, and applying it twice gives back the original components.
+    pub fn complement(&self) -> Result<NeutrosophicJudgment> {
+        derive(
+            COMPLEMENT_OPERATOR_ID,
+            "complement",
+            &[self],
+            &[1.0],
+            serde_json::Map::new(),
+            (self.f, self.i, self.t),
+        )
+    }
+
+    /// Union (disjunction): T = S(Ta, Tb), I = N(Ia, Ib), F = N(Fa, Fb)
+    ///
+    /// N is the t-norm and S the t-conorm of `family`.
+    pub fn union(
+        &self,
+        other: &NeutrosophicJudgment,
+        family: NormFamily,
+    ) -> Result<NeutrosophicJudgment> {
+        let mut details = serde_json::Map::new();
+        details.insert("norm".to_string(), family.name().into());
+        derive(
+            &union_operator_id(family),
+            "union",
+            &[self, other],
+            &[1.0, 1.0],
+            details,
+            (
+                family.t_conorm(self.t, other.t),
+                family.t_norm(self.i, other.i),
+                family.t_norm(self.f, other.f),
+            ),
+        )
+    }
+
+    /// Intersection (conjunction): T = N(Ta, Tb), I = S(Ia, Ib), F = S(Fa, Fb)
+    ///
+    /// N is the t-norm and S the t-conorm of `family`.
+    pub fn intersection(
+        &self,
+        other: &NeutrosophicJudgment,
+        family: NormFamily,
+    ) -> Result<NeutrosophicJudgment> {
+        let mut details = serde_json::Map::new();
+        details.insert("norm".to_string(), family.name().into());
+        derive(
+            &intersection_operator_id(family),
+            "intersection",
+            &[self, other],
+            &[1.0, 1.0],
+            details,
+            (
+                family.t_norm(self.t, other.t),
+                family.t_conorm(self.i, other.i),
+                family.t_conorm(self.f, other.f),
+            ),
+        )
+    }
+
+    /// Inclusion: Ta <= Tb, Ia >= Ib and Fa >= Fb
+    ///
+    /// A pure test; no judgment is derived and nothing is recorded.
+    pub fn is_subset_of(&self, other: &NeutrosophicJudgment) -> bool {
+        self.t <= other.t && self.i >= other.i && self.f >= other.f
+    }
+
+    /// Scalar multiplication n·A: (1 - (1 - T)^n, I^n, F^n)
+    ///
+    /// `n` must be finite and positive. For n > 1 the judgment moves towards
+    /// truth, for n < 1 away from it.
+    pub fn scale(&self, n: f64) -> Result<NeutrosophicJudgment> {
+        check_scalar(n)?;
+        derive(
+            SCALE_OPERATOR_ID,
+            "scale",
+            &[self],
+            &[n],
+            scalar_details(n),
+            (1.0 - (1.0 - self.t).powf(n), self.i.powf(n), self.f.powf(n)),
+        )
+    }
+
+    /// Power A^n: (T^n, 1 - (1 - I)^n, 1 - (1 - F)^n)
+    ///
+    /// `n` must be finite and positive. For n > 1 the judgment moves away
+    /// from truth, for n < 1 towards it.
+    pub fn power(&self, n: f64) -> Result<NeutrosophicJudgment> {
+        check_scalar(n)?;
+        derive(
+            POWER_OPERATOR_ID,
+            "power",
+            &[self],
+            &[n],
+            scalar_details(n),
+            (
+                self.t.powf(n),
+                1.0 - (1.0 - self.i).powf(n),
+                1.0 - (1.0 - self.f).powf(n),
+            ),
+        )
+    }
+}
+
+fn check_scalar(n: f64) -> Result<()> {
+    if n.is_finite() && n > 0.0 {
+        Ok(())
+    } else {
+        Err(OpenTrustError::InvalidValue {
+            field: "scalar".to_string(),
+            value: n,
+            message: "Scalar must be finite and positive".to_string(),
+        })
+    }
+}
+
+fn scalar_details(n: f64) -> serde_json::Map<String, serde_json::Value> {
+    let mut details = serde_json::Map::new();
+    details.insert("scalar".to_string(), n.into());
+    details
+}
+
+/// Builds a derived judgment: operand provenance plus one sealed operation
+/// entry, normalized if needed, with its judgment ID
+///
+/// The seal covers the operands with `weights` (the scalar for scalar
+/// operations, 1.0 otherwise), so it can be checked with
+/// [`verify_conformance_seal_with_inputs`](crate::verify_conformance_seal_with_inputs).
+fn derive(
+    operator: &str,
+    operation: &str,
+    operands: &[&NeutrosophicJudgment],
+    weights: &[f64],
+    details: serde_json::Map<String, serde_json::Value>,
+    (t, i, f): (f64, f64, f64),
+) -> Result<NeutrosophicJudgment> {
+    let operand_ids = operands
+        .iter()
+        .map(|operand| match &operand.judgment_id {
+            Some(id) => Ok(serde_json::Value::from(id.as_str())),
+            None => generate_judgment_id(operand).map(serde_json::Value::from),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut metadata = serde_json::Map::new();
+    metadata.insert("operator".to_string(), operator.into());
+    metadata.insert("operation".to_string(), operation.into());
+    metadata.insert("operands".to_string(), operand_ids.into());
+    metadata.extend(details);
+
+    let seal = generate_conformance_seal(operands, weights, operator)?;
+    let mut entry = create_fusion_provenance_entry(
+        operator,
+        &chrono::Utc::now().to_rfc3339(),
+        &seal,
+        Some(format!("Neutrosophic {} using {}", operation, operator)),
+        Some(serde_json::Value::Object(metadata)),
+    );
+
+    let total = t + i + f;
+    if total > 1.0 {
+        note_normalization(&mut entry, Normalization::Proportional.name(), total);
+    }
+    let (t, i, f) = Normalization::Proportional.apply(t, i, f);
+
+    let mut provenance = Vec::new();
+    for operand in operands {
+        provenance.extend(operand.provenance_chain.clone());
+    }
+    provenance.push(entry);
+
+    ensure_judgment_id(NeutrosophicJudgment::new_with_entries(t, i, f, provenance)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+    use crate::judgment::test_support::judgment;
+
+    #[test]
+    fn test_norm_families() {
+        for family in [
+            NormFamily::Minimum,
+            NormFamily::Product,
+            NormFamily::Lukasiewicz,
+        ] {
+            // Boundary conditions of every t-norm / t-conorm
+            assert_eq!(family.t_norm(0.7, 1.0), 0.7);
+            assert_eq!(family.t_conorm(0.7, 0.0), 0.7);
+            assert!(family.t_norm(0.4, 0.6) <= family.t_conorm(0.4, 0.6));
+        }
+        assert_eq!(NormFamily::Product.t_norm(0.5, 0.5), 0.25);
+        assert_eq!(NormFamily::Lukasiewicz.t_norm(0.5, 0.4), 0.0);
+        assert_eq!(NormFamily::Lukasiewicz.t_conorm(0.5, 0.7), 1.0);
+    }
+
+    #[test]
+    fn test_complement_and_inclusion() {
+        let a = judgment(0.7, 0.2, 0.1, "a");
+        let not_a = a.complement().unwrap();
+        assert_eq!((not_a.t, not_a.i, not_a.f), (0.1, 0.2, 0.7));
+        let back = not_a.complement().unwrap();
+        assert_eq!((back.t, back.i, back.f), (a.t, a.i, a.f));
+        assert_eq!(back.provenance_chain.len(), 3);
+
+        assert!(not_a.is_subset_of(&a));
+        assert!(!a.is_subset_of(&not_a));
+        assert!(a.is_subset_of(&a));
+    }
+
+    #[test]
+    fn test_union_intersection_record_operation() {
+        let a = judgment(0.8, 0.1, 0.1, "kyc");
+        let b = judgment(0.2, 0.1, 0.6, "fraud");
+
+        let union = a.union(&b, NormFamily::Product).unwrap();
+        assert!((union.t - 0.84).abs() < 1e-12);
+        assert!((union.f - 0.06).abs() < 1e-12);
+        let entry = union.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, "otp-union-product-v1");
+        let metadata = entry.metadata.as_ref().unwrap();
+        assert_eq!(metadata["norm"], "product");
+        assert_eq!(
+            metadata["operands"],
+            serde_json::json!([
+                generate_judgment_id(&a).unwrap(),
+                generate_judgment_id(&b).unwrap()
+            ])
+        );
+        assert!(verify_conformance_seal_with_inputs(&union, &[&a, &b], &[1.0, 1.0]).unwrap());
+        assert!(a.is_subset_of(&union) && b.is_subset_of(&union));
+
+        let intersection = a.intersection(&b, NormFamily::Minimum).unwrap();
+        assert_eq!((intersection.t, intersection.f), (0.2, 0.6));
+        assert!(intersection.is_subset_of(&a) && intersection.is_subset_of(&b));
+
+        // "A and not B" references the ID of "not B"
+        let not_b = b.complement().unwrap();
+        let a_and_not_b = a.intersection(&not_b, NormFamily::Minimum).unwrap();
+        let metadata = a_and_not_b
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .clone()
+            .unwrap();
+        assert_eq!(
+            metadata["operands"][1],
+            serde_json::json!(not_b.judgment_id)
+        );
+    }
+
+    #[test]
+    fn test_intersection_normalizes_excess() {
+        let a = judgment(0.5, 0.5, 0.0, "a");
+        let b = judgment(0.5, 0.0, 0.5, "b");
+        // min T 0.5 + max I 0.5 + max F 0.5 = 1.5
+        let both = a.intersection(&b, NormFamily::Minimum).unwrap();
+        assert!(both.total() <= 1.0);
+        let metadata = both
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .clone()
+            .unwrap();
+        assert_eq!(metadata["normalization"]["sum"], 1.5);
+    }
+
+    #[test]
+    fn test_scalar_operations() {
+        let a = judgment(0.5, 0.2, 0.1, "a");
+
+        let doubled = a.scale(2.0).unwrap();
+        assert!((doubled.t - 0.75).abs() < 1e-12);
+        assert!((doubled.i - 0.04).abs() < 1e-12);
+        assert!(a.is_subset_of(&doubled));
+        assert!(verify_conformance_seal_with_inputs(&doubled, &[&a], &[2.0]).unwrap());
+
+        let squared = a.power(2.0).unwrap();
+        assert!((squared.t - 0.25).abs() < 1e-12);
+        assert!(squared.is_subset_of(&a));
+        assert_eq!(
+            squared
+                .provenance_chain
+                .last()
+                .unwrap()
+                .metadata
+                .as_ref()
+                .unwrap()["scalar"],
+            2.0
+        );
+
+        let identity = a.scale(1.0).unwrap();
+        assert_eq!((identity.t, identity.i, identity.f), (a.t, a.i, a.f));
+        assert!(a.scale(0.0).is_err());
+        assert!(a.power(f64::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+//! `otp-server` — local HTTP service for OpenTrust Protocol judgments
+//!
+//! Serves the endpoints documented in [`opentrustprotocol::server`] on a
+//! loopback address, backed by an in-memory mapper registry and judgment
+//! store.
+//!
+//! ```text
+//! otp-server [--addr 127.0.0.1:8787] [--mappers <dir>] [--threads <n>]
+//! ```
+
+use opentrustprotocol::mapper::registry::DefaultMapperRegistry;
+use opentrustprotocol::server::{HttpServer, Service};
+use opentrustprotocol::MapperRegistry;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+const USAGE: &str = "usage: otp-server [--addr 127.0.0.1:8787] [--mappers <dir>] [--threads <n>]";
+
+struct Options {
+    addr: SocketAddr,
+    mappers: Option<PathBuf>,
+    threads: usize,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        addr: SocketAddr::from(([127, 0, 0, 1], 8787)),
+        mappers: None,
+        threads: 4,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} requires a value", name));
+        match arg.as_str() {
+            "--addr" => {
+                let raw = value("--addr")?;
+                options.addr = raw
+                    .parse()
+                    .map_err(|e| format!("invalid address '{}': {}", raw, e))?;
+            }
+            "--mappers" => options.mappers = Some(PathBuf::from(value("--mappers")?)),
+            "--threads" => {
+                let raw = value("--threads")?;
+                options.threads = raw
+                    .parse()
+                    .map_err(|e| format!("invalid thread count '{}': {}", raw, e))?;
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            other => return Err(format!("unexpected argument '{}'\n{}", other, USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    let registry = DefaultMapperRegistry::new();
+    if let Some(dir) = &options.mappers {
+        match registry.import_dir(dir) {
+            Ok(count) => eprintln!("loaded {} mapper(s) from {}", count, dir.display()),
+            Err(e) => {
+                eprintln!("otp-server: {}", e);
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let service = Arc::new(Service::new(Arc::new(registry)));
+    let server = match HttpServer::bind(options.addr, service) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("otp-server: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(addr) = server.local_addr() {
+        eprintln!("listening on http://{}", addr);
+    }
+    server.run(options.threads);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,367 @@
+//! `otp` — command-line tool for OpenTrust Protocol judgments
+//!
+//! Works on JSON files holding one judgment, a JSON array of judgments, or
+//! JSONL (one judgment per line). Use `-` to read from standard input.
+//!
+//! Exit status is 0 on success, 1 when a check fails (seal or ID mismatch)
+//! and 2 on any other error.
+
+use clap::{Parser, Subcommand};
+use opentrustprotocol::mapper::registry::DefaultMapperRegistry;
+use opentrustprotocol::{
+    check_conformance_seal_with_inputs, fuse, generate_judgment_id, load_mapper_file,
+    recorded_seal_weights, MapperRegistry, NeutrosophicJudgment, OpenTrustError,
+};
+use serde_json::Value;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+#[derive(Parser)]
+#[command(
+    name = "otp",
+    version,
+    about = "Inspect, fuse, verify and map OpenTrust Protocol judgments"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show judgments with their provenance tree and judgment ID check
+    Inspect {
+        /// Judgment files (JSON or JSONL)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Fuse judgments with a fusion operator
+    Fuse {
+        /// Operator: cawa, optimistic, pessimistic or a full operator ID
+        #[arg(short, long, default_value = "cawa")]
+        operator: String,
+        /// Comma-separated weights, one per input judgment (CAWA only)
+        #[arg(short, long, value_delimiter = ',')]
+        weights: Vec<f64>,
+        /// Judgment files (JSON or JSONL); all judgments are fused in order
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Verify the conformance seal of a fused judgment against its inputs
+    Verify {
+        /// File holding the fused judgment
+        fused: PathBuf,
+        /// Files holding the input judgments, in fusion order
+        #[arg(short, long, required = true, num_args = 1..)]
+        inputs: Vec<PathBuf>,
+        /// Comma-separated weights; defaults to the weights recorded in the fusion metadata
+        #[arg(short, long, value_delimiter = ',')]
+        weights: Vec<f64>,
+    },
+    /// Map raw values through a mapper configuration file
+    Map {
+        /// Mapper configuration file (JSON, or TOML with the `toml` feature)
+        #[arg(short, long)]
+        config: PathBuf,
+        /// Mapper ID (optionally `id@version`); required if the file defines several mappers
+        #[arg(short, long)]
+        mapper: Option<String>,
+        /// File with one JSON value per line (or a JSON array) to map
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Values to map, parsed as JSON; anything else is taken as a string
+        values: Vec<String>,
+    },
+    /// Recompute judgment IDs and compare them with the stored ones
+    Id {
+        /// Judgment files (JSON or JSONL)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        /// Print the judgments with recomputed IDs instead of a report
+        #[arg(long)]
+        assign: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Inspect { files } => inspect(&files),
+        Command::Fuse {
+            operator,
+            weights,
+            files,
+        } => fuse_files(&operator, &weights, &files),
+        Command::Verify {
+            fused,
+            inputs,
+            weights,
+        } => verify(&fused, &inputs, &weights),
+        Command::Map {
+            config,
+            mapper,
+            input,
+            values,
+        } => map(&config, mapper.as_deref(), input.as_deref(), &values),
+        Command::Id { files, assign } => ids(&files, assign),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("otp: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Read a file, or standard input for `-`
+fn read_source(path: &Path) -> CliResult<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+}
+
+/// Parse a single JSON document, a JSON array, or JSONL into values
+fn read_values(path: &Path) -> CliResult<Vec<Value>> {
+    let text = read_source(path)?;
+    if let Ok(value) = serde_json::from_str::<Value>(&text) {
+        return Ok(match value {
+            Value::Array(values) => values,
+            value => vec![value],
+        });
+    }
+
+    let mut values = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value = serde_json::from_str(line)
+            .map_err(|e| format!("{}:{}: {}", path.display(), index + 1, e))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+fn read_judgments(paths: &[PathBuf]) -> CliResult<Vec<NeutrosophicJudgment>> {
+    let mut judgments = Vec::new();
+    for path in paths {
+        for (index, value) in read_values(path)?.into_iter().enumerate() {
+            let judgment = serde_json::from_value(value)
+                .map_err(|e| format!("{}: judgment {}: {}", path.display(), index + 1, e))?;
+            judgments.push(judgment);
+        }
+    }
+    Ok(judgments)
+}
+
+/// Print one judgment as pretty JSON, several as JSONL
+fn print_judgments(judgments: &[NeutrosophicJudgment]) -> CliResult<()> {
+    match judgments {
+        [judgment] => println!("{}", judgment.to_json()?),
+        judgments => {
+            for judgment in judgments {
+                println!("{}", serde_json::to_string(judgment)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn inspect(files: &[PathBuf]) -> CliResult<bool> {
+    let judgments = read_judgments(files)?;
+    let mut all_ok = true;
+
+    for (index, judgment) in judgments.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("judgment {}", index + 1);
+        // Re-run the constructor checks (ranges, conservation, provenance)
+        let checked = NeutrosophicJudgment::new_with_entries(
+            judgment.t,
+            judgment.i,
+            judgment.f,
+            judgment.provenance_chain.clone(),
+        );
+        let validity = match checked {
+            Ok(_) => "valid".to_string(),
+            Err(e) => {
+                all_ok = false;
+                format!("INVALID: {}", e)
+            }
+        };
+        println!(
+            "  T={:.4} I={:.4} F={:.4}  (sum {:.4}, {})",
+            judgment.t,
+            judgment.i,
+            judgment.f,
+            judgment.total(),
+            validity
+        );
+
+        let computed = generate_judgment_id(judgment)?;
+        match &judgment.judgment_id {
+            Some(stored) if *stored == computed => println!("  id: {} (matches)", stored),
+            Some(stored) => {
+                all_ok = false;
+                println!("  id: {} (MISMATCH, recomputed {})", stored, computed);
+            }
+            None => println!("  id: none (would be {})", computed),
+        }
+
+        println!("  provenance:");
+        let last = judgment.provenance_chain.len().saturating_sub(1);
+        for (position, entry) in judgment.provenance_chain.iter().enumerate() {
+            let (branch, indent) = if position == last {
+                ("└─", "   ")
+            } else {
+                ("├─", "│  ")
+            };
+            println!("  {} {} @ {}", branch, entry.source_id, entry.timestamp);
+            if let Some(description) = &entry.description {
+                println!("  {}  {}", indent, description);
+            }
+            if let Some(seal) = &entry.conformance_seal {
+                println!("  {}  seal: {}", indent, seal);
+            }
+            if let Some(Value::Object(metadata)) = &entry.metadata {
+                for (key, value) in metadata {
+                    println!("  {}  {}: {}", indent, key, value);
+                }
+            }
+        }
+    }
+
+    Ok(all_ok)
+}
+
+fn fuse_files(operator: &str, weights: &[f64], files: &[PathBuf]) -> CliResult<bool> {
+    let judgments = read_judgments(files)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    let weights = (!weights.is_empty()).then_some(weights);
+
+    let fused = fuse(operator, &inputs, weights)?;
+    print_judgments(&[fused])?;
+    Ok(true)
+}
+
+fn verify(fused: &Path, inputs: &[PathBuf], weights: &[f64]) -> CliResult<bool> {
+    let fused = match read_judgments(&[fused.to_path_buf()])?.as_slice() {
+        [judgment] => judgment.clone(),
+        judgments => {
+            return Err(format!(
+                "expected one fused judgment in {}, found {}",
+                fused.display(),
+                judgments.len()
+            )
+            .into())
+        }
+    };
+    let judgments = read_judgments(inputs)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+
+    let weights = if weights.is_empty() {
+        recorded_seal_weights(&fused)
+            .ok_or("no weights given and none recorded in the fusion metadata")?
+    } else {
+        weights.to_vec()
+    };
+
+    match check_conformance_seal_with_inputs(&fused, &inputs, &weights) {
+        Ok(()) => {
+            println!("seal verified");
+            Ok(true)
+        }
+        Err(e @ OpenTrustError::SealMismatch { .. }) => {
+            println!("{}", e);
+            Ok(false)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn map(
+    config: &Path,
+    mapper: Option<&str>,
+    input: Option<&Path>,
+    values: &[String],
+) -> CliResult<bool> {
+    let configs = load_mapper_file(config)?;
+    let mapper_id = match mapper {
+        Some(id) => id.to_string(),
+        None => match configs.as_slice() {
+            [config] => config.id().to_string(),
+            configs => {
+                let ids: Vec<&str> = configs.iter().map(|c| c.id()).collect();
+                return Err(format!(
+                    "{} defines {} mappers ({}); choose one with --mapper",
+                    config.display(),
+                    configs.len(),
+                    ids.join(", ")
+                )
+                .into());
+            }
+        },
+    };
+
+    let registry = DefaultMapperRegistry::new();
+    registry.import(configs)?;
+
+    let mut inputs: Vec<Value> = values
+        .iter()
+        .map(|raw| serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.clone())))
+        .collect();
+    if let Some(path) = input {
+        inputs.extend(read_values(path)?);
+    }
+    if inputs.is_empty() {
+        return Err("no values to map; pass values or --input".into());
+    }
+
+    for value in &inputs {
+        let judgment = registry.apply(&mapper_id, value)?;
+        println!("{}", serde_json::to_string(&judgment)?);
+    }
+    Ok(true)
+}
+
+fn ids(files: &[PathBuf], assign: bool) -> CliResult<bool> {
+    let judgments = read_judgments(files)?;
+
+    if assign {
+        let mut updated = Vec::with_capacity(judgments.len());
+        for mut judgment in judgments {
+            judgment.judgment_id = Some(generate_judgment_id(&judgment)?);
+            updated.push(judgment);
+        }
+        print_judgments(&updated)?;
+        return Ok(true);
+    }
+
+    let mut all_match = true;
+    for judgment in &judgments {
+        let computed = generate_judgment_id(judgment)?;
+        let status = match &judgment.judgment_id {
+            Some(stored) if *stored == computed => "ok".to_string(),
+            Some(stored) => {
+                all_match = false;
+                format!("mismatch (stored {})", stored)
+            }
+            None => "missing".to_string(),
+        };
+        println!("{}  {}", computed, status);
+    }
+    Ok(all_match)
+}
@@ -0,0 +1,305 @@
+//! Fluent builders for judgments and provenance entries
+//!
+//! ```rust
+//! use opentrustprotocol::NeutrosophicJudgment;
+//!
+//! let judgment = NeutrosophicJudgment::builder()
+//!     .t(0.8)
+//!     .i(0.15)
+//!     .f(0.05)
+//!     .source("sensor-1")
+//!     .at("2023-01-01T00:00:00Z")
+//!     .describe("temperature within range")
+//!     .meta("unit", "celsius")
+//!     .with_id()
+//!     .build()?;
+//!
+//! assert!(judgment.judgment_id.is_some());
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::error::Result;
+use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::ensure_judgment_id;
+use serde_json::Value;
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+fn insert_metadata(entry: &mut ProvenanceEntry, key: String, value: Value) {
+    match &mut entry.metadata {
+        Some(Value::Object(map)) => {
+            map.insert(key, value);
+        }
+        metadata => {
+            let mut map = serde_json::Map::new();
+            map.insert(key, value);
+            *metadata = Some(Value::Object(map));
+        }
+    }
+}
+
+/// Builder for [`NeutrosophicJudgment`], created by [`NeutrosophicJudgment::builder`]
+///
+/// Components not set default to 0.0. Each [`source`](Self::source) call
+/// starts a new provenance entry; [`at`](Self::at), [`describe`](Self::describe)
+/// and [`meta`](Self::meta) apply to the most recent one. Entries without an
+/// explicit timestamp are stamped with the current time (RFC 3339) on build.
+/// Nothing is validated until [`build`](Self::build).
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct JudgmentBuilder {
+    t: f64,
+    i: f64,
+    f: f64,
+    entries: Vec<ProvenanceEntry>,
+    assign_id: bool,
+}
+
+impl JudgmentBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the truth degree
+    pub fn t(mut self, t: f64) -> Self {
+        self.t = t;
+        self
+    }
+
+    /// Set the indeterminacy degree
+    pub fn i(mut self, i: f64) -> Self {
+        self.i = i;
+        self
+    }
+
+    /// Set the falsity degree
+    pub fn f(mut self, f: f64) -> Self {
+        self.f = f;
+        self
+    }
+
+    /// Set all three components
+    pub fn tif(self, t: f64, i: f64, f: f64) -> Self {
+        self.t(t).i(i).f(f)
+    }
+
+    /// Start a new provenance entry for `source_id`
+    pub fn source(mut self, source_id: impl Into<String>) -> Self {
+        self.entries
+            .push(ProvenanceEntry::new(source_id.into(), String::new()));
+        self
+    }
+
+    /// Append a complete provenance entry
+    pub fn entry(mut self, entry: ProvenanceEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Set the timestamp of the current provenance entry
+    pub fn at(mut self, timestamp: impl Into<String>) -> Self {
+        self.current().timestamp = timestamp.into();
+        self
+    }
+
+    /// Set the description of the current provenance entry
+    pub fn describe(mut self, description: impl Into<String>) -> Self {
+        self.current().description = Some(description.into());
+        self
+    }
+
+    /// Add a metadata key to the current provenance entry
+    pub fn meta(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        insert_metadata(self.current(), key.into(), value.into());
+        self
+    }
+
+    /// Assign the judgment ID on build
+    pub fn with_id(mut self) -> Self {
+        self.assign_id = true;
+        self
+    }
+
+    /// Validate and build the judgment
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`NeutrosophicJudgment::new_with_entries`]; an
+    /// [`at`](Self::at), [`describe`](Self::describe) or [`meta`](Self::meta)
+    /// before any [`source`](Self::source) yields an entry without a source
+    /// ID, which is rejected here.
+    pub fn build(self) -> Result<NeutrosophicJudgment> {
+        let mut entries = self.entries;
+        let stamp = now();
+        for entry in entries
+            .iter_mut()
+            .filter(|entry| entry.timestamp.is_empty())
+        {
+            entry.timestamp = stamp.clone();
+        }
+
+        let judgment = NeutrosophicJudgment::new_with_entries(self.t, self.i, self.f, entries)?;
+        if self.assign_id {
+            ensure_judgment_id(judgment)
+        } else {
+            Ok(judgment)
+        }
+    }
+
+    /// The most recent provenance entry, started without a source if none exists
+    fn current(&mut self) -> &mut ProvenanceEntry {
+        if self.entries.is_empty() {
+            self.entries
+                .push(ProvenanceEntry::new(String::new(), String::new()));
+        }
+        self.entries.last_mut().expect("an entry was just ensured")
+    }
+}
+
+/// Builder for [`ProvenanceEntry`], created by [`ProvenanceEntry::builder`]
+///
+/// The timestamp defaults to the current time (RFC 3339).
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ProvenanceEntryBuilder {
+    entry: ProvenanceEntry,
+}
+
+impl ProvenanceEntryBuilder {
+    /// Start an entry for `source_id`
+    pub fn new(source_id: impl Into<String>) -> Self {
+        Self {
+            entry: ProvenanceEntry::new(source_id.into(), String::new()),
+        }
+    }
+
+    /// Set the timestamp
+    pub fn at(mut self, timestamp: impl Into<String>) -> Self {
+        self.entry.timestamp = timestamp.into();
+        self
+    }
+
+    /// Set the description
+    pub fn describe(mut self, description: impl Into<String>) -> Self {
+        self.entry.description = Some(description.into());
+        self
+    }
+
+    /// Add a metadata key
+    pub fn meta(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        insert_metadata(&mut self.entry, key.into(), value.into());
+        self
+    }
+
+    /// Build the entry
+    pub fn build(mut self) -> ProvenanceEntry {
+        if self.entry.timestamp.is_empty() {
+            self.entry.timestamp = now();
+        }
+        self.entry
+    }
+}
+
+impl NeutrosophicJudgment {
+    /// Start building a judgment fluently
+    pub fn builder() -> JudgmentBuilder {
+        JudgmentBuilder::new()
+    }
+}
+
+impl ProvenanceEntry {
+    /// Start building a provenance entry for `source_id`
+    pub fn builder(source_id: impl Into<String>) -> ProvenanceEntryBuilder {
+        ProvenanceEntryBuilder::new(source_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OpenTrustError;
+    use crate::judgment_id::generate_judgment_id;
+    use serde_json::json;
+
+    #[test]
+    fn test_builder_matches_constructor() {
+        let built = NeutrosophicJudgment::builder()
+            .t(0.8)
+            .i(0.2)
+            .source("sensor1")
+            .at("2023-01-01T00:00:00Z")
+            .build()
+            .unwrap();
+        let constructed = NeutrosophicJudgment::new(
+            0.8,
+            0.2,
+            0.0,
+            vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        assert_eq!(built, constructed);
+    }
+
+    #[test]
+    fn test_entries_descriptions_metadata_and_id() {
+        let judgment = NeutrosophicJudgment::builder()
+            .tif(0.6, 0.3, 0.1)
+            .source("sensor1")
+            .at("2023-01-01T00:00:00Z")
+            .describe("first reading")
+            .meta("unit", "celsius")
+            .meta("reading", 21.5)
+            .source("sensor2")
+            .entry(
+                ProvenanceEntry::builder("reviewer")
+                    .at("2023-01-02T00:00:00Z")
+                    .meta("approved", true)
+                    .build(),
+            )
+            .with_id()
+            .build()
+            .unwrap();
+
+        let chain = &judgment.provenance_chain;
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain[0].description.as_deref(), Some("first reading"));
+        assert_eq!(
+            chain[0].metadata,
+            Some(json!({"unit": "celsius", "reading": 21.5}))
+        );
+        assert!(!chain[1].timestamp.is_empty());
+        assert_eq!(chain[2].metadata, Some(json!({"approved": true})));
+        assert_eq!(
+            judgment.judgment_id,
+            Some(generate_judgment_id(&judgment).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_validation_happens_on_build() {
+        let result = NeutrosophicJudgment::builder()
+            .t(0.7)
+            .f(0.5)
+            .source("s")
+            .build();
+        assert!(matches!(
+            result,
+            Err(OpenTrustError::ConservationViolation { .. })
+        ));
+
+        assert!(matches!(
+            NeutrosophicJudgment::builder().t(0.5).build(),
+            Err(OpenTrustError::EmptyProvenanceChain)
+        ));
+        assert!(matches!(
+            NeutrosophicJudgment::builder()
+                .t(0.5)
+                .at("2023-01-01T00:00:00Z")
+                .build(),
+            Err(OpenTrustError::InvalidProvenanceEntry { index: 0, .. })
+        ));
+    }
+}
@@ -59,8 +59,24 @@ pub enum ConformanceError {
 
 impl From<ConformanceError> for crate::error::OpenTrustError {
     fn from(err: ConformanceError) -> Self {
-        crate::error::OpenTrustError::InvalidFusionInput {
-            message: err.to_string(),
+        use crate::error::OpenTrustError;
+        match err {
+            ConformanceError::MissingProvenance | ConformanceError::EmptyProvenance => {
+                OpenTrustError::EmptyProvenanceChain
+            }
+            ConformanceError::MissingSeal => OpenTrustError::MissingSeal,
+            ConformanceError::InvalidOperatorId(operator_id) => {
+                OpenTrustError::UnknownOperator { operator_id }
+            }
+            ConformanceError::SerializationError(e) => {
+                OpenTrustError::serialization("conformance seal input", e)
+            }
+            ConformanceError::LengthMismatch => OpenTrustError::SealVerification {
+                reason: "judgments and weights length mismatch".to_string(),
+            },
+            ConformanceError::VerificationFailed { reason } => {
+                OpenTrustError::SealVerification { reason }
+            }
         }
     }
 }
@@ -154,20 +170,19 @@ pub fn generate_conformance_seal(
 ) -> Result<String> {
     // Step 1: Validate inputs
     if judgments.len() != weights.len() {
-        return Err(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Invalid input: judgments and weights length mismatch".to_string(),
+        return Err(crate::error::OpenTrustError::WeightsLengthMismatch {
+            judgments_len: judgments.len(),
+            weights_len: weights.len(),
         });
     }
     
     if judgments.is_empty() {
-        return Err(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Invalid input: judgments list cannot be empty".to_string(),
-        });
+        return Err(crate::error::OpenTrustError::EmptyInput);
     }
     
     if operator_id.is_empty() {
-        return Err(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Invalid operator ID: empty".to_string(),
+        return Err(crate::error::OpenTrustError::UnknownOperator {
+            operator_id: String::new(),
         });
     }
     
@@ -216,20 +231,92 @@ pub fn generate_conformance_seal(
     
     // Step 4: Serialize to canonical JSON (no spaces, sorted keys)
     let canonical_json = serde_json::to_string(&pairs)
-        .map_err(|e| crate::error::OpenTrustError::InvalidFusionInput {
-            message: format!("Serialization error: {}", e),
-        })?;
+        .map_err(|e| crate::error::OpenTrustError::serialization("conformance seal input", e))?;
     
-    // Step 5: Concatenate components
+    // Steps 5 and 6: Concatenate with the operator ID and hash
+    Ok(seal_digest(&canonical_json, operator_id))
+}
+
+/// Hashes canonical seal input JSON together with the operator ID
+/// 
+/// Shared by every judgment kind that carries conformance seals, so they all
+/// use the same separator and digest.
+fn seal_digest(canonical_json: &str, operator_id: &str) -> String {
     let input_string = format!("{}{}{}", canonical_json, SEAL_SEPARATOR, operator_id);
     
-    // Step 6: Calculate SHA-256 hash
     let mut hasher = Sha256::new();
     hasher.update(input_string.as_bytes());
     let hash = hasher.finalize();
     
     // Return hexadecimal representation
-    Ok(format!("{:x}", hash))
+    format!("{:x}", hash)
+}
+
+/// Provenance entry as hashed into seals and judgment IDs of the other
+/// judgment kinds (interval, refined), without its conformance seal
+#[derive(Serialize)]
+pub(crate) struct CanonicalEntryRef<'a> {
+    source_id: &'a str,
+    timestamp: &'a str,
+    description: &'a Option<String>,
+    metadata: &'a Option<serde_json::Value>,
+}
+
+/// Canonical form of a provenance chain for hashing
+pub(crate) fn canonical_entries(chain: &[crate::judgment::ProvenanceEntry]) -> Vec<CanonicalEntryRef<'_>> {
+    chain
+        .iter()
+        .map(|entry| CanonicalEntryRef {
+            source_id: &entry.source_id,
+            timestamp: &entry.timestamp,
+            description: &entry.description,
+            metadata: &entry.metadata,
+        })
+        .collect()
+}
+
+/// Generates a Conformance Seal over canonical judgments of any kind
+/// 
+/// Each input is `(sort key, canonical judgment)`, the sort key being the
+/// source ID of the judgment's last provenance entry. Runs the same checks,
+/// ordering and hashing as [`generate_conformance_seal`].
+pub(crate) fn seal_canonical<J: Serialize>(
+    inputs: Vec<(&str, J)>,
+    weights: &[f64],
+    operator_id: &str,
+) -> Result<String> {
+    #[derive(Serialize)]
+    struct Pair<J> {
+        judgment: J,
+        weight: f64,
+    }
+
+    if inputs.len() != weights.len() {
+        return Err(crate::error::OpenTrustError::WeightsLengthMismatch {
+            judgments_len: inputs.len(),
+            weights_len: weights.len(),
+        });
+    }
+    if inputs.is_empty() {
+        return Err(crate::error::OpenTrustError::EmptyInput);
+    }
+    if operator_id.is_empty() {
+        return Err(crate::error::OpenTrustError::UnknownOperator {
+            operator_id: String::new(),
+        });
+    }
+
+    let mut keyed: Vec<(&str, Pair<J>)> = inputs
+        .into_iter()
+        .zip(weights)
+        .map(|((key, judgment), &weight)| (key, Pair { judgment, weight }))
+        .collect();
+    keyed.sort_by(|a, b| a.0.cmp(b.0));
+    let pairs: Vec<Pair<J>> = keyed.into_iter().map(|(_, pair)| pair).collect();
+
+    let canonical_json = serde_json::to_string(&pairs)
+        .map_err(|e| crate::error::OpenTrustError::serialization("conformance seal input", e))?;
+    Ok(seal_digest(&canonical_json, operator_id))
 }
 
 /// Verifies a Conformance Seal against a fused judgment
@@ -247,7 +334,10 @@ pub fn generate_conformance_seal(
 /// 
 /// # Errors
 /// 
-/// Returns `ConformanceError` if the judgment is malformed or missing required data
+/// Returns `OpenTrustError::EmptyProvenanceChain` or `MissingSeal` for a
+/// malformed judgment, and otherwise `OpenTrustError::SealVerification`:
+/// the seal covers the inputs, so use
+/// [`verify_conformance_seal_with_inputs`] instead.
 /// 
 /// # Example
 /// 
@@ -274,38 +364,20 @@ pub fn verify_conformance_seal(fused_judgment: &NeutrosophicJudgment) -> Result<
     // Extract the last provenance entry (should be the fusion operation)
     let last_entry = fused_judgment.provenance_chain
         .last()
-        .ok_or(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Empty provenance chain".to_string(),
-        })?;
+        .ok_or(crate::error::OpenTrustError::EmptyProvenanceChain)?;
     
     // Extract conformance seal
     let _stored_seal = last_entry.conformance_seal
         .as_ref()
-        .ok_or(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Missing conformance seal in fused judgment".to_string(),
-        })?;
+        .ok_or(crate::error::OpenTrustError::MissingSeal)?;
     
-    // Extract operator ID
-    let _operator_id = &last_entry.source_id;
-    
-    // Extract input judgments from provenance chain (all entries except the last)
-    let _input_judgments: Vec<NeutrosophicJudgment> = fused_judgment.provenance_chain
-        .iter()
-        .rev()
-        .skip(1) // Skip the fusion operation entry
-        .filter_map(|_entry| {
-            // This is a simplified extraction - in a full implementation,
-            // you'd need to store the original judgments or reconstruct them
-            // For now, we'll return an error indicating this needs metadata
-            None
-        })
-        .collect();
-    
-    // For a complete implementation, we need to store the input judgments
-    // and weights in the fusion operation metadata. For now, we'll indicate
-    // this limitation in the error message.
-    Err(crate::error::OpenTrustError::InvalidFusionInput {
-        message: "Complete verification requires input judgments and weights to be stored in fusion metadata. This is a limitation of the current implementation that will be addressed in the next iteration.".to_string()
+    // The seal covers the input judgments and weights, which the fused
+    // judgment does not carry
+    Err(crate::error::OpenTrustError::SealVerification {
+        reason: format!(
+            "the inputs of '{}' are needed to recompute the seal; use verify_conformance_seal_with_inputs",
+            last_entry.source_id
+        ),
     })
 }
 
@@ -358,28 +430,63 @@ pub fn verify_conformance_seal_with_inputs(
     input_judgments: &[&NeutrosophicJudgment],
     weights: &[f64],
 ) -> Result<bool> {
+    match check_conformance_seal_with_inputs(fused_judgment, input_judgments, weights) {
+        Ok(()) => Ok(true),
+        Err(crate::error::OpenTrustError::SealMismatch { .. }) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Weights that were sealed into a fused judgment, read from its fusion metadata
+/// 
+/// Fusion operators record their weights in the metadata of the last
+/// provenance entry; operators without weights (optimistic, pessimistic) are
+/// sealed with a weight of 1.0 per input. Returns `None` when the metadata
+/// does not say.
+pub fn recorded_seal_weights(fused_judgment: &NeutrosophicJudgment) -> Option<Vec<f64>> {
+    let metadata = fused_judgment.provenance_chain.last()?.metadata.as_ref()?;
+    match metadata.get("weights")? {
+        serde_json::Value::Array(weights) => weights.iter().map(|w| w.as_f64()).collect(),
+        serde_json::Value::Null => {
+            let input_count = metadata.get("input_count")?.as_u64()? as usize;
+            Some(vec![1.0; input_count])
+        }
+        _ => None,
+    }
+}
+
+/// Like [`verify_conformance_seal_with_inputs`], but reports a mismatch as an error
+/// 
+/// Returns `OpenTrustError::SealMismatch` with the stored and recomputed seals
+/// when the judgment does not match its inputs.
+pub fn check_conformance_seal_with_inputs(
+    fused_judgment: &NeutrosophicJudgment,
+    input_judgments: &[&NeutrosophicJudgment],
+    weights: &[f64],
+) -> Result<()> {
     // Extract the last provenance entry (should be the fusion operation)
     let last_entry = fused_judgment.provenance_chain
         .last()
-        .ok_or(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Empty provenance chain".to_string(),
-        })?;
+        .ok_or(crate::error::OpenTrustError::EmptyProvenanceChain)?;
     
     // Extract stored seal
     let stored_seal = last_entry.conformance_seal
         .as_ref()
-        .ok_or(crate::error::OpenTrustError::InvalidFusionInput {
-            message: "Missing conformance seal in fused judgment".to_string(),
-        })?;
+        .ok_or(crate::error::OpenTrustError::MissingSeal)?;
     
     // Extract operator ID
     let operator_id = &last_entry.source_id;
     
-    // Regenerate the seal with the provided inputs
+    // Regenerate the seal with the provided inputs and compare
     let regenerated_seal = generate_conformance_seal(input_judgments, weights, operator_id)?;
-    
-    // Compare seals
-    Ok(stored_seal == &regenerated_seal)
+    if stored_seal != &regenerated_seal {
+        return Err(crate::error::OpenTrustError::SealMismatch {
+            operator_id: operator_id.clone(),
+            stored: stored_seal.clone(),
+            computed: regenerated_seal,
+        });
+    }
+    Ok(())
 }
 
 /// Creates a provenance entry for a fusion operation with conformance seal
@@ -592,5 +699,38 @@ mod tests {
         ).unwrap();
         
         assert!(is_valid);
+
+        // Different weights produce a mismatch rather than an error
+        let is_valid = verify_conformance_seal_with_inputs(
+            &fused_judgment,
+            &[&judgment1, &judgment2],
+            &[0.5, 0.5]
+        ).unwrap();
+        assert!(!is_valid);
+
+        match check_conformance_seal_with_inputs(&fused_judgment, &[&judgment1, &judgment2], &[0.5, 0.5]) {
+            Err(crate::error::OpenTrustError::SealMismatch { operator_id, stored, computed }) => {
+                assert_eq!(operator_id, "otp-cawa-v1.1");
+                assert_eq!(stored, seal);
+                assert_ne!(computed, seal);
+            }
+            other => panic!("Expected SealMismatch, got {:?}", other),
+        }
+
+        // Inputs without a seal are reported as such
+        match verify_conformance_seal_with_inputs(&judgment1, &[&judgment2], &[1.0]) {
+            Err(crate::error::OpenTrustError::MissingSeal) => {}
+            other => panic!("Expected MissingSeal, got {:?}", other),
+        }
+
+        // Without the inputs the seal cannot be recomputed
+        assert!(matches!(
+            verify_conformance_seal(&fused_judgment),
+            Err(crate::error::OpenTrustError::SealVerification { .. })
+        ));
+        assert_eq!(
+            generate_conformance_seal(&[], &[], "otp-cawa-v1.1"),
+            Err(crate::error::OpenTrustError::EmptyInput)
+        );
     }
 }
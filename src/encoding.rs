@@ -0,0 +1,239 @@
+//! Compact binary encodings (CBOR and MessagePack)
+//!
+//! Judgments with long fused provenance chains are large as pretty JSON. This
+//! module encodes any OTP type — [`NeutrosophicJudgment`], [`OutcomeJudgment`],
+//! [`MapperParams`](crate::MapperParams) — as CBOR (feature `cbor`) or
+//! MessagePack (feature `msgpack`).
+//!
+//! ## Deterministic encoding
+//!
+//! Both formats carry exactly the JSON data model of the type, so the binary
+//! and JSON forms convert into each other losslessly. Encoding follows fixed
+//! rules, so the same value always produces the same bytes:
+//!
+//! 1. The value is first converted to its JSON form: structs become maps with
+//!    the same field names as in JSON, unset optional fields are `null` (or
+//!    omitted where the JSON form omits them, like an unset `judgment_id`).
+//! 2. Map keys are sorted by their UTF-8 bytes; maps, arrays and strings use
+//!    definite lengths.
+//! 3. Integers use the shortest encoding. CBOR floats use the shortest width
+//!    that preserves the exact value (RFC 8949 preferred serialization);
+//!    MessagePack floats are always float64.
+//!
+//! Judgment IDs and conformance seals are defined over the decoded values,
+//! so a judgment decoded from CBOR or MessagePack has the same ID and
+//! verifies against the same seal as its JSON form.
+//!
+//! [`NeutrosophicJudgment`]: crate::NeutrosophicJudgment
+//! [`OutcomeJudgment`]: crate::OutcomeJudgment
+
+use crate::error::{OpenTrustError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Convert a value to its JSON data model (sorted map keys)
+fn to_canonical_value<T: Serialize + ?Sized>(value: &T) -> Result<Value> {
+    serde_json::to_value(value)
+        .map_err(|e| OpenTrustError::serialization(std::any::type_name::<T>(), e))
+}
+
+fn from_canonical_value<T: DeserializeOwned>(value: Value) -> Result<T> {
+    serde_json::from_value(value)
+        .map_err(|e| OpenTrustError::deserialization(std::any::type_name::<T>(), e))
+}
+
+fn encoding_error<E>(format: &'static str, what: &'static str, error: E) -> OpenTrustError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    OpenTrustError::Encoding {
+        format,
+        what,
+        source: Arc::new(error),
+    }
+}
+
+fn decoding_error<E>(format: &'static str, what: &'static str, error: E) -> OpenTrustError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    OpenTrustError::Decoding {
+        format,
+        what,
+        source: Arc::new(error),
+    }
+}
+
+/// Encode a JSON value as CBOR
+#[cfg(feature = "cbor")]
+pub fn json_to_cbor(value: &Value) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes)
+        .map_err(|e| encoding_error("CBOR", "JSON value", e))?;
+    Ok(bytes)
+}
+
+/// Decode CBOR into its JSON form
+#[cfg(feature = "cbor")]
+pub fn cbor_to_json(bytes: &[u8]) -> Result<Value> {
+    ciborium::from_reader(bytes).map_err(|e| decoding_error("CBOR", "JSON value", e))
+}
+
+/// Encode a value as deterministic CBOR
+#[cfg(feature = "cbor")]
+pub fn to_cbor<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+    json_to_cbor(&to_canonical_value(value)?)
+}
+
+/// Decode a value from CBOR
+#[cfg(feature = "cbor")]
+pub fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    from_canonical_value(cbor_to_json(bytes)?)
+}
+
+/// Encode a JSON value as MessagePack
+#[cfg(feature = "msgpack")]
+pub fn json_to_msgpack(value: &Value) -> Result<Vec<u8>> {
+    rmp_serde::to_vec(value).map_err(|e| encoding_error("MessagePack", "JSON value", e))
+}
+
+/// Decode MessagePack into its JSON form
+#[cfg(feature = "msgpack")]
+pub fn msgpack_to_json(bytes: &[u8]) -> Result<Value> {
+    rmp_serde::from_slice(bytes).map_err(|e| decoding_error("MessagePack", "JSON value", e))
+}
+
+/// Encode a value as deterministic MessagePack
+#[cfg(feature = "msgpack")]
+pub fn to_msgpack<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+    json_to_msgpack(&to_canonical_value(value)?)
+}
+
+/// Decode a value from MessagePack
+#[cfg(feature = "msgpack")]
+pub fn from_msgpack<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    from_canonical_value(msgpack_to_json(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::judgment_id::generate_judgment_id;
+    use crate::mapper::types::{BaseMapperParams, BooleanParams, JudgmentData, MapperType};
+    use crate::{conflict_aware_weighted_average, MapperParams, NeutrosophicJudgment};
+
+    fn fused_judgment() -> NeutrosophicJudgment {
+        let a = NeutrosophicJudgment::new(
+            0.8,
+            0.2,
+            0.0,
+            vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        let b = NeutrosophicJudgment::new(
+            0.6,
+            0.3,
+            0.1,
+            vec![("sensor2".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        conflict_aware_weighted_average(&[&a, &b], &[0.6, 0.4]).unwrap()
+    }
+
+    fn boolean_config() -> MapperParams {
+        MapperParams::Boolean(BooleanParams {
+            base: BaseMapperParams {
+                id: "ssl".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Boolean,
+                description: Some("SSL certificate present".to_string()),
+                metadata: None,
+                input_path: Some("/tls/valid".to_string()),
+                deprecated: false,
+            },
+            true_map: JudgmentData {
+                T: 0.9,
+                I: 0.1,
+                F: 0.0,
+            },
+            false_map: JudgmentData {
+                T: 0.0,
+                I: 0.0,
+                F: 1.0,
+            },
+        })
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_round_trip_is_lossless_and_deterministic() {
+        let judgment = fused_judgment();
+        let bytes = to_cbor(&judgment).unwrap();
+        assert!(bytes.len() < judgment.to_json().unwrap().len());
+
+        let decoded: NeutrosophicJudgment = from_cbor(&bytes).unwrap();
+        assert_eq!(decoded, judgment);
+        assert_eq!(to_cbor(&decoded).unwrap(), bytes);
+        assert_eq!(
+            cbor_to_json(&bytes).unwrap(),
+            serde_json::to_value(&judgment).unwrap()
+        );
+        assert_eq!(
+            generate_judgment_id(&decoded).unwrap(),
+            generate_judgment_id(&judgment).unwrap()
+        );
+
+        let config = boolean_config();
+        let decoded: MapperParams = from_cbor(&to_cbor(&config).unwrap()).unwrap();
+        assert_eq!(decoded, config);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trip_is_lossless_and_deterministic() {
+        let judgment = fused_judgment();
+        let bytes = to_msgpack(&judgment).unwrap();
+        assert!(bytes.len() < judgment.to_json().unwrap().len());
+
+        let decoded: NeutrosophicJudgment = from_msgpack(&bytes).unwrap();
+        assert_eq!(decoded, judgment);
+        assert_eq!(to_msgpack(&decoded).unwrap(), bytes);
+        assert_eq!(
+            generate_judgment_id(&decoded).unwrap(),
+            generate_judgment_id(&judgment).unwrap()
+        );
+
+        use crate::judgment_id::{OutcomeJudgment, OutcomeType};
+        let outcome = OutcomeJudgment::new(
+            "a".repeat(64),
+            1.0,
+            0.0,
+            0.0,
+            OutcomeType::Success,
+            "oracle".to_string(),
+            judgment.provenance_chain.clone(),
+        )
+        .unwrap();
+        let json = serde_json::to_value(&outcome).unwrap();
+        assert_eq!(
+            msgpack_to_json(&json_to_msgpack(&json).unwrap()).unwrap(),
+            json
+        );
+
+        let config = boolean_config();
+        let decoded: MapperParams = from_msgpack(&to_msgpack(&config).unwrap()).unwrap();
+        assert_eq!(decoded, config);
+    }
+
+    #[test]
+    fn test_canonical_value_sorts_keys() {
+        let value = to_canonical_value(&boolean_config()).unwrap();
+        let keys: Vec<&String> = value["params"].as_object().unwrap().keys().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert!(from_canonical_value::<MapperParams>(value).is_ok());
+    }
+}
@@ -1,131 +1,345 @@
 //! Error types for the OpenTrust Protocol SDK
 
-use std::fmt;
+use crate::mapper::loader::ConfigError;
+use crate::mapper::types::{InputError, MapperError, ValidationError};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Result type alias for OpenTrust Protocol operations
 pub type Result<T> = std::result::Result<T, OpenTrustError>;
 
 /// Errors that can occur in OpenTrust Protocol operations
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Each failure has its own variant so callers can match on it; underlying
+/// errors are kept as sources (behind `Arc` so the error stays `Clone`). New
+/// variants may be added in minor releases.
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
 pub enum OpenTrustError {
     /// Invalid T, I, or F values (must be between 0.0 and 1.0)
+    #[error("Invalid {field} value {value}: {message}")]
     InvalidValue {
         field: String,
         value: f64,
         message: String,
     },
     /// Conservation constraint violated (T + I + F > 1.0)
+    #[error("Conservation constraint violated: T + I + F = {t} + {i} + {f} = {sum} > 1.0")]
     ConservationViolation { t: f64, i: f64, f: f64, sum: f64 },
     /// Empty provenance chain
+    #[error("Provenance chain cannot be empty")]
     EmptyProvenanceChain,
     /// Invalid provenance entry
+    #[error("Invalid provenance entry at index {index}: {message}")]
     InvalidProvenanceEntry { index: usize, message: String },
     /// Invalid input for fusion operations
+    #[error("Invalid fusion input: {message}")]
     InvalidFusionInput { message: String },
     /// Weights and judgments length mismatch
+    #[error("Weights length ({weights_len}) must match judgments length ({judgments_len})")]
     WeightsLengthMismatch {
         judgments_len: usize,
         weights_len: usize,
     },
     /// All weights are zero
+    #[error("All weights cannot be zero")]
     AllWeightsZero,
+    /// A value could not be serialized to JSON
+    #[error("Failed to serialize {what}: {source}")]
+    Serialization {
+        what: &'static str,
+        source: Arc<serde_json::Error>,
+    },
+    /// A JSON document could not be deserialized
+    #[error("Failed to deserialize {what}: {source}")]
+    Deserialization {
+        what: &'static str,
+        source: Arc<serde_json::Error>,
+    },
+    /// A value could not be encoded in a binary format (CBOR, MessagePack)
+    #[error("Failed to encode {what} as {format}: {source}")]
+    Encoding {
+        format: &'static str,
+        what: &'static str,
+        source: Arc<dyn std::error::Error + Send + Sync>,
+    },
+    /// Binary data could not be decoded
+    #[error("Failed to decode {what} from {format}: {source}")]
+    Decoding {
+        format: &'static str,
+        what: &'static str,
+        source: Arc<dyn std::error::Error + Send + Sync>,
+    },
+    /// The fusion operator ID is not one this SDK implements
+    #[error("Unknown fusion operator '{operator_id}'")]
+    UnknownOperator { operator_id: String },
+    /// A fused judgment carries no conformance seal
+    #[error("Missing conformance seal in fused judgment")]
+    MissingSeal,
+    /// The stored conformance seal does not match the one recomputed from the inputs
+    #[error("Conformance seal mismatch for operator '{operator_id}': stored {stored}, computed {computed}")]
+    SealMismatch {
+        operator_id: String,
+        stored: String,
+        computed: String,
+    },
+    /// A fusion or seal operation received no judgments
+    #[error("Judgments list cannot be empty")]
+    EmptyInput,
+    /// A conformance seal could not be verified
+    #[error("Seal verification failed: {reason}")]
+    SealVerification { reason: String },
+    /// A mapper with the same ID and version is already registered
+    #[error("Mapper with ID '{id}' version {version} already exists")]
+    DuplicateMapper { id: String, version: String },
+    /// No registered mapper matches the ID (or `id@version` pin)
+    #[error("No mapper registered for '{id}'")]
+    MapperNotFound { id: String },
+    /// A mapper version or version pin is not a semantic version
+    #[error("Mapper '{id}' has invalid semantic version '{version}': {source}")]
+    InvalidVersion {
+        id: String,
+        version: String,
+        source: Arc<semver::Error>,
+    },
+    /// The mapper registry implementation does not support the operation
+    #[error("Mapper registry does not support {operation}")]
+    UnsupportedRegistryOperation { operation: &'static str },
+    /// A mapper received an input of the wrong type
+    #[error("Invalid input type: expected {expected}, got {actual}")]
+    InputType { expected: String, actual: String },
+    /// A categorical mapper received a category it has no judgment for
+    #[error("Input category '{category}' not found in mapper and no default_judgment is defined")]
+    UnknownCategory { category: String },
+    /// Any other problem with a mapper input
+    #[error(transparent)]
+    Input(Arc<InputError>),
+    /// Mapper parameters are invalid
+    #[error(transparent)]
+    Validation(Arc<ValidationError>),
+    /// A mapper or registry operation failed
+    #[error(transparent)]
+    Mapper(Arc<MapperError>),
+    /// Mapper configuration files could not be loaded
+    #[error(transparent)]
+    Config(Arc<ConfigError>),
+    /// A rule expression or rule set definition is malformed
+    #[error("Invalid rule '{rule_id}': {message}")]
+    InvalidRule { rule_id: String, message: String },
+    /// A rule references an atom for which no judgment was supplied
+    #[error("Rule '{rule_id}' needs a judgment for atom '{atom}'")]
+    MissingAtom { rule_id: String, atom: String },
+    /// The rule set has no rule with this ID
+    #[error("No rule '{rule_id}' in rule set '{rule_set}'")]
+    RuleNotFound { rule_set: String, rule_id: String },
+    /// A file or directory could not be read or written
+    #[error("I/O error on {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: Arc<std::io::Error>,
+    },
     /// Serialization/deserialization error
+    #[deprecated(note = "use `Serialization` or `Deserialization`, which keep the source error")]
+    #[error("Serialization error: {message}")]
     SerializationError { message: String },
 }
 
-impl fmt::Display for OpenTrustError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// Compares field by field; wrapped source errors compare by message
+#[allow(deprecated)]
+impl PartialEq for OpenTrustError {
+    fn eq(&self, other: &Self) -> bool {
+        use OpenTrustError::*;
+
+        fn same<E: std::fmt::Display + ?Sized>(a: &Arc<E>, b: &Arc<E>) -> bool {
+            a.to_string() == b.to_string()
+        }
+
         match self {
-            OpenTrustError::InvalidValue {
+            InvalidValue {
                 field,
                 value,
                 message,
-            } => {
-                write!(f, "Invalid {} value {}: {}", field, value, message)
-            }
-            OpenTrustError::ConservationViolation {
-                t,
-                i,
-                f: falsity,
-                sum,
-            } => {
-                write!(
-                    f,
-                    "Conservation constraint violated: T + I + F = {} + {} + {} = {} > 1.0",
-                    t, i, falsity, sum
-                )
-            }
-            OpenTrustError::EmptyProvenanceChain => {
-                write!(f, "Provenance chain cannot be empty")
-            }
-            OpenTrustError::InvalidProvenanceEntry { index, message } => {
-                write!(
-                    f,
-                    "Invalid provenance entry at index {}: {}",
-                    index, message
-                )
-            }
-            OpenTrustError::InvalidFusionInput { message } => {
-                write!(f, "Invalid fusion input: {}", message)
-            }
-            OpenTrustError::WeightsLengthMismatch {
+            } => matches!(other, InvalidValue { field: f, value: v, message: m }
+                if field == f && value == v && message == m),
+            ConservationViolation { t, i, f, sum } => {
+                matches!(other, ConservationViolation { t: t2, i: i2, f: f2, sum: s2 }
+                    if t == t2 && i == i2 && f == f2 && sum == s2)
+            }
+            EmptyProvenanceChain => matches!(other, EmptyProvenanceChain),
+            InvalidProvenanceEntry { index, message } => {
+                matches!(other, InvalidProvenanceEntry { index: i, message: m }
+                    if index == i && message == m)
+            }
+            InvalidFusionInput { message } => {
+                matches!(other, InvalidFusionInput { message: m } if message == m)
+            }
+            WeightsLengthMismatch {
                 judgments_len,
                 weights_len,
-            } => {
-                write!(
-                    f,
-                    "Weights length ({}) must match judgments length ({})",
-                    weights_len, judgments_len
-                )
+            } => matches!(other, WeightsLengthMismatch { judgments_len: j, weights_len: w }
+                if judgments_len == j && weights_len == w),
+            AllWeightsZero => matches!(other, AllWeightsZero),
+            Serialization { what, source } => {
+                matches!(other, Serialization { what: w, source: s } if what == w && same(source, s))
+            }
+            Deserialization { what, source } => {
+                matches!(other, Deserialization { what: w, source: s }
+                    if what == w && same(source, s))
+            }
+            Encoding {
+                format,
+                what,
+                source,
+            } => matches!(other, Encoding { format: f, what: w, source: s }
+                if format == f && what == w && same(source, s)),
+            Decoding {
+                format,
+                what,
+                source,
+            } => matches!(other, Decoding { format: f, what: w, source: s }
+                if format == f && what == w && same(source, s)),
+            UnknownOperator { operator_id } => {
+                matches!(other, UnknownOperator { operator_id: o } if operator_id == o)
+            }
+            MissingSeal => matches!(other, MissingSeal),
+            SealMismatch {
+                operator_id,
+                stored,
+                computed,
+            } => matches!(other, SealMismatch { operator_id: o, stored: s, computed: c }
+                if operator_id == o && stored == s && computed == c),
+            EmptyInput => matches!(other, EmptyInput),
+            SealVerification { reason } => {
+                matches!(other, SealVerification { reason: r } if reason == r)
+            }
+            DuplicateMapper { id, version } => {
+                matches!(other, DuplicateMapper { id: i, version: v } if id == i && version == v)
+            }
+            MapperNotFound { id } => matches!(other, MapperNotFound { id: i } if id == i),
+            InvalidVersion {
+                id,
+                version,
+                source,
+            } => matches!(other, InvalidVersion { id: i, version: v, source: s }
+                if id == i && version == v && same(source, s)),
+            UnsupportedRegistryOperation { operation } => {
+                matches!(other, UnsupportedRegistryOperation { operation: o } if operation == o)
+            }
+            InputType { expected, actual } => {
+                matches!(other, InputType { expected: e, actual: a } if expected == e && actual == a)
+            }
+            UnknownCategory { category } => {
+                matches!(other, UnknownCategory { category: c } if category == c)
+            }
+            Input(source) => matches!(other, Input(s) if same(source, s)),
+            Validation(source) => matches!(other, Validation(s) if same(source, s)),
+            Mapper(source) => matches!(other, Mapper(s) if same(source, s)),
+            Config(source) => matches!(other, Config(s) if same(source, s)),
+            InvalidRule { rule_id, message } => {
+                matches!(other, InvalidRule { rule_id: r, message: m } if rule_id == r && message == m)
+            }
+            MissingAtom { rule_id, atom } => {
+                matches!(other, MissingAtom { rule_id: r, atom: a } if rule_id == r && atom == a)
             }
-            OpenTrustError::AllWeightsZero => {
-                write!(f, "All weights cannot be zero")
+            RuleNotFound { rule_set, rule_id } => {
+                matches!(other, RuleNotFound { rule_set: s, rule_id: r }
+                    if rule_set == s && rule_id == r)
             }
-            OpenTrustError::SerializationError { message } => {
-                write!(f, "Serialization error: {}", message)
+            Io { path, source } => {
+                matches!(other, Io { path: p, source: s } if path == p && same(source, s))
+            }
+            SerializationError { message } => {
+                matches!(other, SerializationError { message: m } if message == m)
             }
         }
     }
 }
 
-impl std::error::Error for OpenTrustError {}
+impl OpenTrustError {
+    pub(crate) fn serialization(what: &'static str, source: serde_json::Error) -> Self {
+        OpenTrustError::Serialization {
+            what,
+            source: Arc::new(source),
+        }
+    }
+
+    pub(crate) fn deserialization(what: &'static str, source: serde_json::Error) -> Self {
+        OpenTrustError::Deserialization {
+            what,
+            source: Arc::new(source),
+        }
+    }
+
+    pub(crate) fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        OpenTrustError::Io {
+            path: path.into(),
+            source: Arc::new(source),
+        }
+    }
+}
 
 // Conversion from mapper errors
-impl From<crate::mapper::types::ValidationError> for OpenTrustError {
-    fn from(error: crate::mapper::types::ValidationError) -> Self {
+impl From<ValidationError> for OpenTrustError {
+    fn from(error: ValidationError) -> Self {
         match error {
-            crate::mapper::types::ValidationError::InvalidJudgment { message } => {
-                OpenTrustError::InvalidFusionInput { message }
-            }
-            crate::mapper::types::ValidationError::ConservationViolation { sum } => {
-                OpenTrustError::ConservationViolation {
-                    t: 0.0,
-                    i: 0.0,
-                    f: 0.0,
-                    sum,
-                }
-            }
-            crate::mapper::types::ValidationError::MissingParameter { param } => {
-                OpenTrustError::InvalidFusionInput {
-                    message: format!("Missing required parameter: {}", param),
-                }
+            ValidationError::ConservationViolation { t, i, f, sum } => {
+                OpenTrustError::ConservationViolation { t, i, f, sum }
             }
+            other => OpenTrustError::Validation(Arc::new(other)),
         }
     }
 }
 
-impl From<crate::mapper::types::InputError> for OpenTrustError {
-    fn from(error: crate::mapper::types::InputError) -> Self {
-        OpenTrustError::InvalidFusionInput {
-            message: error.to_string(),
+impl From<InputError> for OpenTrustError {
+    fn from(error: InputError) -> Self {
+        match error {
+            InputError::InvalidType { expected, actual } => {
+                OpenTrustError::InputType { expected, actual }
+            }
+            other => OpenTrustError::Input(Arc::new(other)),
         }
     }
 }
 
-impl From<crate::mapper::types::MapperError> for OpenTrustError {
-    fn from(error: crate::mapper::types::MapperError) -> Self {
-        OpenTrustError::InvalidFusionInput {
-            message: error.to_string(),
-        }
+impl From<MapperError> for OpenTrustError {
+    fn from(error: MapperError) -> Self {
+        OpenTrustError::Mapper(Arc::new(error))
+    }
+}
+
+impl From<ConfigError> for OpenTrustError {
+    fn from(error: ConfigError) -> Self {
+        OpenTrustError::Config(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_compare_by_fields_and_source_message() {
+        assert_eq!(
+            OpenTrustError::AllWeightsZero,
+            OpenTrustError::AllWeightsZero
+        );
+        assert_ne!(OpenTrustError::AllWeightsZero, OpenTrustError::MissingSeal);
+        assert_ne!(
+            OpenTrustError::MapperNotFound {
+                id: "a".to_string()
+            },
+            OpenTrustError::MapperNotFound {
+                id: "b".to_string()
+            }
+        );
+
+        let parse = |text: &str| {
+            OpenTrustError::deserialization(
+                "judgment",
+                serde_json::from_str::<serde_json::Value>(text).unwrap_err(),
+            )
+        };
+        assert_eq!(parse("{"), parse("{"));
+        assert_ne!(parse("{"), parse("["));
     }
 }
@@ -0,0 +1,498 @@
+//! Explanations of fused judgments
+//!
+//! [`explain_fusion`] answers "why did CAWA come out at T = 0.41?" for the
+//! point fusion operators. For every input it reports:
+//!
+//! - the effective weight: its share of the conflict-adjusted CAWA weights
+//!   (`w · (1 - T·F)`, normalized), or 1/n for the averaged I of optimistic
+//!   and pessimistic fusion
+//! - its marginal contribution to the fused T, I and F; the contributions
+//!   of all inputs add up to the fused values
+//! - its leave-one-out delta: the fused values minus those obtained without
+//!   it
+//! - for optimistic and pessimistic fusion, whether it supplied the extreme
+//!   T or F
+//!
+//! The explanation can be attached to the fusion entry's metadata under
+//! `explanation`, either with [`FusionExplanation::attach_to`] or by fusing
+//! with [`fuse_explained`]:
+//!
+//! ```rust
+//! use opentrustprotocol::{fuse_explained, NeutrosophicJudgment};
+//!
+//! let a = NeutrosophicJudgment::new(0.7, 0.1, 0.1, vec![("a".to_string(), "2023-01-01T00:00:00Z".to_string())])?;
+//! let b = NeutrosophicJudgment::new(0.2, 0.2, 0.5, vec![("b".to_string(), "2023-01-01T00:00:00Z".to_string())])?;
+//!
+//! let (fused, explanation) = fuse_explained("cawa", &[&a, &b], Some(&[1.0, 1.0]))?;
+//! let share = explanation.inputs[0].effective_weight;
+//! assert!((fused.t - (share * 0.7 + (1.0 - share) * 0.2)).abs() < 1e-12);
+//! assert!(fused.provenance_chain.last().unwrap().metadata.as_ref().unwrap()["explanation"].is_object());
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{
+    fuse, validate_inputs, CAWA_OPERATOR_ID, OPTIMISTIC_OPERATOR_ID, PESSIMISTIC_OPERATOR_ID,
+};
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::{ensure_judgment_id, generate_judgment_id};
+use serde::{Deserialize, Serialize};
+
+/// T, I and F values (or differences of them)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ComponentValues {
+    /// Truth
+    pub t: f64,
+    /// Indeterminacy
+    pub i: f64,
+    /// Falsity
+    pub f: f64,
+}
+
+impl ComponentValues {
+    fn new((t, i, f): (f64, f64, f64)) -> Self {
+        Self { t, i, f }
+    }
+
+    fn minus(self, other: ComponentValues) -> Self {
+        Self {
+            t: self.t - other.t,
+            i: self.i - other.i,
+            f: self.f - other.f,
+        }
+    }
+}
+
+/// How one input shaped a fused judgment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputExplanation {
+    /// Position of the input
+    pub index: usize,
+    /// Judgment ID of the input
+    pub judgment_id: String,
+    /// Weight given by the caller (CAWA only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    /// Conflict score T·F that reduced the weight (CAWA only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict: Option<f64>,
+    /// Share of the averaging weight, after conflict adjustment
+    pub effective_weight: f64,
+    /// Part of the fused T, I and F attributable to this input
+    pub contribution: ComponentValues,
+    /// Fused values minus the values fused without this input (absent for
+    /// a single input)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leave_one_out: Option<ComponentValues>,
+    /// Whether this input supplied the extreme T (optimistic and pessimistic)
+    #[serde(default)]
+    pub determines_t: bool,
+    /// Whether this input supplied the extreme F (optimistic and pessimistic)
+    #[serde(default)]
+    pub determines_f: bool,
+}
+
+/// Explanation of a fusion result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FusionExplanation {
+    /// Operator ID of the explained fusion
+    pub operator: String,
+    /// Fused values
+    pub result: ComponentValues,
+    /// T + I + F before proportional rescaling, if the result was rescaled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalization: Option<f64>,
+    /// Per-input explanations, in input order
+    pub inputs: Vec<InputExplanation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Cawa,
+    Optimistic,
+    Pessimistic,
+}
+
+impl Operator {
+    fn resolve(operator: &str, weights: Option<&[f64]>) -> Result<Self> {
+        let resolved = match operator {
+            "cawa" | CAWA_OPERATOR_ID => Operator::Cawa,
+            "optimistic" | OPTIMISTIC_OPERATOR_ID => Operator::Optimistic,
+            "pessimistic" | PESSIMISTIC_OPERATOR_ID => Operator::Pessimistic,
+            _ => {
+                return Err(OpenTrustError::UnknownOperator {
+                    operator_id: operator.to_string(),
+                })
+            }
+        };
+        if resolved != Operator::Cawa && weights.is_some() {
+            return Err(OpenTrustError::InvalidFusionInput {
+                message: format!("Operator '{}' does not take weights", operator),
+            });
+        }
+        Ok(resolved)
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            Operator::Cawa => CAWA_OPERATOR_ID,
+            Operator::Optimistic => OPTIMISTIC_OPERATOR_ID,
+            Operator::Pessimistic => PESSIMISTIC_OPERATOR_ID,
+        }
+    }
+
+    /// Fused values and, if rescaled, the sum before rescaling
+    ///
+    /// Mirrors the fusion operators in [`crate::fusion`].
+    fn combine(
+        self,
+        judgments: &[&NeutrosophicJudgment],
+        weights: &[f64],
+    ) -> (ComponentValues, Option<f64>) {
+        let count = judgments.len() as f64;
+        let average = |component: fn(&NeutrosophicJudgment) -> f64| {
+            judgments.iter().map(|j| component(j)).sum::<f64>() / count
+        };
+        match self {
+            Operator::Cawa => {
+                let shares = cawa_shares(judgments, weights);
+                let weighted = |component: fn(&NeutrosophicJudgment) -> f64| {
+                    judgments
+                        .iter()
+                        .zip(&shares)
+                        .map(|(j, share)| component(j) * share)
+                        .sum::<f64>()
+                };
+                (
+                    ComponentValues::new((weighted(|j| j.t), weighted(|j| j.i), weighted(|j| j.f))),
+                    None,
+                )
+            }
+            Operator::Optimistic | Operator::Pessimistic => {
+                let optimistic = self == Operator::Optimistic;
+                let (t, f) = if optimistic {
+                    (
+                        judgments.iter().map(|j| j.t).fold(0.0, f64::max),
+                        judgments.iter().map(|j| j.f).fold(1.0, f64::min),
+                    )
+                } else {
+                    (
+                        judgments.iter().map(|j| j.t).fold(1.0, f64::min),
+                        judgments.iter().map(|j| j.f).fold(0.0, f64::max),
+                    )
+                };
+                let i = average(|j| j.i);
+                let total = t + i + f;
+                if total > 1.0 {
+                    (
+                        ComponentValues::new((t / total, i / total, f / total)),
+                        Some(total),
+                    )
+                } else {
+                    (ComponentValues::new((t, i, f)), None)
+                }
+            }
+        }
+    }
+}
+
+/// Normalized conflict-adjusted CAWA weights, or equal shares if they are
+/// all zero
+fn cawa_shares(judgments: &[&NeutrosophicJudgment], weights: &[f64]) -> Vec<f64> {
+    let adjusted: Vec<f64> = judgments
+        .iter()
+        .zip(weights)
+        .map(|(j, weight)| weight * (1.0 - j.t * j.f))
+        .collect();
+    let total: f64 = adjusted.iter().sum();
+    if total == 0.0 {
+        vec![1.0 / judgments.len() as f64; judgments.len()]
+    } else {
+        adjusted.iter().map(|weight| weight / total).collect()
+    }
+}
+
+/// Explain the result of fusing `judgments` with `operator`
+///
+/// `operator` and `weights` are interpreted as by [`fuse`]. Nothing is
+/// fused or sealed.
+///
+/// # Errors
+///
+/// Returns the errors [`fuse`] would for the same arguments.
+pub fn explain_fusion(
+    operator: &str,
+    judgments: &[&NeutrosophicJudgment],
+    weights: Option<&[f64]>,
+) -> Result<FusionExplanation> {
+    let kind = Operator::resolve(operator, weights)?;
+    validate_inputs(judgments, weights)?;
+    let weights = weights.map_or_else(|| vec![1.0; judgments.len()], <[f64]>::to_vec);
+
+    let (result, normalization) = kind.combine(judgments, &weights);
+    let scale = normalization.map_or(1.0, |total| 1.0 / total);
+    let count = judgments.len() as f64;
+
+    // Inputs supplying the extreme T and F share the credit for it
+    let (extreme_t, extreme_f) = match kind {
+        Operator::Cawa => (None, None),
+        Operator::Optimistic => (
+            Some(judgments.iter().map(|j| j.t).fold(0.0, f64::max)),
+            Some(judgments.iter().map(|j| j.f).fold(1.0, f64::min)),
+        ),
+        Operator::Pessimistic => (
+            Some(judgments.iter().map(|j| j.t).fold(1.0, f64::min)),
+            Some(judgments.iter().map(|j| j.f).fold(0.0, f64::max)),
+        ),
+    };
+    let holders = |extreme: Option<f64>, component: fn(&NeutrosophicJudgment) -> f64| {
+        judgments
+            .iter()
+            .filter(|j| Some(component(j)) == extreme)
+            .count() as f64
+    };
+    let t_holders = holders(extreme_t, |j| j.t);
+    let f_holders = holders(extreme_f, |j| j.f);
+
+    let shares = match kind {
+        Operator::Cawa => cawa_shares(judgments, &weights),
+        _ => vec![1.0 / count; judgments.len()],
+    };
+
+    let mut inputs = Vec::with_capacity(judgments.len());
+    for (index, judgment) in judgments.iter().enumerate() {
+        let share = shares[index];
+        let determines_t = extreme_t == Some(judgment.t);
+        let determines_f = extreme_f == Some(judgment.f);
+        let contribution = match kind {
+            Operator::Cawa => {
+                ComponentValues::new((judgment.t * share, judgment.i * share, judgment.f * share))
+            }
+            _ => ComponentValues::new((
+                if determines_t {
+                    judgment.t / t_holders * scale
+                } else {
+                    0.0
+                },
+                judgment.i / count * scale,
+                if determines_f {
+                    judgment.f / f_holders * scale
+                } else {
+                    0.0
+                },
+            )),
+        };
+
+        let leave_one_out = (judgments.len() > 1).then(|| {
+            let (rest, rest_weights): (Vec<&NeutrosophicJudgment>, Vec<f64>) = judgments
+                .iter()
+                .zip(&weights)
+                .enumerate()
+                .filter(|&(other, _)| other != index)
+                .map(|(_, (j, w))| (*j, *w))
+                .unzip();
+            result.minus(kind.combine(&rest, &rest_weights).0)
+        });
+
+        inputs.push(InputExplanation {
+            index,
+            judgment_id: match &judgment.judgment_id {
+                Some(id) => id.clone(),
+                None => generate_judgment_id(judgment)?,
+            },
+            weight: (kind == Operator::Cawa).then_some(weights[index]),
+            conflict: (kind == Operator::Cawa).then_some(judgment.t * judgment.f),
+            effective_weight: share,
+            contribution,
+            leave_one_out,
+            determines_t,
+            determines_f,
+        });
+    }
+
+    Ok(FusionExplanation {
+        operator: kind.id().to_string(),
+        result,
+        normalization,
+        inputs,
+    })
+}
+
+/// Fuse with [`fuse`] and attach the explanation to the fusion entry
+///
+/// See [`FusionExplanation::attach_to`].
+pub fn fuse_explained(
+    operator: &str,
+    judgments: &[&NeutrosophicJudgment],
+    weights: Option<&[f64]>,
+) -> Result<(NeutrosophicJudgment, FusionExplanation)> {
+    let explanation = explain_fusion(operator, judgments, weights)?;
+    let fused = explanation.attach_to(fuse(operator, judgments, weights)?)?;
+    Ok((fused, explanation))
+}
+
+impl FusionExplanation {
+    /// Add this explanation to the metadata of the fused judgment's fusion
+    /// entry, under `explanation`
+    ///
+    /// The conformance seal covers the inputs only, so it stays valid; the
+    /// judgment ID covers the provenance and is recomputed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidFusionInput` if the judgment's last
+    /// provenance entry is not a fusion entry of this explanation's operator.
+    pub fn attach_to(&self, mut fused: NeutrosophicJudgment) -> Result<NeutrosophicJudgment> {
+        let explanation = serde_json::to_value(self)
+            .map_err(|e| OpenTrustError::serialization("fusion explanation", e))?;
+        let entry = fused
+            .provenance_chain
+            .last_mut()
+            .filter(|entry| entry.source_id == self.operator && entry.conformance_seal.is_some())
+            .ok_or_else(|| OpenTrustError::InvalidFusionInput {
+                message: format!(
+                    "Judgment was not produced by '{}'; cannot attach its explanation",
+                    self.operator
+                ),
+            })?;
+        match &mut entry.metadata {
+            Some(serde_json::Value::Object(metadata)) => {
+                metadata.insert("explanation".to_string(), explanation);
+            }
+            metadata => *metadata = Some(serde_json::json!({ "explanation": explanation })),
+        }
+
+        fused.judgment_id = None;
+        ensure_judgment_id(fused)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+    use crate::fusion::{conflict_aware_weighted_average, optimistic_fusion, pessimistic_fusion};
+    use crate::judgment::test_support::judgment;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    fn inputs() -> Vec<NeutrosophicJudgment> {
+        vec![
+            judgment(0.8, 0.1, 0.1, "a"),
+            judgment(0.3, 0.3, 0.4, "b"),
+            judgment(0.5, 0.4, 0.0, "c"),
+        ]
+    }
+
+    #[test]
+    fn test_explanations_match_fusion_and_contributions_add_up() {
+        let inputs = inputs();
+        let refs: Vec<&NeutrosophicJudgment> = inputs.iter().collect();
+        let weights = [0.5, 0.3, 0.2];
+
+        let cases = [
+            (
+                explain_fusion("cawa", &refs, Some(&weights)).unwrap(),
+                conflict_aware_weighted_average(&refs, &weights).unwrap(),
+            ),
+            (
+                explain_fusion("optimistic", &refs, None).unwrap(),
+                optimistic_fusion(&refs).unwrap(),
+            ),
+            (
+                explain_fusion("pessimistic", &refs, None).unwrap(),
+                pessimistic_fusion(&refs).unwrap(),
+            ),
+        ];
+        for (explanation, fused) in &cases {
+            assert_close(explanation.result.t, fused.t);
+            assert_close(explanation.result.i, fused.i);
+            assert_close(explanation.result.f, fused.f);
+            let sum = |component: fn(&ComponentValues) -> f64| {
+                explanation
+                    .inputs
+                    .iter()
+                    .map(|input| component(&input.contribution))
+                    .sum::<f64>()
+            };
+            assert_close(sum(|c| c.t), fused.t);
+            assert_close(sum(|c| c.i), fused.i);
+            assert_close(sum(|c| c.f), fused.f);
+        }
+
+        // CAWA: weights shrink with conflict, shares sum to 1
+        let cawa = &cases[0].0;
+        assert_close(cawa.inputs[0].conflict.unwrap(), 0.08);
+        assert_close(
+            cawa.inputs.iter().map(|input| input.effective_weight).sum(),
+            1.0,
+        );
+        assert_close(
+            cawa.inputs[0].effective_weight,
+            0.5 * 0.92 / (0.5 * 0.92 + 0.3 * 0.88 + 0.2),
+        );
+
+        // Optimistic: max T from a, min F from c; rescaled 0.8 + 0.2667 + 0.0
+        let optimistic = &cases[1].0;
+        assert!(optimistic.inputs[0].determines_t && !optimistic.inputs[0].determines_f);
+        assert!(optimistic.inputs[2].determines_f);
+        assert!(optimistic.normalization.is_some());
+        // Pessimistic: min T and max F both from b
+        let pessimistic = &cases[2].0;
+        assert!(pessimistic.inputs[1].determines_t && pessimistic.inputs[1].determines_f);
+        assert_eq!(pessimistic.inputs[0].contribution.t, 0.0);
+    }
+
+    #[test]
+    fn test_leave_one_out_deltas() {
+        let inputs = inputs();
+        let refs: Vec<&NeutrosophicJudgment> = inputs.iter().collect();
+        let explanation = explain_fusion("pessimistic", &refs, None).unwrap();
+
+        let without_b = pessimistic_fusion(&[refs[0], refs[2]]).unwrap();
+        let delta = explanation.inputs[1].leave_one_out.unwrap();
+        assert_close(delta.t, explanation.result.t - without_b.t);
+        assert_close(delta.f, explanation.result.f - without_b.f);
+        assert!(delta.t < 0.0 && delta.f > 0.0);
+
+        let single = explain_fusion("cawa", &refs[..1], None).unwrap();
+        assert!(single.inputs[0].leave_one_out.is_none());
+        assert!(explain_fusion("optimistic", &refs, Some(&[1.0; 3])).is_err());
+        assert!(explain_fusion("median", &refs, None).is_err());
+    }
+
+    #[test]
+    fn test_attached_explanation_keeps_seal_valid() {
+        let inputs = inputs();
+        let refs: Vec<&NeutrosophicJudgment> = inputs.iter().collect();
+        let weights = [0.5, 0.3, 0.2];
+
+        let plain = conflict_aware_weighted_average(&refs, &weights).unwrap();
+        let (fused, explanation) = fuse_explained("cawa", &refs, Some(&weights)).unwrap();
+        let metadata = fused
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .as_ref()
+            .unwrap();
+        let attached: FusionExplanation =
+            serde_json::from_value(metadata["explanation"].clone()).unwrap();
+        assert_eq!(attached, explanation);
+        assert_eq!(metadata["operator"], CAWA_OPERATOR_ID);
+
+        assert!(verify_conformance_seal_with_inputs(&fused, &refs, &weights).unwrap());
+        assert_ne!(fused.judgment_id, plain.judgment_id);
+        assert_eq!(
+            fused.judgment_id.clone().unwrap(),
+            generate_judgment_id(&fused).unwrap()
+        );
+
+        // Only the matching fusion entry accepts the explanation
+        let optimistic = optimistic_fusion(&refs).unwrap();
+        assert!(explanation.attach_to(optimistic).is_err());
+        assert!(explanation.attach_to(inputs[0].clone()).is_err());
+    }
+}
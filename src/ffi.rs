@@ -0,0 +1,817 @@
+//! C ABI for embedding the SDK in non-Rust hosts
+//!
+//! Built with the `ffi` feature. The crate builds only an `rlib` by default;
+//! `cargo rustc --lib --release --features ffi --crate-type cdylib` produces
+//! the shared library exporting the `otp_*` functions declared in
+//! `include/opentrustprotocol.h` (generated with
+//! `cbindgen --config cbindgen.toml --output include/opentrustprotocol.h`).
+//!
+//! ## Conventions
+//!
+//! - Every function returns an [`OtpStatus`]; `OTP_STATUS_OK` (0) means success. The
+//!   numeric codes are stable across releases.
+//! - On failure, [`otp_last_error_message`] copies a description of the most
+//!   recent error on the calling thread into a caller-provided buffer.
+//! - Judgments and registries are opaque handles, released with
+//!   [`otp_judgment_free`] and [`otp_registry_free`].
+//! - Strings returned through `char **out` are owned by the caller and must be
+//!   released with [`otp_string_free`].
+//! - Panics never cross the boundary; they are reported as `OTP_STATUS_PANIC`.
+
+use crate::conformance::{
+    check_conformance_seal_with_inputs, generate_conformance_seal, recorded_seal_weights,
+};
+use crate::error::OpenTrustError;
+use crate::fusion::{conflict_aware_weighted_average, optimistic_fusion, pessimistic_fusion};
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::generate_judgment_id;
+use crate::mapper::registry::DefaultMapperRegistry;
+use crate::mapper::types::{create_timestamp, MapperParams, MapperRegistry};
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// Status codes returned by every `otp_*` function
+///
+/// Values are part of the ABI and never change meaning; new codes may be
+/// added.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpStatus {
+    /// Success
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8
+    InvalidUtf8 = 2,
+    /// A JSON argument could not be parsed
+    InvalidJson = 3,
+    /// T, I or F out of range, or T + I + F > 1
+    InvalidJudgment = 4,
+    /// The provenance chain is empty or has an invalid entry
+    InvalidProvenance = 5,
+    /// Fusion inputs are invalid (empty list, weight mismatch, zero weights)
+    InvalidFusionInput = 6,
+    /// The fusion operator ID is not supported
+    UnknownOperator = 7,
+    /// The fused judgment has no conformance seal
+    MissingSeal = 8,
+    /// The conformance seal does not match the inputs
+    SealMismatch = 9,
+    /// No mapper is registered under the requested ID
+    MapperNotFound = 10,
+    /// The mapper rejected its input
+    InvalidMapperInput = 11,
+    /// A mapper configuration is invalid or duplicated
+    InvalidMapperConfig = 12,
+    /// A file could not be read
+    Io = 13,
+    /// A Rust panic was caught at the boundary
+    Panic = 98,
+    /// Any other error
+    Other = 99,
+}
+
+impl OtpStatus {
+    /// Every status code, for lookups by numeric value
+    const ALL: [OtpStatus; 16] = [
+        OtpStatus::Ok,
+        OtpStatus::NullPointer,
+        OtpStatus::InvalidUtf8,
+        OtpStatus::InvalidJson,
+        OtpStatus::InvalidJudgment,
+        OtpStatus::InvalidProvenance,
+        OtpStatus::InvalidFusionInput,
+        OtpStatus::UnknownOperator,
+        OtpStatus::MissingSeal,
+        OtpStatus::SealMismatch,
+        OtpStatus::MapperNotFound,
+        OtpStatus::InvalidMapperInput,
+        OtpStatus::InvalidMapperConfig,
+        OtpStatus::Io,
+        OtpStatus::Panic,
+        OtpStatus::Other,
+    ];
+
+    fn name(self) -> &'static CStr {
+        match self {
+            OtpStatus::Ok => c"OTP_STATUS_OK",
+            OtpStatus::NullPointer => c"OTP_STATUS_NULL_POINTER",
+            OtpStatus::InvalidUtf8 => c"OTP_STATUS_INVALID_UTF8",
+            OtpStatus::InvalidJson => c"OTP_STATUS_INVALID_JSON",
+            OtpStatus::InvalidJudgment => c"OTP_STATUS_INVALID_JUDGMENT",
+            OtpStatus::InvalidProvenance => c"OTP_STATUS_INVALID_PROVENANCE",
+            OtpStatus::InvalidFusionInput => c"OTP_STATUS_INVALID_FUSION_INPUT",
+            OtpStatus::UnknownOperator => c"OTP_STATUS_UNKNOWN_OPERATOR",
+            OtpStatus::MissingSeal => c"OTP_STATUS_MISSING_SEAL",
+            OtpStatus::SealMismatch => c"OTP_STATUS_SEAL_MISMATCH",
+            OtpStatus::MapperNotFound => c"OTP_STATUS_MAPPER_NOT_FOUND",
+            OtpStatus::InvalidMapperInput => c"OTP_STATUS_INVALID_MAPPER_INPUT",
+            OtpStatus::InvalidMapperConfig => c"OTP_STATUS_INVALID_MAPPER_CONFIG",
+            OtpStatus::Io => c"OTP_STATUS_IO",
+            OtpStatus::Panic => c"OTP_STATUS_PANIC",
+            OtpStatus::Other => c"OTP_STATUS_OTHER",
+        }
+    }
+}
+
+impl From<&OpenTrustError> for OtpStatus {
+    fn from(error: &OpenTrustError) -> Self {
+        match error {
+            OpenTrustError::InvalidValue { .. } | OpenTrustError::ConservationViolation { .. } => {
+                OtpStatus::InvalidJudgment
+            }
+            OpenTrustError::EmptyProvenanceChain
+            | OpenTrustError::InvalidProvenanceEntry { .. } => OtpStatus::InvalidProvenance,
+            OpenTrustError::InvalidFusionInput { .. }
+            | OpenTrustError::EmptyInput
+            | OpenTrustError::WeightsLengthMismatch { .. }
+            | OpenTrustError::AllWeightsZero => OtpStatus::InvalidFusionInput,
+            OpenTrustError::Deserialization { .. } => OtpStatus::InvalidJson,
+            OpenTrustError::UnknownOperator { .. } => OtpStatus::UnknownOperator,
+            OpenTrustError::MissingSeal => OtpStatus::MissingSeal,
+            OpenTrustError::SealMismatch { .. } => OtpStatus::SealMismatch,
+            OpenTrustError::MapperNotFound { .. } => OtpStatus::MapperNotFound,
+            OpenTrustError::InputType { .. }
+            | OpenTrustError::UnknownCategory { .. }
+            | OpenTrustError::Input(_) => OtpStatus::InvalidMapperInput,
+            OpenTrustError::DuplicateMapper { .. }
+            | OpenTrustError::InvalidVersion { .. }
+            | OpenTrustError::Validation(_)
+            | OpenTrustError::Mapper(_)
+            | OpenTrustError::Config(_) => OtpStatus::InvalidMapperConfig,
+            OpenTrustError::Io { .. } => OtpStatus::Io,
+            _ => OtpStatus::Other,
+        }
+    }
+}
+
+/// Opaque handle to a [`NeutrosophicJudgment`]
+pub struct OtpJudgment(NeutrosophicJudgment);
+
+/// Opaque handle to a mapper registry
+pub struct OtpRegistry(DefaultMapperRegistry);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// An error crossing the boundary: its status code and message
+struct FfiError(OtpStatus, String);
+
+impl From<OpenTrustError> for FfiError {
+    fn from(error: OpenTrustError) -> Self {
+        FfiError(OtpStatus::from(&error), error.to_string())
+    }
+}
+
+type FfiResult<T = ()> = std::result::Result<T, FfiError>;
+
+fn set_last_error(message: String) {
+    // Interior NULs cannot be represented in a C string
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Run `body`, recording its error and catching panics
+fn guard(body: impl FnOnce() -> FfiResult) -> OtpStatus {
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => {
+            LAST_ERROR.with(|last| *last.borrow_mut() = None);
+            OtpStatus::Ok
+        }
+        Ok(Err(FfiError(status, message))) => {
+            set_last_error(message);
+            status
+        }
+        Err(_) => {
+            set_last_error("panic inside the OpenTrust Protocol library".to_string());
+            OtpStatus::Panic
+        }
+    }
+}
+
+fn null_pointer(name: &str) -> FfiError {
+    FfiError(OtpStatus::NullPointer, format!("{} must not be null", name))
+}
+
+/// Borrow a non-null pointer
+///
+/// # Safety
+/// `pointer` must be null or valid for reads for the duration of the call.
+unsafe fn borrow<'a, T>(pointer: *const T, name: &str) -> FfiResult<&'a T> {
+    pointer.as_ref().ok_or_else(|| null_pointer(name))
+}
+
+/// Read a NUL-terminated UTF-8 string
+///
+/// # Safety
+/// `pointer` must be null or point to a NUL-terminated string.
+unsafe fn read_str<'a>(pointer: *const c_char, name: &str) -> FfiResult<&'a str> {
+    if pointer.is_null() {
+        return Err(null_pointer(name));
+    }
+    CStr::from_ptr(pointer).to_str().map_err(|e| {
+        FfiError(
+            OtpStatus::InvalidUtf8,
+            format!("{} is not UTF-8: {}", name, e),
+        )
+    })
+}
+
+/// Collect `count` judgment handles
+///
+/// # Safety
+/// `judgments` must be null or point to `count` valid judgment handles.
+unsafe fn read_judgments<'a>(
+    judgments: *const *const OtpJudgment,
+    count: usize,
+) -> FfiResult<Vec<&'a NeutrosophicJudgment>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    if judgments.is_null() {
+        return Err(null_pointer("judgments"));
+    }
+    std::slice::from_raw_parts(judgments, count)
+        .iter()
+        .map(|&handle| borrow(handle, "judgment handle").map(|handle| &handle.0))
+        .collect()
+}
+
+/// Read `count` weights
+///
+/// # Safety
+/// `weights` must be null or point to `count` doubles.
+unsafe fn read_weights<'a>(weights: *const f64, count: usize) -> Option<&'a [f64]> {
+    if weights.is_null() {
+        None
+    } else if count == 0 {
+        Some(&[])
+    } else {
+        Some(std::slice::from_raw_parts(weights, count))
+    }
+}
+
+/// Store `value` through an output pointer
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write_out<T>(out: *mut T, value: T) -> FfiResult {
+    if out.is_null() {
+        return Err(null_pointer("out"));
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write_judgment(out: *mut *mut OtpJudgment, judgment: NeutrosophicJudgment) -> FfiResult {
+    if out.is_null() {
+        return Err(null_pointer("out"));
+    }
+    out.write(Box::into_raw(Box::new(OtpJudgment(judgment))));
+    Ok(())
+}
+
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write_string(out: *mut *mut c_char, value: String) -> FfiResult {
+    let value = CString::new(value)
+        .map_err(|e| FfiError(OtpStatus::Other, format!("string contains NUL: {}", e)))?;
+    if out.is_null() {
+        return Err(null_pointer("out"));
+    }
+    out.write(value.into_raw());
+    Ok(())
+}
+
+/// Copy the message of the last error on this thread into `buffer`
+///
+/// Writes at most `buffer_len` bytes including the terminating NUL, truncating
+/// if needed, and returns the full message length plus one (the buffer size
+/// needed), or 0 if the last call on this thread succeeded. `buffer` may be
+/// null to query the length.
+///
+/// # Safety
+/// `buffer` must be null or valid for `buffer_len` bytes of writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_last_error_message(buffer: *mut c_char, buffer_len: usize) -> usize {
+    LAST_ERROR.with(|last| match &*last.borrow() {
+        None => 0,
+        Some(message) => {
+            let bytes = message.as_bytes_with_nul();
+            if !buffer.is_null() && buffer_len > 0 {
+                let copied = bytes.len().min(buffer_len) - 1;
+                ptr::copy_nonoverlapping(bytes.as_ptr().cast::<c_char>(), buffer, copied);
+                *buffer.add(copied) = 0;
+            }
+            bytes.len()
+        }
+    })
+}
+
+/// Static, NUL-terminated name of a status code (e.g. `"OTP_STATUS_SEAL_MISMATCH"`)
+///
+/// Takes the code as a plain `int`, so any value is safe to pass; codes this
+/// library does not define yield `"OTP_STATUS_UNKNOWN"`.
+#[no_mangle]
+pub extern "C" fn otp_status_name(status: c_int) -> *const c_char {
+    OtpStatus::ALL
+        .iter()
+        .find(|known| **known as c_int == status)
+        .map_or(c"OTP_STATUS_UNKNOWN", |known| known.name())
+        .as_ptr()
+}
+
+/// Release a string returned by this library
+///
+/// # Safety
+/// `string` must be null or a string returned through a `char **out`
+/// parameter of this library, not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn otp_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Create a judgment with a single provenance entry timestamped now
+///
+/// # Safety
+/// `source_id` must be a NUL-terminated string and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_new(
+    t: f64,
+    i: f64,
+    f: f64,
+    source_id: *const c_char,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let source_id = read_str(source_id, "source_id")?.to_string();
+        let judgment = NeutrosophicJudgment::new(t, i, f, vec![(source_id, create_timestamp())])?;
+        write_judgment(out, judgment)
+    })
+}
+
+/// Parse and validate a judgment from JSON
+///
+/// # Safety
+/// `json` must be a NUL-terminated string and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_from_json(
+    json: *const c_char,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let parsed = NeutrosophicJudgment::from_json(read_str(json, "json")?)?;
+        // Run the constructor checks; keep the parsed ID and seals
+        NeutrosophicJudgment::new_with_entries(
+            parsed.t,
+            parsed.i,
+            parsed.f,
+            parsed.provenance_chain.clone(),
+        )?;
+        write_judgment(out, parsed)
+    })
+}
+
+/// Serialize a judgment as JSON; free the result with [`otp_string_free`]
+///
+/// # Safety
+/// `judgment` must be a valid handle and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_to_json(
+    judgment: *const OtpJudgment,
+    out: *mut *mut c_char,
+) -> OtpStatus {
+    guard(|| {
+        let judgment = borrow(judgment, "judgment")?;
+        write_string(out, judgment.0.to_json()?)
+    })
+}
+
+/// Read the T, I and F components of a judgment
+///
+/// # Safety
+/// `judgment` must be a valid handle and `t`, `i`, `f` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_components(
+    judgment: *const OtpJudgment,
+    t: *mut f64,
+    i: *mut f64,
+    f: *mut f64,
+) -> OtpStatus {
+    guard(|| {
+        let judgment = &borrow(judgment, "judgment")?.0;
+        write_out(t, judgment.t)?;
+        write_out(i, judgment.i)?;
+        write_out(f, judgment.f)
+    })
+}
+
+/// Release a judgment handle
+///
+/// # Safety
+/// `judgment` must be null or a handle returned by this library, not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_free(judgment: *mut OtpJudgment) {
+    if !judgment.is_null() {
+        drop(Box::from_raw(judgment));
+    }
+}
+
+/// Compute the judgment ID; free the result with [`otp_string_free`]
+///
+/// # Safety
+/// `judgment` must be a valid handle and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_judgment_id(
+    judgment: *const OtpJudgment,
+    out: *mut *mut c_char,
+) -> OtpStatus {
+    guard(|| {
+        let judgment = borrow(judgment, "judgment")?;
+        write_string(out, generate_judgment_id(&judgment.0)?)
+    })
+}
+
+/// Conflict-aware weighted average of `count` judgments
+///
+/// # Safety
+/// `judgments` and `weights` must point to `count` elements and `out` must
+/// be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_fuse_cawa(
+    judgments: *const *const OtpJudgment,
+    weights: *const f64,
+    count: usize,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let inputs = read_judgments(judgments, count)?;
+        let weights = read_weights(weights, count).ok_or_else(|| null_pointer("weights"))?;
+        write_judgment(out, conflict_aware_weighted_average(&inputs, weights)?)
+    })
+}
+
+/// Optimistic fusion of `count` judgments
+///
+/// # Safety
+/// `judgments` must point to `count` handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_fuse_optimistic(
+    judgments: *const *const OtpJudgment,
+    count: usize,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let inputs = read_judgments(judgments, count)?;
+        write_judgment(out, optimistic_fusion(&inputs)?)
+    })
+}
+
+/// Pessimistic fusion of `count` judgments
+///
+/// # Safety
+/// `judgments` must point to `count` handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_fuse_pessimistic(
+    judgments: *const *const OtpJudgment,
+    count: usize,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let inputs = read_judgments(judgments, count)?;
+        write_judgment(out, pessimistic_fusion(&inputs)?)
+    })
+}
+
+/// Generate the conformance seal for fusing `count` judgments with `operator_id`
+///
+/// `weights` may be null for unweighted operators (all weights 1.0). Free the
+/// result with [`otp_string_free`].
+///
+/// # Safety
+/// `judgments` (and `weights` unless null) must point to `count` elements,
+/// `operator_id` must be a NUL-terminated string and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_generate_conformance_seal(
+    judgments: *const *const OtpJudgment,
+    weights: *const f64,
+    count: usize,
+    operator_id: *const c_char,
+    out: *mut *mut c_char,
+) -> OtpStatus {
+    guard(|| {
+        let inputs = read_judgments(judgments, count)?;
+        let operator_id = read_str(operator_id, "operator_id")?;
+        let weights = match read_weights(weights, count) {
+            Some(weights) => weights.to_vec(),
+            None => vec![1.0; count],
+        };
+        write_string(
+            out,
+            generate_conformance_seal(&inputs, &weights, operator_id)?,
+        )
+    })
+}
+
+/// Verify a fused judgment's conformance seal against its `count` inputs
+///
+/// Returns `OTP_STATUS_OK` if the seal matches and `OTP_STATUS_SEAL_MISMATCH` if it does
+/// not. `weights` may be null to use the weights recorded in the fusion
+/// metadata.
+///
+/// # Safety
+/// `fused` must be a valid handle; `inputs` (and `weights` unless null) must
+/// point to `count` elements.
+#[no_mangle]
+pub unsafe extern "C" fn otp_verify_conformance_seal(
+    fused: *const OtpJudgment,
+    inputs: *const *const OtpJudgment,
+    weights: *const f64,
+    count: usize,
+) -> OtpStatus {
+    guard(|| {
+        let fused = &borrow(fused, "fused")?.0;
+        let inputs = read_judgments(inputs, count)?;
+        let weights = match read_weights(weights, count) {
+            Some(weights) => weights.to_vec(),
+            None => recorded_seal_weights(fused).ok_or_else(|| {
+                FfiError(
+                    OtpStatus::InvalidFusionInput,
+                    "no weights given and none recorded in the fusion metadata".to_string(),
+                )
+            })?,
+        };
+        check_conformance_seal_with_inputs(fused, &inputs, &weights)?;
+        Ok(())
+    })
+}
+
+/// Create an empty mapper registry
+///
+/// # Safety
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_registry_new(out: *mut *mut OtpRegistry) -> OtpStatus {
+    guard(|| {
+        write_out(
+            out,
+            Box::into_raw(Box::new(OtpRegistry(DefaultMapperRegistry::new()))),
+        )
+    })
+}
+
+/// Release a registry handle
+///
+/// # Safety
+/// `registry` must be null or a handle returned by [`otp_registry_new`], not
+/// yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn otp_registry_free(registry: *mut OtpRegistry) {
+    if !registry.is_null() {
+        drop(Box::from_raw(registry));
+    }
+}
+
+/// Register mappers from JSON: one mapper configuration or an array of them
+///
+/// # Safety
+/// `registry` must be a valid handle and `json` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn otp_registry_load_json(
+    registry: *const OtpRegistry,
+    json: *const c_char,
+) -> OtpStatus {
+    guard(|| {
+        let registry = borrow(registry, "registry")?;
+        let json = read_str(json, "json")?;
+        let invalid_json = |e: serde_json::Error| FfiError(OtpStatus::InvalidJson, e.to_string());
+        let configs = match serde_json::from_str::<serde_json::Value>(json).map_err(invalid_json)? {
+            serde_json::Value::Array(items) => items
+                .into_iter()
+                .map(serde_json::from_value::<MapperParams>)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(invalid_json)?,
+            value => vec![serde_json::from_value(value).map_err(invalid_json)?],
+        };
+        registry.0.import(configs)?;
+        Ok(())
+    })
+}
+
+/// Register every mapper configuration file in a directory
+///
+/// # Safety
+/// `registry` must be a valid handle and `dir` a NUL-terminated path.
+#[no_mangle]
+pub unsafe extern "C" fn otp_registry_load_dir(
+    registry: *const OtpRegistry,
+    dir: *const c_char,
+) -> OtpStatus {
+    guard(|| {
+        let registry = borrow(registry, "registry")?;
+        registry.0.import_dir(read_str(dir, "dir")?.as_ref())?;
+        Ok(())
+    })
+}
+
+/// Apply a registered mapper (`id` or `id@version`) to a JSON input
+///
+/// # Safety
+/// `registry` must be a valid handle, `mapper_id` and `input_json`
+/// NUL-terminated strings and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn otp_registry_apply(
+    registry: *const OtpRegistry,
+    mapper_id: *const c_char,
+    input_json: *const c_char,
+    out: *mut *mut OtpJudgment,
+) -> OtpStatus {
+    guard(|| {
+        let registry = borrow(registry, "registry")?;
+        let mapper_id = read_str(mapper_id, "mapper_id")?;
+        let input: serde_json::Value = serde_json::from_str(read_str(input_json, "input_json")?)
+            .map_err(|e| FfiError(OtpStatus::InvalidJson, e.to_string()))?;
+        write_judgment(out, registry.0.apply(mapper_id, &input)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fusion::CAWA_OPERATOR_ID;
+
+    fn last_error() -> String {
+        let mut buffer = vec![0 as c_char; 256];
+        unsafe {
+            otp_last_error_message(buffer.as_mut_ptr(), buffer.len());
+            CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    fn take_string(string: *mut c_char) -> String {
+        let value = unsafe { CStr::from_ptr(string) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        unsafe { otp_string_free(string) };
+        value
+    }
+
+    #[test]
+    fn test_fuse_seal_and_verify_through_handles() {
+        unsafe {
+            let mut a = ptr::null_mut();
+            let mut b = ptr::null_mut();
+            assert_eq!(
+                otp_judgment_new(0.8, 0.2, 0.0, c"sensor1".as_ptr(), &mut a),
+                OtpStatus::Ok
+            );
+            let json = cr#"{"t":0.6,"i":0.3,"f":0.1,"provenance_chain":[{"source_id":"sensor2","timestamp":"2023-01-01T00:00:00Z"}]}"#;
+            assert_eq!(otp_judgment_from_json(json.as_ptr(), &mut b), OtpStatus::Ok);
+
+            let inputs = [a as *const OtpJudgment, b as *const OtpJudgment];
+            let weights = [0.6, 0.4];
+            let mut fused = ptr::null_mut();
+            assert_eq!(
+                otp_fuse_cawa(inputs.as_ptr(), weights.as_ptr(), 2, &mut fused),
+                OtpStatus::Ok
+            );
+            assert_eq!(
+                otp_verify_conformance_seal(fused, inputs.as_ptr(), ptr::null(), 2),
+                OtpStatus::Ok
+            );
+            let other_weights = [0.5, 0.5];
+            assert_eq!(
+                otp_verify_conformance_seal(fused, inputs.as_ptr(), other_weights.as_ptr(), 2),
+                OtpStatus::SealMismatch
+            );
+            assert!(last_error().contains("seal mismatch"));
+
+            let mut seal = ptr::null_mut();
+            let operator = CString::new(CAWA_OPERATOR_ID).unwrap();
+            assert_eq!(
+                otp_generate_conformance_seal(
+                    inputs.as_ptr(),
+                    weights.as_ptr(),
+                    2,
+                    operator.as_ptr(),
+                    &mut seal
+                ),
+                OtpStatus::Ok
+            );
+            let fused_rust = &(*fused).0;
+            assert_eq!(
+                Some(take_string(seal)),
+                fused_rust.provenance_chain.last().unwrap().conformance_seal
+            );
+
+            let mut id = ptr::null_mut();
+            assert_eq!(otp_judgment_id(fused, &mut id), OtpStatus::Ok);
+            assert_eq!(take_string(id), generate_judgment_id(fused_rust).unwrap());
+
+            let mut pessimistic = ptr::null_mut();
+            assert_eq!(
+                otp_fuse_pessimistic(inputs.as_ptr(), 2, &mut pessimistic),
+                OtpStatus::Ok
+            );
+            let (mut t, mut i, mut f) = (0.0, 0.0, 0.0);
+            otp_judgment_components(pessimistic, &mut t, &mut i, &mut f);
+            let expected = pessimistic_fusion(&[&(*a).0, &(*b).0]).unwrap();
+            assert_eq!((t, i, f), (expected.t, expected.i, expected.f));
+
+            for handle in [a, b, fused, pessimistic] {
+                otp_judgment_free(handle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors_map_to_status_codes() {
+        unsafe {
+            let mut judgment = ptr::null_mut();
+            assert_eq!(
+                otp_judgment_new(0.8, 0.5, 0.0, c"s".as_ptr(), &mut judgment),
+                OtpStatus::InvalidJudgment
+            );
+            assert!(last_error().contains("Conservation"));
+            assert!(otp_last_error_message(ptr::null_mut(), 0) > 1);
+
+            // Truncation keeps the buffer NUL-terminated
+            let mut small = [1 as c_char; 4];
+            otp_last_error_message(small.as_mut_ptr(), small.len());
+            assert_eq!(small[3], 0);
+
+            assert_eq!(
+                otp_judgment_from_json(c"{".as_ptr(), &mut judgment),
+                OtpStatus::InvalidJson
+            );
+            assert_eq!(
+                otp_judgment_new(0.5, 0.5, 0.0, ptr::null(), &mut judgment),
+                OtpStatus::NullPointer
+            );
+            assert_eq!(
+                otp_fuse_optimistic(ptr::null(), 0, &mut judgment),
+                OtpStatus::InvalidFusionInput
+            );
+
+            let mut ok = ptr::null_mut();
+            assert_eq!(
+                otp_judgment_new(0.5, 0.5, 0.0, c"s".as_ptr(), &mut ok),
+                OtpStatus::Ok
+            );
+            assert_eq!(otp_last_error_message(ptr::null_mut(), 0), 0);
+            otp_judgment_free(ok);
+
+            let name = CStr::from_ptr(otp_status_name(OtpStatus::SealMismatch as c_int));
+            assert_eq!(name.to_str().unwrap(), "OTP_STATUS_SEAL_MISMATCH");
+            for unknown in [-1, 14, 100, c_int::MAX] {
+                let name = CStr::from_ptr(otp_status_name(unknown));
+                assert_eq!(name.to_str().unwrap(), "OTP_STATUS_UNKNOWN");
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_apply() {
+        unsafe {
+            let mut registry = ptr::null_mut();
+            assert_eq!(otp_registry_new(&mut registry), OtpStatus::Ok);
+            let config = cr#"[{"mapper_type":"boolean","params":{"id":"ssl","version":"1.0.0","mapper_type":"Boolean","true_map":{"T":0.9,"I":0.1,"F":0.0},"false_map":{"T":0.0,"I":0.0,"F":1.0}}}]"#;
+            assert_eq!(
+                otp_registry_load_json(registry, config.as_ptr()),
+                OtpStatus::Ok
+            );
+            assert_eq!(
+                otp_registry_load_json(registry, config.as_ptr()),
+                OtpStatus::InvalidMapperConfig
+            );
+
+            let mut judgment = ptr::null_mut();
+            assert_eq!(
+                otp_registry_apply(registry, c"ssl".as_ptr(), c"true".as_ptr(), &mut judgment),
+                OtpStatus::Ok
+            );
+            assert_eq!((*judgment).0.t, 0.9);
+            assert_eq!(
+                otp_registry_apply(registry, c"ssl".as_ptr(), c"[1]".as_ptr(), &mut judgment),
+                OtpStatus::InvalidMapperInput
+            );
+            assert_eq!(
+                otp_registry_apply(registry, c"nope".as_ptr(), c"true".as_ptr(), &mut judgment),
+                OtpStatus::MapperNotFound
+            );
+
+            otp_judgment_free(judgment);
+            otp_registry_free(registry);
+        }
+    }
+}
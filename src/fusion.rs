@@ -13,8 +13,18 @@ use crate::error::{OpenTrustError, Result};
 use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
 use crate::judgment_id::ensure_judgment_id;
 
+/// Operator ID recorded by [`conflict_aware_weighted_average`]
+pub const CAWA_OPERATOR_ID: &str = "otp-cawa-v1.1";
+/// Operator ID recorded by [`optimistic_fusion`]
+pub const OPTIMISTIC_OPERATOR_ID: &str = "otp-optimistic-v1.1";
+/// Operator ID recorded by [`pessimistic_fusion`]
+pub const PESSIMISTIC_OPERATOR_ID: &str = "otp-pessimistic-v1.1";
+
+/// Version recorded in the metadata of fusion provenance entries
+pub(crate) const FUSION_METADATA_VERSION: &str = "0.3.0";
+
 /// Validates inputs for fusion functions
-fn validate_inputs(judgments: &[&NeutrosophicJudgment], weights: Option<&[f64]>) -> Result<()> {
+pub(crate) fn validate_inputs<J>(judgments: &[&J], weights: Option<&[f64]>) -> Result<()> {
     if judgments.is_empty() {
         return Err(OpenTrustError::InvalidFusionInput {
             message: "Judgments list cannot be empty".to_string(),
@@ -62,20 +72,14 @@ fn create_fusion_provenance_with_seal(
         metadata.insert("weights".to_string(), serde_json::Value::Null);
     }
 
-    metadata.insert("version".to_string(), "0.3.0".into());
+    metadata.insert("version".to_string(), FUSION_METADATA_VERSION.into());
 
     // **REVOLUTIONARY**: Generate Conformance Seal
     let conformance_seal = if let Some(weights) = weights {
-        generate_conformance_seal(judgments, weights, operator)
-            .map_err(|e| OpenTrustError::InvalidFusionInput {
-                message: format!("Failed to generate conformance seal: {}", e),
-            })?
+        generate_conformance_seal(judgments, weights, operator)?
     } else {
         // For operations without weights, create a simplified seal
-        generate_conformance_seal(judgments, &vec![1.0; judgments.len()], operator)
-            .map_err(|e| OpenTrustError::InvalidFusionInput {
-                message: format!("Failed to generate conformance seal: {}", e),
-            })?
+        generate_conformance_seal(judgments, &vec![1.0; judgments.len()], operator)?
     };
 
     Ok(create_fusion_provenance_entry(
@@ -87,6 +91,21 @@ fn create_fusion_provenance_with_seal(
     ))
 }
 
+/// Records in a provenance entry's metadata that the result was normalized
+/// to satisfy T + I + F <= 1
+///
+/// Adds `"normalization": {"method": .., "sum": ..}`, where `sum` is the
+/// total before normalization.
+pub(crate) fn note_normalization(entry: &mut ProvenanceEntry, method: &str, sum: f64) {
+    let note = serde_json::json!({ "method": method, "sum": sum });
+    match &mut entry.metadata {
+        Some(serde_json::Value::Object(metadata)) => {
+            metadata.insert("normalization".to_string(), note);
+        }
+        metadata => *metadata = Some(serde_json::json!({ "normalization": note })),
+    }
+}
+
 /// Fuses a list of judgments using the conflict-aware weighted average.
 /// This is the primary and recommended operator in OTP.
 ///
@@ -164,7 +183,7 @@ pub fn conflict_aware_weighted_average(
         new_provenance.extend(judgment.provenance_chain.clone());
     }
     new_provenance.push(create_fusion_provenance_with_seal(
-        "otp-cawa-v1.1",
+        CAWA_OPERATOR_ID,
         judgments,
         Some(weights),
     )?);
@@ -190,7 +209,9 @@ pub fn conflict_aware_weighted_average(
 /// # Returns
 ///
 /// A new NeutrosophicJudgment with the max T, min F, and average I,
-/// plus automatic Conformance Seal and Judgment ID generation
+/// plus automatic Conformance Seal and Judgment ID generation. If these sum
+/// to more than 1 they are scaled down proportionally, and the fusion entry's
+/// metadata records it under `normalization`.
 ///
 /// # Errors
 ///
@@ -216,11 +237,11 @@ pub fn optimistic_fusion(judgments: &[&NeutrosophicJudgment]) -> Result<Neutroso
     for judgment in judgments {
         new_provenance.extend(judgment.provenance_chain.clone());
     }
-    new_provenance.push(create_fusion_provenance_with_seal(
-        "otp-optimistic-v1.1",
-        judgments,
-        None,
-    )?);
+    let mut fusion_entry = create_fusion_provenance_with_seal(OPTIMISTIC_OPERATOR_ID, judgments, None)?;
+    if total > 1.0 {
+        note_normalization(&mut fusion_entry, "proportional", total);
+    }
+    new_provenance.push(fusion_entry);
 
     // Create the fused judgment
     let fused_judgment = NeutrosophicJudgment::new_with_entries(scaled_t, scaled_i, scaled_f, new_provenance)?;
@@ -243,7 +264,9 @@ pub fn optimistic_fusion(judgments: &[&NeutrosophicJudgment]) -> Result<Neutroso
 /// # Returns
 ///
 /// A new NeutrosophicJudgment with the max F, min T, and average I,
-/// plus automatic Conformance Seal and Judgment ID generation
+/// plus automatic Conformance Seal and Judgment ID generation. If these sum
+/// to more than 1 they are scaled down proportionally, and the fusion entry's
+/// metadata records it under `normalization`.
 ///
 /// # Errors
 ///
@@ -269,11 +292,11 @@ pub fn pessimistic_fusion(judgments: &[&NeutrosophicJudgment]) -> Result<Neutros
     for judgment in judgments {
         new_provenance.extend(judgment.provenance_chain.clone());
     }
-    new_provenance.push(create_fusion_provenance_with_seal(
-        "otp-pessimistic-v1.1",
-        judgments,
-        None,
-    )?);
+    let mut fusion_entry = create_fusion_provenance_with_seal(PESSIMISTIC_OPERATOR_ID, judgments, None)?;
+    if total > 1.0 {
+        note_normalization(&mut fusion_entry, "proportional", total);
+    }
+    new_provenance.push(fusion_entry);
 
     // Create the fused judgment
     let fused_judgment = NeutrosophicJudgment::new_with_entries(scaled_t, scaled_i, scaled_f, new_provenance)?;
@@ -282,6 +305,41 @@ pub fn pessimistic_fusion(judgments: &[&NeutrosophicJudgment]) -> Result<Neutros
     ensure_judgment_id(fused_judgment)
 }
 
+/// Fuses judgments with the operator named by `operator`
+///
+/// `operator` is either a full operator ID (`"otp-cawa-v1.1"`) or its short
+/// name (`"cawa"`, `"optimistic"`, `"pessimistic"`). CAWA uses equal weights
+/// when `weights` is `None`; the other operators take no weights.
+///
+/// # Errors
+///
+/// Returns `OpenTrustError::UnknownOperator` for an unrecognized operator, or
+/// the error of the selected fusion function.
+pub fn fuse(
+    operator: &str,
+    judgments: &[&NeutrosophicJudgment],
+    weights: Option<&[f64]>,
+) -> Result<NeutrosophicJudgment> {
+    match operator {
+        "cawa" | CAWA_OPERATOR_ID => match weights {
+            Some(weights) => conflict_aware_weighted_average(judgments, weights),
+            None => conflict_aware_weighted_average(judgments, &vec![1.0; judgments.len()]),
+        },
+        "optimistic" | OPTIMISTIC_OPERATOR_ID | "pessimistic" | PESSIMISTIC_OPERATOR_ID
+            if weights.is_some() =>
+        {
+            Err(OpenTrustError::InvalidFusionInput {
+                message: format!("Operator '{}' does not take weights", operator),
+            })
+        }
+        "optimistic" | OPTIMISTIC_OPERATOR_ID => optimistic_fusion(judgments),
+        "pessimistic" | PESSIMISTIC_OPERATOR_ID => pessimistic_fusion(judgments),
+        _ => Err(OpenTrustError::UnknownOperator {
+            operator_id: operator.to_string(),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,6 +407,22 @@ mod tests {
         assert!(fused.f <= 0.1);
     }
 
+    #[test]
+    fn test_rescaling_is_noted_in_provenance() {
+        let judgment1 = create_test_judgment(0.8, 0.2, 0.0);
+        let judgment2 = create_test_judgment(0.6, 0.3, 0.1);
+
+        // Max T 0.8 + average I 0.25 + min F 0.0 = 1.05 is rescaled
+        let fused = optimistic_fusion(&[&judgment1, &judgment2]).unwrap();
+        let metadata = fused.provenance_chain.last().unwrap().metadata.as_ref().unwrap();
+        assert_eq!(metadata["normalization"]["method"], "proportional");
+        assert!((metadata["normalization"]["sum"].as_f64().unwrap() - 1.05).abs() < 1e-9);
+
+        let fused = pessimistic_fusion(&[&judgment1, &judgment2]).unwrap();
+        let metadata = fused.provenance_chain.last().unwrap().metadata.as_ref().unwrap();
+        assert!(metadata.get("normalization").is_none());
+    }
+
     #[test]
     fn test_empty_judgments_error() {
         let result = conflict_aware_weighted_average(&[], &[]);
@@ -460,4 +534,27 @@ mod tests {
         // Different input judgments should generate different IDs
         assert_ne!(fused1.judgment_id, fused2.judgment_id);
     }
+
+    #[test]
+    fn test_fuse_by_operator_name() {
+        let judgment1 = create_test_judgment(0.8, 0.2, 0.0);
+        let judgment2 = create_test_judgment(0.6, 0.3, 0.1);
+        let inputs = [&judgment1, &judgment2];
+
+        let by_name = fuse("cawa", &inputs, Some(&[0.6, 0.4])).unwrap();
+        let direct = conflict_aware_weighted_average(&inputs, &[0.6, 0.4]).unwrap();
+        assert_eq!(by_name.t, direct.t);
+
+        let optimistic = fuse(OPTIMISTIC_OPERATOR_ID, &inputs, None).unwrap();
+        assert_eq!(optimistic.t, optimistic_fusion(&inputs).unwrap().t);
+
+        assert!(matches!(
+            fuse("pessimistic", &inputs, Some(&[0.5, 0.5])),
+            Err(OpenTrustError::InvalidFusionInput { .. })
+        ));
+        assert!(matches!(
+            fuse("median", &inputs, None),
+            Err(OpenTrustError::UnknownOperator { ref operator_id }) if operator_id == "median"
+        ));
+    }
 }
@@ -0,0 +1,813 @@
+//! Interval-valued neutrosophic judgments
+//!
+//! When a source can only bound its assessment ("T is between 0.6 and 0.8"),
+//! each of T, I and F is a closed sub-interval of [0, 1]. An
+//! [`IntervalNeutrosophicJudgment`] is valid when every interval is proper
+//! (lower <= upper) and the lower bounds satisfy the conservation constraint
+//! (lower T + lower I + lower F <= 1), i.e. at least one point judgment
+//! inside the intervals is valid.
+//!
+//! Interval judgments are fused with interval versions of the point
+//! operators, carry conformance seals and judgment IDs computed the same way
+//! as for [`NeutrosophicJudgment`], and can be projected to a point judgment
+//! by midpoint or pessimistic projection.
+//!
+//! ```rust
+//! use opentrustprotocol::{interval_cawa, Interval, IntervalNeutrosophicJudgment};
+//!
+//! let analyst = IntervalNeutrosophicJudgment::from_source(
+//!     Interval::new(0.6, 0.8)?,
+//!     Interval::new(0.1, 0.2)?,
+//!     Interval::point(0.0)?,
+//!     "analyst",
+//!     "2023-01-01T00:00:00Z",
+//! )?;
+//! let model = IntervalNeutrosophicJudgment::from_source(
+//!     Interval::new(0.5, 0.7)?,
+//!     Interval::new(0.2, 0.3)?,
+//!     Interval::new(0.0, 0.1)?,
+//!     "model",
+//!     "2023-01-01T00:00:00Z",
+//! )?;
+//!
+//! let fused = interval_cawa(&[&analyst, &model], &[0.5, 0.5])?;
+//! assert!(fused.judgment_id.is_some());
+//!
+//! let point = fused.midpoint()?;
+//! assert!(point.t > 0.5 && point.t < 0.8);
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::conformance::{
+    canonical_entries, create_fusion_provenance_entry, seal_canonical, CanonicalEntryRef,
+};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{validate_inputs, FUSION_METADATA_VERSION};
+use crate::judgment::{validate_provenance, NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::{ensure_judgment_id, id_canonical};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Operator ID recorded by [`interval_cawa`]
+pub const INTERVAL_CAWA_OPERATOR_ID: &str = "otp-interval-cawa-v1";
+/// Operator ID recorded by [`interval_optimistic_fusion`]
+pub const INTERVAL_OPTIMISTIC_OPERATOR_ID: &str = "otp-interval-optimistic-v1";
+/// Operator ID recorded by [`interval_pessimistic_fusion`]
+pub const INTERVAL_PESSIMISTIC_OPERATOR_ID: &str = "otp-interval-pessimistic-v1";
+/// Source ID of the entry added by [`IntervalNeutrosophicJudgment::midpoint`]
+pub const MIDPOINT_PROJECTION_ID: &str = "otp-interval-midpoint-v1";
+/// Source ID of the entry added by [`IntervalNeutrosophicJudgment::pessimistic_projection`]
+pub const PESSIMISTIC_PROJECTION_ID: &str = "otp-interval-pessimistic-projection-v1";
+
+/// A closed sub-interval [lower, upper] of [0, 1]
+///
+/// Serializes as `{"lower": .., "upper": ..}`; deserializing checks the
+/// bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "IntervalData")]
+pub struct Interval {
+    lower: f64,
+    upper: f64,
+}
+
+/// Unchecked wire form of [`Interval`]
+#[derive(Deserialize)]
+struct IntervalData {
+    lower: f64,
+    upper: f64,
+}
+
+impl TryFrom<IntervalData> for Interval {
+    type Error = OpenTrustError;
+
+    fn try_from(data: IntervalData) -> Result<Self> {
+        Interval::new(data.lower, data.upper)
+    }
+}
+
+impl Interval {
+    /// Create an interval, checking 0 <= lower <= upper <= 1
+    pub fn new(lower: f64, upper: f64) -> Result<Self> {
+        Self::checked("interval", lower, upper)
+    }
+
+    /// The degenerate interval [value, value]
+    pub fn point(value: f64) -> Result<Self> {
+        Self::new(value, value)
+    }
+
+    /// Lower bound
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Upper bound
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// (lower + upper) / 2
+    pub fn midpoint(&self) -> f64 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    /// upper - lower
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Returns true if `value` lies within the interval
+    pub fn contains(&self, value: f64) -> bool {
+        (self.lower..=self.upper).contains(&value)
+    }
+
+    fn checked(field: &str, lower: f64, upper: f64) -> Result<Self> {
+        for value in [lower, upper] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(OpenTrustError::InvalidValue {
+                    field: field.to_string(),
+                    value,
+                    message: format!("{} interval bounds must be between 0 and 1", field),
+                });
+            }
+        }
+        if lower > upper {
+            return Err(OpenTrustError::InvalidValue {
+                field: field.to_string(),
+                value: lower,
+                message: format!(
+                    "{} interval lower bound must not exceed upper bound {}",
+                    field, upper
+                ),
+            });
+        }
+        Ok(Self { lower, upper })
+    }
+
+    /// Build from bounds already known to be within [0, 1] and ordered
+    fn from_bounds(lower: f64, upper: f64) -> Self {
+        Self {
+            lower: lower.min(upper),
+            upper,
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:.3}, {:.3}]", self.lower, self.upper)
+    }
+}
+
+/// A neutrosophic judgment whose T, I and F are closed intervals
+///
+/// Deserializing checks the same invariants as [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "IntervalJudgmentData")]
+pub struct IntervalNeutrosophicJudgment {
+    /// Unique identifier for the Circle of Trust, see
+    /// [`generate_interval_judgment_id`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub judgment_id: Option<String>,
+    /// Truth interval
+    pub t: Interval,
+    /// Indeterminacy interval
+    pub i: Interval,
+    /// Falsity interval
+    pub f: Interval,
+    /// Immutable audit trail
+    pub provenance_chain: Vec<ProvenanceEntry>,
+}
+
+/// Unchecked wire form of [`IntervalNeutrosophicJudgment`]
+#[derive(Deserialize)]
+struct IntervalJudgmentData {
+    #[serde(default)]
+    judgment_id: Option<String>,
+    t: Interval,
+    i: Interval,
+    f: Interval,
+    provenance_chain: Vec<ProvenanceEntry>,
+}
+
+impl TryFrom<IntervalJudgmentData> for IntervalNeutrosophicJudgment {
+    type Error = OpenTrustError;
+
+    fn try_from(data: IntervalJudgmentData) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: data.judgment_id,
+            t: data.t,
+            i: data.i,
+            f: data.f,
+            provenance_chain: data.provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+}
+
+impl IntervalNeutrosophicJudgment {
+    /// Creates a new interval judgment
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::ConservationViolation` if the lower bounds sum
+    /// to more than 1, or a provenance error if the chain is empty or an entry
+    /// lacks a source ID or timestamp.
+    pub fn new(
+        t: Interval,
+        i: Interval,
+        f: Interval,
+        provenance_chain: Vec<ProvenanceEntry>,
+    ) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: None,
+            t,
+            i,
+            f,
+            provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+
+    /// Creates an interval judgment with a single provenance entry
+    pub fn from_source(
+        t: Interval,
+        i: Interval,
+        f: Interval,
+        source_id: impl Into<String>,
+        timestamp: impl Into<String>,
+    ) -> Result<Self> {
+        Self::new(
+            t,
+            i,
+            f,
+            vec![ProvenanceEntry::new(source_id.into(), timestamp.into())],
+        )
+    }
+
+    /// The degenerate interval judgment [t, t], [i, i], [f, f] of a point judgment
+    ///
+    /// The provenance chain is kept; the judgment ID is dropped, since the ID
+    /// of an interval judgment is computed over its interval form.
+    pub fn from_point(judgment: &NeutrosophicJudgment) -> Result<Self> {
+        Self::new(
+            Interval::checked("T", judgment.t, judgment.t)?,
+            Interval::checked("I", judgment.i, judgment.i)?,
+            Interval::checked("F", judgment.f, judgment.f)?,
+            judgment.provenance_chain.clone(),
+        )
+    }
+
+    /// Re-runs the constructor checks (conservation of the lower bounds and
+    /// provenance)
+    pub fn validate(&self) -> Result<()> {
+        let (t, i, f) = (self.t.lower, self.i.lower, self.f.lower);
+        let sum = t + i + f;
+        if sum > 1.0 {
+            return Err(OpenTrustError::ConservationViolation { t, i, f, sum });
+        }
+        validate_provenance(&self.provenance_chain)
+    }
+
+    /// Returns true if the judgment passes all constructor checks
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Returns a JSON representation of the judgment
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| OpenTrustError::serialization("interval judgment", e))
+    }
+
+    /// Creates a judgment from JSON, checking its invariants
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| OpenTrustError::deserialization("interval judgment", e))
+    }
+
+    /// Projects to the point judgment at the interval midpoints
+    ///
+    /// If the midpoints sum to more than 1 they are scaled down
+    /// proportionally, as the point fusion operators do. The result carries
+    /// this judgment's provenance plus a [`MIDPOINT_PROJECTION_ID`] entry and
+    /// has its judgment ID assigned.
+    pub fn midpoint(&self) -> Result<NeutrosophicJudgment> {
+        let (t, i, f) = (self.t.midpoint(), self.i.midpoint(), self.f.midpoint());
+        let total = t + i + f;
+        let (t, i, f) = if total > 1.0 {
+            (t / total, i / total, f / total)
+        } else {
+            (t, i, f)
+        };
+        self.project(MIDPOINT_PROJECTION_ID, "Midpoint projection", t, i, f)
+    }
+
+    /// Projects to the worst case within the intervals
+    ///
+    /// T is the lower truth bound and F the upper falsity bound, lowered only
+    /// as far as the conservation constraint requires; I takes its upper
+    /// bound within what remains. The result carries this judgment's
+    /// provenance plus a [`PESSIMISTIC_PROJECTION_ID`] entry and has its
+    /// judgment ID assigned.
+    pub fn pessimistic_projection(&self) -> Result<NeutrosophicJudgment> {
+        let t = self.t.lower;
+        let f = self.f.upper.min(1.0 - t);
+        let i = self.i.upper.min(1.0 - t - f).max(0.0);
+        self.project(PESSIMISTIC_PROJECTION_ID, "Pessimistic projection", t, i, f)
+    }
+
+    fn project(
+        &self,
+        projection_id: &str,
+        label: &str,
+        t: f64,
+        i: f64,
+        f: f64,
+    ) -> Result<NeutrosophicJudgment> {
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("operator".to_string(), projection_id.into());
+        metadata.insert(
+            "interval_judgment_id".to_string(),
+            self.judgment_id.clone().into(),
+        );
+        for (name, interval) in [("t", self.t), ("i", self.i), ("f", self.f)] {
+            metadata.insert(
+                name.to_string(),
+                serde_json::json!([interval.lower, interval.upper]),
+            );
+        }
+
+        let mut provenance = self.provenance_chain.clone();
+        provenance.push(ProvenanceEntry {
+            source_id: projection_id.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            description: Some(format!("{} of interval judgment", label)),
+            metadata: Some(serde_json::Value::Object(metadata)),
+            conformance_seal: None,
+        });
+
+        ensure_judgment_id(NeutrosophicJudgment::new_with_entries(t, i, f, provenance)?)
+    }
+}
+
+impl fmt::Display for IntervalNeutrosophicJudgment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IntervalNeutrosophicJudgment(T={}, I={}, F={})",
+            self.t, self.i, self.f
+        )
+    }
+}
+
+/// Canonical interval judgment for ID generation
+#[derive(Serialize)]
+struct CanonicalIdJudgment<'a> {
+    t: Interval,
+    i: Interval,
+    f: Interval,
+    provenance_chain: Vec<CanonicalEntryRef<'a>>,
+}
+
+/// Canonical interval judgment for seal generation
+#[derive(Serialize)]
+struct CanonicalSealJudgment<'a> {
+    #[serde(rename = "T")]
+    t: Interval,
+    #[serde(rename = "I")]
+    i: Interval,
+    #[serde(rename = "F")]
+    f: Interval,
+    provenance_chain: Vec<CanonicalEntryRef<'a>>,
+}
+
+/// Generates the judgment ID of an interval judgment
+///
+/// SHA-256 of the canonical JSON of the intervals and provenance chain,
+/// excluding the judgment ID and conformance seals, as
+/// [`generate_judgment_id`](crate::generate_judgment_id) does for point
+/// judgments.
+pub fn generate_interval_judgment_id(judgment: &IntervalNeutrosophicJudgment) -> Result<String> {
+    let canonical = CanonicalIdJudgment {
+        t: judgment.t,
+        i: judgment.i,
+        f: judgment.f,
+        provenance_chain: canonical_entries(&judgment.provenance_chain),
+    };
+    id_canonical(&canonical, "interval judgment for ID generation")
+}
+
+/// Assigns the judgment ID of an interval judgment if it is missing
+pub fn ensure_interval_judgment_id(
+    mut judgment: IntervalNeutrosophicJudgment,
+) -> Result<IntervalNeutrosophicJudgment> {
+    if judgment.judgment_id.is_none() {
+        judgment.judgment_id = Some(generate_interval_judgment_id(&judgment)?);
+    }
+    Ok(judgment)
+}
+
+/// Generates a conformance seal for an interval fusion operation
+///
+/// Follows the algorithm of
+/// [`generate_conformance_seal`](crate::generate_conformance_seal), with
+/// each component serialized as its `{"lower", "upper"}` interval.
+pub fn generate_interval_conformance_seal(
+    judgments: &[&IntervalNeutrosophicJudgment],
+    weights: &[f64],
+    operator_id: &str,
+) -> Result<String> {
+    let inputs = judgments
+        .iter()
+        .map(|judgment| {
+            let key = judgment
+                .provenance_chain
+                .last()
+                .map_or("", |entry| entry.source_id.as_str());
+            let canonical = CanonicalSealJudgment {
+                t: judgment.t,
+                i: judgment.i,
+                f: judgment.f,
+                provenance_chain: canonical_entries(&judgment.provenance_chain),
+            };
+            (key, canonical)
+        })
+        .collect();
+    seal_canonical(inputs, weights, operator_id)
+}
+
+/// Verifies the conformance seal of a fused interval judgment against its inputs
+///
+/// Returns `false` on a mismatch, like
+/// [`verify_conformance_seal_with_inputs`](crate::verify_conformance_seal_with_inputs).
+pub fn verify_interval_conformance_seal_with_inputs(
+    fused_judgment: &IntervalNeutrosophicJudgment,
+    input_judgments: &[&IntervalNeutrosophicJudgment],
+    weights: &[f64],
+) -> Result<bool> {
+    let last_entry = fused_judgment
+        .provenance_chain
+        .last()
+        .ok_or(OpenTrustError::EmptyProvenanceChain)?;
+    let stored_seal = last_entry
+        .conformance_seal
+        .as_ref()
+        .ok_or(OpenTrustError::MissingSeal)?;
+    let regenerated =
+        generate_interval_conformance_seal(input_judgments, weights, &last_entry.source_id)?;
+    Ok(stored_seal == &regenerated)
+}
+
+/// Concatenates the input chains, appends the sealed fusion entry and builds
+/// the fused judgment with its ID
+fn seal_fused(
+    operator: &str,
+    judgments: &[&IntervalNeutrosophicJudgment],
+    weights: Option<&[f64]>,
+    (t, i, f): (Interval, Interval, Interval),
+) -> Result<IntervalNeutrosophicJudgment> {
+    let mut metadata = serde_json::Map::new();
+    metadata.insert("operator".to_string(), operator.into());
+    metadata.insert("input_count".to_string(), judgments.len().into());
+    metadata.insert(
+        "weights".to_string(),
+        weights.map_or(serde_json::Value::Null, |weights| weights.into()),
+    );
+    metadata.insert("version".to_string(), FUSION_METADATA_VERSION.into());
+
+    let seal_weights = weights.map_or_else(|| vec![1.0; judgments.len()], <[f64]>::to_vec);
+    let seal = generate_interval_conformance_seal(judgments, &seal_weights, operator)?;
+
+    let mut provenance = Vec::new();
+    for judgment in judgments {
+        provenance.extend(judgment.provenance_chain.clone());
+    }
+    provenance.push(create_fusion_provenance_entry(
+        operator,
+        &chrono::Utc::now().to_rfc3339(),
+        &seal,
+        Some(format!(
+            "Fusion operation using {} with Conformance Seal",
+            operator
+        )),
+        Some(serde_json::Value::Object(metadata)),
+    ));
+
+    ensure_interval_judgment_id(IntervalNeutrosophicJudgment::new(t, i, f, provenance)?)
+}
+
+/// Scales the lower bounds down proportionally if they sum to more than 1
+///
+/// Lowering a lower bound keeps every interval proper, so the upper bounds
+/// are left as they are.
+fn conserve(t: Interval, i: Interval, f: Interval) -> (Interval, Interval, Interval) {
+    let total = t.lower + i.lower + f.lower;
+    if total > 1.0 {
+        let scale =
+            |interval: Interval| Interval::from_bounds(interval.lower / total, interval.upper);
+        (scale(t), scale(i), scale(f))
+    } else {
+        (t, i, f)
+    }
+}
+
+/// Interval version of the conflict-aware weighted average
+///
+/// Each judgment's weight is reduced by its conflict score, computed at the
+/// interval midpoints (`weight * (1 - mid(T) * mid(F))`); lower and upper
+/// bounds are then averaged separately with the adjusted weights. If every
+/// adjusted weight is zero the bounds are averaged unweighted.
+///
+/// # Errors
+///
+/// The same input errors as
+/// [`conflict_aware_weighted_average`](crate::conflict_aware_weighted_average).
+pub fn interval_cawa(
+    judgments: &[&IntervalNeutrosophicJudgment],
+    weights: &[f64],
+) -> Result<IntervalNeutrosophicJudgment> {
+    validate_inputs(judgments, Some(weights))?;
+
+    let mut adjusted: Vec<f64> = judgments
+        .iter()
+        .zip(weights)
+        .map(|(judgment, &weight)| weight * (1.0 - judgment.t.midpoint() * judgment.f.midpoint()))
+        .collect();
+    if adjusted.iter().sum::<f64>() == 0.0 {
+        adjusted = vec![1.0; judgments.len()];
+    }
+    let total: f64 = adjusted.iter().sum();
+
+    let average = |component: fn(&IntervalNeutrosophicJudgment) -> Interval| {
+        let (lower, upper) = judgments.iter().zip(&adjusted).fold(
+            (0.0, 0.0),
+            |(lower, upper), (judgment, &weight)| {
+                let interval = component(judgment);
+                (
+                    lower + interval.lower * weight,
+                    upper + interval.upper * weight,
+                )
+            },
+        );
+        Interval::from_bounds((lower / total).min(1.0), (upper / total).min(1.0))
+    };
+
+    let fused = conserve(average(|j| j.t), average(|j| j.i), average(|j| j.f));
+    seal_fused(INTERVAL_CAWA_OPERATOR_ID, judgments, Some(weights), fused)
+}
+
+/// Interval version of optimistic fusion
+///
+/// T takes the largest lower and upper bounds, F the smallest, and I the
+/// average bounds; lower bounds are scaled down if they violate
+/// conservation.
+pub fn interval_optimistic_fusion(
+    judgments: &[&IntervalNeutrosophicJudgment],
+) -> Result<IntervalNeutrosophicJudgment> {
+    validate_inputs(judgments, None)?;
+    let fused = conserve(
+        extreme(judgments, |j| j.t, f64::max),
+        average_unweighted(judgments, |j| j.i),
+        extreme(judgments, |j| j.f, f64::min),
+    );
+    seal_fused(INTERVAL_OPTIMISTIC_OPERATOR_ID, judgments, None, fused)
+}
+
+/// Interval version of pessimistic fusion
+///
+/// T takes the smallest lower and upper bounds, F the largest, and I the
+/// average bounds; lower bounds are scaled down if they violate
+/// conservation.
+pub fn interval_pessimistic_fusion(
+    judgments: &[&IntervalNeutrosophicJudgment],
+) -> Result<IntervalNeutrosophicJudgment> {
+    validate_inputs(judgments, None)?;
+    let fused = conserve(
+        extreme(judgments, |j| j.t, f64::min),
+        average_unweighted(judgments, |j| j.i),
+        extreme(judgments, |j| j.f, f64::max),
+    );
+    seal_fused(INTERVAL_PESSIMISTIC_OPERATOR_ID, judgments, None, fused)
+}
+
+fn extreme(
+    judgments: &[&IntervalNeutrosophicJudgment],
+    component: fn(&IntervalNeutrosophicJudgment) -> Interval,
+    pick: fn(f64, f64) -> f64,
+) -> Interval {
+    let first = component(judgments[0]);
+    let (lower, upper) = judgments[1..]
+        .iter()
+        .map(|judgment| component(judgment))
+        .fold((first.lower, first.upper), |(lower, upper), interval| {
+            (pick(lower, interval.lower), pick(upper, interval.upper))
+        });
+    Interval::from_bounds(lower, upper)
+}
+
+fn average_unweighted(
+    judgments: &[&IntervalNeutrosophicJudgment],
+    component: fn(&IntervalNeutrosophicJudgment) -> Interval,
+) -> Interval {
+    let count = judgments.len() as f64;
+    let lower = judgments.iter().map(|j| component(j).lower).sum::<f64>() / count;
+    let upper = judgments.iter().map(|j| component(j).upper).sum::<f64>() / count;
+    Interval::from_bounds(lower, upper.min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(lower: f64, upper: f64) -> Interval {
+        Interval::new(lower, upper).unwrap()
+    }
+
+    fn judgment(
+        t: (f64, f64),
+        i: (f64, f64),
+        f: (f64, f64),
+        source: &str,
+    ) -> IntervalNeutrosophicJudgment {
+        IntervalNeutrosophicJudgment::from_source(
+            interval(t.0, t.1),
+            interval(i.0, i.1),
+            interval(f.0, f.1),
+            source,
+            "2023-01-01T00:00:00Z",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interval_and_judgment_validation() {
+        assert!(Interval::new(0.8, 0.6).is_err());
+        assert!(Interval::new(-0.1, 0.6).is_err());
+        assert!(Interval::new(0.2, f64::NAN).is_err());
+        assert_eq!(interval(0.6, 0.8).midpoint(), 0.7);
+
+        // Upper bounds may exceed 1 in total; lower bounds may not
+        let wide = judgment((0.6, 0.8), (0.1, 0.3), (0.1, 0.3), "analyst");
+        assert!(wide.is_valid());
+        assert!(matches!(
+            IntervalNeutrosophicJudgment::from_source(
+                interval(0.6, 0.8),
+                interval(0.3, 0.4),
+                interval(0.2, 0.3),
+                "analyst",
+                "2023-01-01T00:00:00Z",
+            ),
+            Err(OpenTrustError::ConservationViolation { .. })
+        ));
+        assert!(matches!(
+            IntervalNeutrosophicJudgment::new(
+                interval(0.6, 0.8),
+                interval(0.0, 0.1),
+                interval(0.0, 0.1),
+                vec![]
+            ),
+            Err(OpenTrustError::EmptyProvenanceChain)
+        ));
+    }
+
+    #[test]
+    fn test_json_round_trip_checks_invariants() {
+        let original =
+            ensure_interval_judgment_id(judgment((0.6, 0.8), (0.1, 0.2), (0.0, 0.1), "analyst"))
+                .unwrap();
+        let json = original.to_json().unwrap();
+        assert!(json.contains("\"lower\": 0.6"));
+        assert_eq!(
+            IntervalNeutrosophicJudgment::from_json(&json).unwrap(),
+            original
+        );
+
+        let reversed = r#"{"t": {"lower": 0.8, "upper": 0.6}, "i": {"lower": 0.0, "upper": 0.1},
+            "f": {"lower": 0.0, "upper": 0.1},
+            "provenance_chain": [{"source_id": "a", "timestamp": "2023-01-01T00:00:00Z"}]}"#;
+        assert!(IntervalNeutrosophicJudgment::from_json(reversed).is_err());
+        let overfull = r#"{"t": {"lower": 0.8, "upper": 0.9}, "i": {"lower": 0.3, "upper": 0.3},
+            "f": {"lower": 0.0, "upper": 0.1},
+            "provenance_chain": [{"source_id": "a", "timestamp": "2023-01-01T00:00:00Z"}]}"#;
+        assert!(IntervalNeutrosophicJudgment::from_json(overfull).is_err());
+    }
+
+    #[test]
+    fn test_degenerate_intervals_match_point_ids() {
+        let point = ensure_judgment_id(
+            NeutrosophicJudgment::new(
+                0.8,
+                0.2,
+                0.0,
+                vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let lifted = IntervalNeutrosophicJudgment::from_point(&point).unwrap();
+        assert_eq!(lifted.t, Interval::point(0.8).unwrap());
+        assert_eq!(lifted.provenance_chain, point.provenance_chain);
+        assert_eq!(lifted.judgment_id, None);
+
+        let id = generate_interval_judgment_id(&lifted).unwrap();
+        assert_eq!(id.len(), 64);
+        assert_ne!(id, crate::generate_judgment_id(&point).unwrap());
+        assert_eq!(id, generate_interval_judgment_id(&lifted.clone()).unwrap());
+    }
+
+    #[test]
+    fn test_interval_cawa_bounds_and_seal() {
+        let a = judgment((0.6, 0.8), (0.1, 0.2), (0.0, 0.0), "analyst");
+        let b = judgment((0.4, 0.6), (0.2, 0.3), (0.1, 0.2), "model");
+
+        let fused = interval_cawa(&[&a, &b], &[0.5, 0.5]).unwrap();
+        assert!(fused.is_valid());
+        assert!(fused.t.lower() > 0.4 && fused.t.lower() < 0.6);
+        assert!(fused.t.upper() > 0.6 && fused.t.upper() < 0.8);
+        assert_eq!(fused.provenance_chain.len(), 3);
+        assert_eq!(
+            fused.judgment_id,
+            Some(generate_interval_judgment_id(&fused).unwrap())
+        );
+
+        assert!(
+            verify_interval_conformance_seal_with_inputs(&fused, &[&a, &b], &[0.5, 0.5]).unwrap()
+        );
+        assert!(
+            !verify_interval_conformance_seal_with_inputs(&fused, &[&a, &b], &[0.6, 0.4]).unwrap()
+        );
+
+        // Order of inputs does not change the seal
+        assert_eq!(
+            generate_interval_conformance_seal(&[&a, &b], &[1.0, 1.0], INTERVAL_CAWA_OPERATOR_ID)
+                .unwrap(),
+            generate_interval_conformance_seal(&[&b, &a], &[1.0, 1.0], INTERVAL_CAWA_OPERATOR_ID)
+                .unwrap()
+        );
+        assert!(matches!(
+            interval_cawa(&[&a], &[0.0]),
+            Err(OpenTrustError::AllWeightsZero)
+        ));
+    }
+
+    #[test]
+    fn test_interval_optimistic_and_pessimistic() {
+        let a = judgment((0.6, 0.8), (0.1, 0.2), (0.0, 0.1), "analyst");
+        let b = judgment((0.4, 0.7), (0.1, 0.3), (0.1, 0.2), "model");
+
+        let optimistic = interval_optimistic_fusion(&[&a, &b]).unwrap();
+        assert_eq!(optimistic.t, interval(0.6, 0.8));
+        assert_eq!(optimistic.f, interval(0.0, 0.1));
+        assert!(
+            verify_interval_conformance_seal_with_inputs(&optimistic, &[&a, &b], &[1.0, 1.0])
+                .unwrap()
+        );
+
+        let pessimistic = interval_pessimistic_fusion(&[&a, &b]).unwrap();
+        assert_eq!(pessimistic.t, interval(0.4, 0.7));
+        assert_eq!(pessimistic.f, interval(0.1, 0.2));
+        assert_eq!(
+            pessimistic.provenance_chain.last().unwrap().source_id,
+            INTERVAL_PESSIMISTIC_OPERATOR_ID
+        );
+
+        // Lower bounds that would violate conservation are scaled down
+        let c = judgment((0.9, 1.0), (0.1, 0.1), (0.0, 0.0), "c");
+        let d = judgment((0.0, 0.1), (0.3, 0.4), (0.0, 0.1), "d");
+        let scaled = interval_optimistic_fusion(&[&c, &d]).unwrap();
+        assert!(scaled.is_valid());
+        assert!(scaled.t.lower() < 0.9);
+        assert_eq!(scaled.t.upper(), 1.0);
+    }
+
+    #[test]
+    fn test_projections() {
+        let wide =
+            ensure_interval_judgment_id(judgment((0.6, 0.8), (0.1, 0.3), (0.1, 0.3), "analyst"))
+                .unwrap();
+
+        let midpoint = wide.midpoint().unwrap();
+        assert!(midpoint.is_valid());
+        // Midpoints 0.7 + 0.2 + 0.2 = 1.1 are scaled to sum to 1
+        assert!((midpoint.total() - 1.0).abs() < 1e-9);
+        assert!(midpoint.judgment_id.is_some());
+        let entry = midpoint.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, MIDPOINT_PROJECTION_ID);
+        assert_eq!(
+            entry.metadata.as_ref().unwrap()["interval_judgment_id"],
+            serde_json::json!(wide.judgment_id)
+        );
+
+        let pessimistic = wide.pessimistic_projection().unwrap();
+        assert_eq!(pessimistic.t, 0.6);
+        assert_eq!(pessimistic.f, 0.3);
+        assert!((pessimistic.i - 0.1).abs() < 1e-9);
+        assert_eq!(
+            pessimistic.provenance_chain.last().unwrap().source_id,
+            PESSIMISTIC_PROJECTION_ID
+        );
+    }
+}
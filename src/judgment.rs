@@ -97,7 +97,7 @@ impl NeutrosophicJudgment {
         f: f64,
         provenance_chain: Vec<ProvenanceEntry>,
     ) -> Result<Self> {
-        Self::validate(t, i, f, &provenance_chain)?;
+        Self::validate_parts(t, i, f, &provenance_chain)?;
 
         Ok(Self {
             judgment_id: None, // Will be generated later if needed
@@ -108,72 +108,54 @@ impl NeutrosophicJudgment {
         })
     }
 
-    /// Validates the judgment parameters
-    fn validate(t: f64, i: f64, f: f64, provenance_chain: &[ProvenanceEntry]) -> Result<()> {
-        // Range validation
-        if !(0.0..=1.0).contains(&t) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "T".to_string(),
-                value: t,
-                message: "T value must be between 0 and 1".to_string(),
-            });
-        }
-        if !(0.0..=1.0).contains(&i) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "I".to_string(),
-                value: i,
-                message: "I value must be between 0 and 1".to_string(),
-            });
-        }
-        if !(0.0..=1.0).contains(&f) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "F".to_string(),
-                value: f,
-                message: "F value must be between 0 and 1".to_string(),
-            });
-        }
-
-        // Conservation constraint validation
-        let sum = t + i + f;
-        if sum > 1.0 {
-            return Err(OpenTrustError::ConservationViolation { t, i, f, sum });
-        }
-
-        // Provenance chain validation
-        if provenance_chain.is_empty() {
-            return Err(OpenTrustError::EmptyProvenanceChain);
-        }
-
-        for (index, entry) in provenance_chain.iter().enumerate() {
-            if entry.source_id.trim().is_empty() {
-                return Err(OpenTrustError::InvalidProvenanceEntry {
-                    index,
-                    message: "Provenance entry must have source_id".to_string(),
-                });
-            }
-            if entry.timestamp.trim().is_empty() {
-                return Err(OpenTrustError::InvalidProvenanceEntry {
-                    index,
-                    message: "Provenance entry must have timestamp".to_string(),
-                });
-            }
-        }
+    /// Re-runs the constructor checks (ranges, conservation, provenance)
+    ///
+    /// Judgments built field by field or deserialized as
+    /// `NeutrosophicJudgment` are not checked; use
+    /// [`Judgment`](crate::Judgment) to have invalid values rejected when
+    /// parsing.
+    pub fn validate(&self) -> Result<()> {
+        Self::validate_parts(self.t, self.i, self.f, &self.provenance_chain)
+    }
 
-        Ok(())
+    /// Validates the judgment parameters
+    fn validate_parts(t: f64, i: f64, f: f64, provenance_chain: &[ProvenanceEntry]) -> Result<()> {
+        validate_components(t, i, f)?;
+        validate_provenance(provenance_chain)
     }
 
     /// Returns a JSON representation of the judgment
     pub fn to_json(&self) -> Result<String> {
-        serde_json::to_string_pretty(self).map_err(|e| OpenTrustError::InvalidFusionInput {
-            message: format!("Failed to serialize judgment: {}", e),
-        })
+        serde_json::to_string_pretty(self).map_err(|e| OpenTrustError::serialization("judgment", e))
     }
 
     /// Creates a judgment from JSON
     pub fn from_json(json: &str) -> Result<Self> {
-        serde_json::from_str(json).map_err(|e| OpenTrustError::InvalidFusionInput {
-            message: format!("Failed to deserialize judgment: {}", e),
-        })
+        serde_json::from_str(json).map_err(|e| OpenTrustError::deserialization("judgment", e))
+    }
+
+    /// Returns the deterministic CBOR encoding of the judgment
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        crate::encoding::to_cbor(self)
+    }
+
+    /// Creates a judgment from CBOR
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        crate::encoding::from_cbor(bytes)
+    }
+
+    /// Returns the deterministic MessagePack encoding of the judgment
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Result<Vec<u8>> {
+        crate::encoding::to_msgpack(self)
+    }
+
+    /// Creates a judgment from MessagePack
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self> {
+        crate::encoding::from_msgpack(bytes)
     }
 
     /// Checks if this judgment is equal to another (within epsilon tolerance)
@@ -189,12 +171,73 @@ impl NeutrosophicJudgment {
         self.t + self.i + self.f
     }
 
-    /// Returns true if the judgment satisfies conservation constraint
+    /// Returns true if the judgment passes all constructor checks
+    /// (ranges, conservation constraint and provenance chain)
     pub fn is_valid(&self) -> bool {
-        self.total() <= 1.0
+        self.validate().is_ok()
     }
 }
 
+/// Checks that T, I and F are in [0, 1] and that T + I + F <= 1
+pub(crate) fn validate_components(t: f64, i: f64, f: f64) -> Result<()> {
+    // Range validation
+    if !(0.0..=1.0).contains(&t) {
+        return Err(OpenTrustError::InvalidValue {
+            field: "T".to_string(),
+            value: t,
+            message: "T value must be between 0 and 1".to_string(),
+        });
+    }
+    if !(0.0..=1.0).contains(&i) {
+        return Err(OpenTrustError::InvalidValue {
+            field: "I".to_string(),
+            value: i,
+            message: "I value must be between 0 and 1".to_string(),
+        });
+    }
+    if !(0.0..=1.0).contains(&f) {
+        return Err(OpenTrustError::InvalidValue {
+            field: "F".to_string(),
+            value: f,
+            message: "F value must be between 0 and 1".to_string(),
+        });
+    }
+
+    // Conservation constraint validation
+    let sum = t + i + f;
+    if sum > 1.0 {
+        return Err(OpenTrustError::ConservationViolation { t, i, f, sum });
+    }
+
+    Ok(())
+}
+
+/// Checks that the provenance chain is non-empty and every entry has a
+/// source ID and timestamp
+pub(crate) fn validate_provenance(provenance_chain: &[ProvenanceEntry]) -> Result<()> {
+    // Provenance chain validation
+    if provenance_chain.is_empty() {
+        return Err(OpenTrustError::EmptyProvenanceChain);
+    }
+
+    for (index, entry) in provenance_chain.iter().enumerate() {
+        if entry.source_id.trim().is_empty() {
+            return Err(OpenTrustError::InvalidProvenanceEntry {
+                index,
+                message: "Provenance entry must have source_id".to_string(),
+            });
+        }
+        if entry.timestamp.trim().is_empty() {
+            return Err(OpenTrustError::InvalidProvenanceEntry {
+                index,
+                message: "Provenance entry must have timestamp".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 impl fmt::Display for NeutrosophicJudgment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -205,6 +248,29 @@ impl fmt::Display for NeutrosophicJudgment {
     }
 }
 
+/// Judgment fixtures shared by the unit tests
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::NeutrosophicJudgment;
+
+    /// A judgment from `source` at 2023-01-01T00:00:00Z
+    pub(crate) fn judgment(t: f64, i: f64, f: f64, source: &str) -> NeutrosophicJudgment {
+        judgment_at(t, i, f, source, "2023-01-01T00:00:00Z")
+    }
+
+    /// A judgment from `source` at `timestamp`
+    pub(crate) fn judgment_at(
+        t: f64,
+        i: f64,
+        f: f64,
+        source: &str,
+        timestamp: &str,
+    ) -> NeutrosophicJudgment {
+        NeutrosophicJudgment::new(t, i, f, vec![(source.to_string(), timestamp.to_string())])
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,6 +337,14 @@ mod tests {
         let deserialized = NeutrosophicJudgment::from_json(&json).unwrap();
 
         assert!(judgment.equals(&deserialized, 1e-10));
+
+        match NeutrosophicJudgment::from_json("{\"t\": 0.5}") {
+            Err(OpenTrustError::Deserialization { what, source }) => {
+                assert_eq!(what, "judgment");
+                assert!(source.is_data());
+            }
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
     }
 }
 
@@ -7,6 +7,7 @@
 
 use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
 use crate::error::{OpenTrustError, Result};
+use crate::validated::Judgment;
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use std::fmt;
@@ -87,6 +88,9 @@ impl OutcomeJudgment {
     
     /// Converts this Outcome Judgment to a regular Neutrosophic Judgment
     /// (without the oracle-specific fields)
+    ///
+    /// The result is not validated: outcomes may have an empty provenance
+    /// chain. Use [`to_judgment`](Self::to_judgment) for a checked conversion.
     pub fn to_neutrosophic_judgment(&self) -> NeutrosophicJudgment {
         NeutrosophicJudgment {
             judgment_id: Some(self.judgment_id.clone()),
@@ -97,38 +101,16 @@ impl OutcomeJudgment {
         }
     }
     
+    /// Converts this Outcome Judgment to a validated [`Judgment`]
+    ///
+    /// Fails if the outcome has no (or an invalid) provenance chain.
+    pub fn to_judgment(&self) -> Result<Judgment> {
+        Judgment::try_from(self.to_neutrosophic_judgment())
+    }
+
     /// Validates the outcome judgment parameters
     fn validate(t: f64, i: f64, f: f64) -> Result<()> {
-        // Range validation
-        if !(0.0..=1.0).contains(&t) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "T".to_string(),
-                value: t,
-                message: "T value must be between 0 and 1".to_string(),
-            });
-        }
-        if !(0.0..=1.0).contains(&i) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "I".to_string(),
-                value: i,
-                message: "I value must be between 0 and 1".to_string(),
-            });
-        }
-        if !(0.0..=1.0).contains(&f) {
-            return Err(OpenTrustError::InvalidValue {
-                field: "F".to_string(),
-                value: f,
-                message: "F value must be between 0 and 1".to_string(),
-            });
-        }
-
-        // Conservation constraint validation
-        let sum = t + i + f;
-        if sum > 1.0 {
-            return Err(OpenTrustError::ConservationViolation { t, i, f, sum });
-        }
-
-        Ok(())
+        crate::judgment::validate_components(t, i, f)
     }
 }
 
@@ -177,18 +159,20 @@ pub fn generate_judgment_id(judgment: &NeutrosophicJudgment) -> Result<String> {
         }).collect(),
     };
     
-    // Serialize to canonical JSON
-    let canonical_json = serde_json::to_string(&canonical)
-        .map_err(|e| OpenTrustError::SerializationError {
-            message: format!("Failed to serialize judgment for ID generation: {}", e),
-        })?;
-    
-    // Generate SHA-256 hash
+    id_canonical(&canonical, "judgment for ID generation")
+}
+
+/// Generates a judgment ID over a canonical judgment of any kind
+///
+/// Hex-encoded SHA-256 of the canonical JSON; `what` names the judgment in
+/// serialization errors.
+pub(crate) fn id_canonical<J: Serialize>(canonical: &J, what: &'static str) -> Result<String> {
+    let canonical_json =
+        serde_json::to_string(canonical).map_err(|e| OpenTrustError::serialization(what, e))?;
+
     let mut hasher = Sha256::new();
     hasher.update(canonical_json.as_bytes());
-    let hash = hasher.finalize();
-    
-    Ok(format!("{:x}", hash))
+    Ok(format!("{:x}", hasher.finalize()))
 }
 
 /// Canonical representation of a Neutrosophic Judgment for ID generation
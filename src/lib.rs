@@ -64,37 +64,98 @@
 //! println!("Fused judgment: {}", fused);
 //! ```
 
+pub mod algebra;
+pub mod builder;
 pub mod conformance;
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub mod encoding;
 pub mod error;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod fusion;
+pub mod interval;
 pub mod judgment;
 pub mod judgment_id;
 pub mod mapper;
+pub mod paraconsistent;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod refined;
+pub mod rules;
+pub mod schema;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod subjective;
+pub mod update;
+pub mod validated;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Re-export main types and functions
+pub use algebra::{intersection_operator_id, union_operator_id, NormFamily};
+pub use builder::{JudgmentBuilder, ProvenanceEntryBuilder};
 pub use conformance::{
-    generate_conformance_seal, verify_conformance_seal, verify_conformance_seal_with_inputs,
+    check_conformance_seal_with_inputs, generate_conformance_seal, recorded_seal_weights,
+    verify_conformance_seal, verify_conformance_seal_with_inputs,
     create_fusion_provenance_entry,
 };
 pub use error::{OpenTrustError, Result};
-pub use fusion::{conflict_aware_weighted_average, optimistic_fusion, pessimistic_fusion};
+pub use explain::{
+    explain_fusion, fuse_explained, ComponentValues, FusionExplanation, InputExplanation,
+};
+pub use fusion::{
+    conflict_aware_weighted_average, fuse, optimistic_fusion, pessimistic_fusion, CAWA_OPERATOR_ID,
+    OPTIMISTIC_OPERATOR_ID, PESSIMISTIC_OPERATOR_ID,
+};
+pub use interval::{
+    ensure_interval_judgment_id, generate_interval_conformance_seal, generate_interval_judgment_id,
+    interval_cawa, interval_optimistic_fusion, interval_pessimistic_fusion,
+    verify_interval_conformance_seal_with_inputs, Interval, IntervalNeutrosophicJudgment,
+    INTERVAL_CAWA_OPERATOR_ID, INTERVAL_OPTIMISTIC_OPERATOR_ID, INTERVAL_PESSIMISTIC_OPERATOR_ID,
+};
 pub use judgment::NeutrosophicJudgment;
+pub use paraconsistent::{
+    generate_paraconsistent_conformance_seal, generate_paraconsistent_judgment_id,
+    ConservationMode, FusionPipeline, Normalization, ParaconsistentJudgment,
+};
+pub use refined::{
+    ensure_refined_judgment_id, generate_refined_conformance_seal, generate_refined_judgment_id,
+    refined_cawa, verify_collapse, CollapseMethod, Components, RefinedNeutrosophicJudgment,
+    REFINED_CAWA_OPERATOR_ID,
+};
+pub use rules::{
+    load_rule_set_dir, load_rule_set_file, Expr, Rule, RuleSet, RULE_OPERATOR_PREFIX,
+};
+pub use subjective::{
+    recorded_base_rate, subjective_averaging_fusion, subjective_cumulative_fusion, Opinion,
+    DEFAULT_BASE_RATE, OPINION_CONVERSION_ID, SL_AVERAGING_OPERATOR_ID, SL_CUMULATIVE_OPERATOR_ID,
+};
+pub use update::{
+    update, UpdateModel, COUNT_UPDATE_OPERATOR_ID, DECAYED_UPDATE_OPERATOR_ID,
+    DEFAULT_PRIOR_WEIGHT,
+};
+pub use validated::{Judgment, Tif};
 pub use judgment_id::{
     generate_judgment_id, ensure_judgment_id, OutcomeJudgment, OutcomeType,
 };
 
 // Re-export mapper types and functions
 pub use mapper::{
-    create_judgment, create_timestamp, get_global_registry, normalize_boolean_input,
-    reset_global_registry, validate_judgment_values, BaseMapperParams, BooleanMapper,
-    BooleanParams, CategoricalMapper, CategoricalParams, InputError, Mapper, MapperError,
-    MapperParams, MapperRegistry, MapperType, MapperValidator, NumericalMapper, NumericalParams,
-    ProvenanceEntry, ValidationError,
+    build_mapper, create_judgment, create_timestamp, get_global_registry, load_mapper_dir,
+    load_mapper_file, normalize_boolean_input, normalize_json_boolean_input,
+    reset_global_registry, resolve_json_input, save_mapper_dir, save_mapper_file,
+    validate_judgment_values, BaseMapperParams, BooleanMapper, BooleanParams, CalibrationCurve,
+    CalibrationMapper, CalibrationMethod, CalibrationOptions, CalibrationParams,
+    CategoricalMapper, CategoricalParams, ConfigError, ConfigIssue, CurvePoint, InputError,
+    Mapper, MapperError, MapperParams, MapperDirWatcher, MapperRegistry, MapperType,
+    MapperValidator, NumericalMapper, NumericalParams, ProvenanceEntry, SupportBin,
+    ValidationError, WatcherHandle,
 };
 
 // Re-export mapper sub-types
 pub use mapper::types::JudgmentData;
-pub use mapper::validator::ValidationResult;
+pub use mapper::validator::{IssueCode, Severity, ValidationIssue, ValidationResult};
 
 /// Current version of the OpenTrust Protocol SDK
 /// 
@@ -2,8 +2,9 @@
 
 use crate::judgment::NeutrosophicJudgment;
 use crate::mapper::types::{
-    create_judgment, create_timestamp, normalize_boolean_input, validate_judgment_values,
-    BooleanParams, Mapper, MapperType, ProvenanceEntry, ValidationError,
+    create_judgment, create_timestamp, insert_mapper_identity, normalize_boolean_input,
+    resolve_json_input, validate_judgment_values, BooleanParams, Mapper, MapperType,
+    ProvenanceEntry, ValidationError,
 };
 #[cfg(test)]
 use crate::mapper::types::{BaseMapperParams, JudgmentData};
@@ -58,6 +59,7 @@ impl BooleanMapper {
             "mapper_type".to_string(),
             serde_json::Value::String("boolean".to_string()),
         );
+        insert_mapper_identity(&mut metadata, &self.params.base);
         metadata.insert(
             "normalized".to_string(),
             serde_json::Value::String(normalized.to_string()),
@@ -82,6 +84,8 @@ impl BooleanMapper {
                 "original_input".to_string(),
                 serde_json::Value::String(val.clone()),
             );
+        } else if let Some(val) = input_value.downcast_ref::<serde_json::Value>() {
+            metadata.insert("original_input".to_string(), val.clone());
         }
 
         ProvenanceEntry {
@@ -95,11 +99,21 @@ impl BooleanMapper {
 
     /// Apply the mapper to a boolean input
     pub fn apply(&self, input_value: &dyn std::any::Any) -> crate::Result<NeutrosophicJudgment> {
-        let normalized_input = normalize_boolean_input(input_value).map_err(|e| {
-            crate::error::OpenTrustError::InvalidFusionInput {
-                message: e.to_string(),
-            }
-        })?;
+        if let Some(json) = input_value.downcast_ref::<serde_json::Value>() {
+            return self.apply_json(json);
+        }
+        self.apply_value(input_value)
+    }
+
+    /// Apply the mapper to a JSON input, following `input_path` if set
+    pub fn apply_json(&self, input: &serde_json::Value) -> crate::Result<NeutrosophicJudgment> {
+        let value = resolve_json_input(&self.params.base, input)?;
+        self.apply_value(value)
+    }
+
+    /// Map an already-resolved input value
+    fn apply_value(&self, input_value: &dyn std::any::Any) -> crate::Result<NeutrosophicJudgment> {
+        let normalized_input = normalize_boolean_input(input_value)?;
 
         let judgment_data = if normalized_input {
             &self.params.true_map
@@ -132,10 +146,7 @@ impl Mapper for BooleanMapper {
     }
 
     fn validate(&self) -> crate::Result<()> {
-        self.validate_parameters()
-            .map_err(|e| crate::error::OpenTrustError::InvalidFusionInput {
-                message: e.to_string(),
-            })
+        self.validate_parameters().map_err(Into::into)
     }
 }
 
@@ -152,6 +163,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -178,6 +191,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 1.5, // Invalid - should fail
@@ -204,6 +219,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -243,6 +260,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -278,6 +297,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -313,6 +334,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -332,4 +355,50 @@ mod tests {
         let result = mapper.apply(&"invalid".to_string());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_boolean_mapper_apply_json() {
+        let params = BooleanParams {
+            base: BaseMapperParams {
+                id: "test-boolean".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Boolean,
+                description: None,
+                metadata: None,
+                input_path: Some("/tls/valid".to_string()),
+                deprecated: false,
+            },
+            true_map: JudgmentData {
+                T: 0.9,
+                I: 0.1,
+                F: 0.0,
+            },
+            false_map: JudgmentData {
+                T: 0.0,
+                I: 0.1,
+                F: 0.9,
+            },
+        };
+
+        let mapper = BooleanMapper::new(params).unwrap();
+
+        let judgment1 = mapper
+            .apply(&serde_json::json!({"tls": {"valid": true}}))
+            .unwrap();
+        assert_eq!(judgment1.t, 0.9);
+
+        let judgment2 = mapper
+            .apply(&serde_json::json!({"tls": {"valid": "no"}}))
+            .unwrap();
+        assert_eq!(judgment2.f, 0.9);
+
+        let judgment3 = mapper
+            .apply(&serde_json::json!({"tls": {"valid": 1}}))
+            .unwrap();
+        assert_eq!(judgment3.t, 0.9);
+
+        // Wrong JSON type
+        let result = mapper.apply(&serde_json::json!({"tls": {"valid": [true]}}));
+        assert!(result.is_err());
+    }
 }
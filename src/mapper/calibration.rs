@@ -0,0 +1,694 @@
+//! CalibrationMapper implementation for turning model scores into calibrated judgments
+//!
+//! The mapper is fitted from historical `(score, OutcomeType)` pairs, with
+//! `Success` counting as 1, `Failure` as 0 and `Partial` as 0.5. The fitted
+//! curve gives the probability p of success for a score; the number of
+//! training samples n near that score gives the indeterminacy:
+//!
+//! ```text
+//! I = W / (n + W),  T = p · (1 - I),  F = (1 - p) · (1 - I)
+//! ```
+//!
+//! where W is the prior weight, so regions with little training data (and
+//! scores outside the training range) map to high I. The fitted parameters
+//! serialize as [`MapperParams::Calibration`](crate::MapperParams), so a
+//! calibration mapper is registered, versioned and stored like any other.
+
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::OutcomeType;
+use crate::mapper::types::{
+    create_judgment, create_timestamp, insert_mapper_identity, json_type_name, resolve_json_input,
+    BaseMapperParams, CalibrationCurve, CalibrationParams, CurvePoint, InputError, Mapper,
+    MapperType, ProvenanceEntry, SupportBin, ValidationError,
+};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Fitting method for [`CalibrationMapper::fit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalibrationMethod {
+    /// Isotonic regression (pool adjacent violators)
+    #[default]
+    Isotonic,
+    /// Platt scaling (logistic fit)
+    Platt,
+}
+
+/// Options for [`CalibrationMapper::fit`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalibrationOptions {
+    /// Fitting method
+    pub method: CalibrationMethod,
+    /// Number of equal-width support bins over the training score range
+    pub bins: usize,
+    /// Evidence weight of the unknown (W)
+    pub prior_weight: f64,
+}
+
+impl Default for CalibrationOptions {
+    fn default() -> Self {
+        Self {
+            method: CalibrationMethod::Isotonic,
+            bins: 10,
+            prior_weight: 2.0,
+        }
+    }
+}
+
+/// CalibrationMapper for transforming model scores into Neutrosophic Judgments
+pub struct CalibrationMapper {
+    params: CalibrationParams,
+}
+
+impl CalibrationMapper {
+    /// Create a new CalibrationMapper from fitted parameters
+    pub fn new(params: CalibrationParams) -> Result<Self, ValidationError> {
+        let mapper = Self { params };
+        mapper.validate_parameters()?;
+        Ok(mapper)
+    }
+
+    /// Fit a CalibrationMapper from historical `(score, outcome)` pairs
+    ///
+    /// `base.mapper_type` is set to `Calibration`. The training data hash is
+    /// computed over the pairs sorted by score and outcome, so it does not
+    /// depend on their order.
+    pub fn fit(
+        mut base: BaseMapperParams,
+        samples: &[(f64, OutcomeType)],
+        options: CalibrationOptions,
+    ) -> Result<Self, ValidationError> {
+        if samples.is_empty() {
+            return Err(ValidationError::MissingParameter {
+                param: "samples".to_string(),
+            });
+        }
+        if let Some((score, _)) = samples.iter().find(|(score, _)| !score.is_finite()) {
+            return Err(ValidationError::InvalidParameter {
+                param: "samples".to_string(),
+                message: format!("score {} is not finite", score),
+            });
+        }
+        if options.bins == 0 {
+            return Err(ValidationError::InvalidParameter {
+                param: "bins".to_string(),
+                message: "at least one support bin is needed".to_string(),
+            });
+        }
+
+        let mut sorted: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|(score, outcome)| (*score, outcome_value(outcome)))
+            .collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).expect("scores are finite"));
+
+        let curve = match options.method {
+            CalibrationMethod::Isotonic => CalibrationCurve::Isotonic {
+                points: fit_isotonic(&sorted),
+            },
+            CalibrationMethod::Platt => {
+                let (a, b) = fit_platt(&sorted);
+                CalibrationCurve::Platt { a, b }
+            }
+        };
+
+        base.mapper_type = MapperType::Calibration;
+        Self::new(CalibrationParams {
+            base,
+            curve,
+            support: support_bins(&sorted, options.bins),
+            prior_weight: options.prior_weight,
+            sample_count: samples.len(),
+            training_data_hash: training_data_hash(samples),
+        })
+    }
+
+    /// The fitted parameters
+    pub fn params(&self) -> &CalibrationParams {
+        &self.params
+    }
+
+    /// Validate the mapper parameters
+    fn validate_parameters(&self) -> Result<(), ValidationError> {
+        let invalid = |param: &str, message: String| ValidationError::InvalidParameter {
+            param: param.to_string(),
+            message,
+        };
+        let params = &self.params;
+
+        if !params.prior_weight.is_finite() || params.prior_weight <= 0.0 {
+            return Err(invalid(
+                "prior_weight",
+                format!("must be positive, got {}", params.prior_weight),
+            ));
+        }
+        if let Some(message) = curve_problem(&params.curve) {
+            return Err(invalid("curve", message));
+        }
+        if let Some(message) = support_problem(&params.support) {
+            return Err(invalid("support", message));
+        }
+        if params.training_data_hash.len() != 64
+            || !params
+                .training_data_hash
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        {
+            return Err(invalid(
+                "training_data_hash",
+                "must be a lowercase hex SHA-256 digest".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Calibrated probability of success for a score
+    pub fn probability(&self, score: f64) -> f64 {
+        match &self.params.curve {
+            CalibrationCurve::Isotonic { points } => interpolate(points, score),
+            CalibrationCurve::Platt { a, b } => 1.0 / (1.0 + (-(a * score + b)).exp()),
+        }
+    }
+
+    /// Training samples in the support bin containing a score (0 outside
+    /// the training range)
+    pub fn support(&self, score: f64) -> usize {
+        bin_index(&self.params.support, score).map_or(0, |index| self.params.support[index].count)
+    }
+
+    /// Create provenance entry for the transformation
+    fn create_provenance_entry(
+        &self,
+        input_value: f64,
+        probability: f64,
+        support: usize,
+    ) -> ProvenanceEntry {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "mapper_type".to_string(),
+            serde_json::Value::String("calibration".to_string()),
+        );
+        insert_mapper_identity(&mut metadata, &self.params.base);
+        let method = match self.params.curve {
+            CalibrationCurve::Isotonic { .. } => "isotonic",
+            CalibrationCurve::Platt { .. } => "platt",
+        };
+        metadata.insert("method".to_string(), method.into());
+        metadata.insert("input_value".to_string(), input_value.into());
+        metadata.insert("probability".to_string(), probability.into());
+        metadata.insert("support".to_string(), support.into());
+        metadata.insert("sample_count".to_string(), self.params.sample_count.into());
+        metadata.insert(
+            "training_data_hash".to_string(),
+            self.params.training_data_hash.clone().into(),
+        );
+
+        ProvenanceEntry {
+            source_id: self.params.base.id.clone(),
+            timestamp: create_timestamp(),
+            description: Some(format!("Calibrated mapping of score {}", input_value)),
+            metadata: Some(metadata),
+            conformance_seal: None,
+        }
+    }
+
+    /// Apply the mapper to a score
+    pub fn apply(&self, input_value: f64) -> crate::Result<NeutrosophicJudgment> {
+        if !input_value.is_finite() {
+            return Err(InputError::OutOfRange {
+                value: format!("{} is not a finite score", input_value),
+            }
+            .into());
+        }
+
+        let probability = self.probability(input_value).clamp(0.0, 1.0);
+        let support = self.support(input_value);
+        #[allow(non_snake_case)]
+        let I = self.params.prior_weight / (support as f64 + self.params.prior_weight);
+        #[allow(non_snake_case)]
+        let T = probability * (1.0 - I);
+        #[allow(non_snake_case)]
+        let F = (1.0 - I - T).max(0.0);
+
+        let provenance_entry = self.create_provenance_entry(input_value, probability, support);
+        create_judgment(T, I, F, vec![provenance_entry])
+    }
+
+    /// Apply the mapper to a JSON input, following `input_path` if set
+    pub fn apply_json(&self, input: &serde_json::Value) -> crate::Result<NeutrosophicJudgment> {
+        let value = resolve_json_input(&self.params.base, input)?;
+        let number = value.as_f64().ok_or_else(|| InputError::InvalidType {
+            expected: "number".to_string(),
+            actual: json_type_name(value).to_string(),
+        })?;
+        self.apply(number)
+    }
+}
+
+impl Mapper for CalibrationMapper {
+    fn apply(&self, input: &dyn std::any::Any) -> crate::Result<NeutrosophicJudgment> {
+        if let Some(value) = input.downcast_ref::<f64>() {
+            self.apply(*value)
+        } else if let Some(value) = input.downcast_ref::<i32>() {
+            self.apply(*value as f64)
+        } else if let Some(value) = input.downcast_ref::<i64>() {
+            self.apply(*value as f64)
+        } else if let Some(value) = input.downcast_ref::<serde_json::Value>() {
+            self.apply_json(value)
+        } else {
+            Err(crate::error::OpenTrustError::InputType {
+                expected: "number".to_string(),
+                actual: std::any::type_name_of_val(input).to_string(),
+            })
+        }
+    }
+
+    fn get_params(&self) -> &dyn std::any::Any {
+        &self.params
+    }
+
+    fn get_type(&self) -> MapperType {
+        MapperType::Calibration
+    }
+
+    fn validate(&self) -> crate::Result<()> {
+        self.validate_parameters().map_err(Into::into)
+    }
+}
+
+/// Problem with a calibration curve, if any
+pub(crate) fn curve_problem(curve: &CalibrationCurve) -> Option<String> {
+    match curve {
+        CalibrationCurve::Isotonic { points } => {
+            if points.is_empty() {
+                return Some("isotonic curve has no points".to_string());
+            }
+            if let Some(point) = points
+                .iter()
+                .find(|point| !point.score.is_finite() || !(0.0..=1.0).contains(&point.probability))
+            {
+                return Some(format!(
+                    "point ({}, {}) needs a finite score and a probability in [0, 1]",
+                    point.score, point.probability
+                ));
+            }
+            points.windows(2).find_map(|pair| {
+                (pair[1].score < pair[0].score || pair[1].probability < pair[0].probability).then(
+                    || {
+                        format!(
+                            "isotonic points must be non-decreasing in score and probability, \
+                             but ({}, {}) follows ({}, {})",
+                            pair[1].score, pair[1].probability, pair[0].score, pair[0].probability
+                        )
+                    },
+                )
+            })
+        }
+        CalibrationCurve::Platt { a, b } => (!a.is_finite() || !b.is_finite())
+            .then(|| format!("Platt coefficients must be finite, got a={}, b={}", a, b)),
+    }
+}
+
+/// Problem with support bins, if any
+pub(crate) fn support_problem(support: &[SupportBin]) -> Option<String> {
+    if let Some(bin) = support
+        .iter()
+        .find(|bin| !bin.lower.is_finite() || !bin.upper.is_finite() || bin.lower > bin.upper)
+    {
+        return Some(format!(
+            "bin [{}, {}] is not a finite range",
+            bin.lower, bin.upper
+        ));
+    }
+    support.windows(2).find_map(|pair| {
+        (pair[1].lower < pair[0].upper).then(|| {
+            format!(
+                "bins must be ascending and disjoint, but [{}, {}] follows [{}, {}]",
+                pair[1].lower, pair[1].upper, pair[0].lower, pair[0].upper
+            )
+        })
+    })
+}
+
+fn outcome_value(outcome: &OutcomeType) -> f64 {
+    match outcome {
+        OutcomeType::Success => 1.0,
+        OutcomeType::Failure => 0.0,
+        OutcomeType::Partial => 0.5,
+    }
+}
+
+/// SHA-256 of `[[score, "outcome"], ...]` sorted by score and outcome
+fn training_data_hash(samples: &[(f64, OutcomeType)]) -> String {
+    let mut canonical: Vec<(f64, String)> = samples
+        .iter()
+        .map(|(score, outcome)| (*score, outcome.to_string()))
+        .collect();
+    canonical.sort_by(|a, b| a.partial_cmp(b).expect("scores are finite"));
+    let json = serde_json::to_string(&canonical).expect("scores are finite");
+    format!("{:x}", Sha256::digest(json.as_bytes()))
+}
+
+/// Linear interpolation on a non-decreasing curve, flat beyond its ends
+fn interpolate(points: &[CurvePoint], score: f64) -> f64 {
+    let after = points.partition_point(|point| point.score <= score);
+    match (after.checked_sub(1).map(|i| points[i]), points.get(after)) {
+        (None, Some(first)) => first.probability,
+        (Some(last), None) => last.probability,
+        (Some(low), Some(high)) => {
+            let ratio = (score - low.score) / (high.score - low.score);
+            low.probability + ratio * (high.probability - low.probability)
+        }
+        (None, None) => 0.5,
+    }
+}
+
+/// Pool-adjacent-violators on `(score, outcome)` pairs sorted by score
+///
+/// Each pooled block contributes its lowest and highest score at the block
+/// mean, so the curve is flat within blocks and linear between them.
+fn fit_isotonic(sorted: &[(f64, f64)]) -> Vec<CurvePoint> {
+    // (lowest score, highest score, outcome sum, count)
+    let mut blocks: Vec<(f64, f64, f64, f64)> = Vec::new();
+    for &(score, value) in sorted {
+        match blocks.last_mut() {
+            // Equal scores always share a block
+            Some(last) if last.1 == score => {
+                last.2 += value;
+                last.3 += 1.0;
+            }
+            _ => blocks.push((score, score, value, 1.0)),
+        }
+        while blocks.len() > 1 {
+            let last = blocks[blocks.len() - 1];
+            let previous = blocks[blocks.len() - 2];
+            if previous.2 / previous.3 <= last.2 / last.3 {
+                break;
+            }
+            blocks.pop();
+            let merged = blocks.last_mut().expect("two blocks");
+            merged.1 = last.1;
+            merged.2 += last.2;
+            merged.3 += last.3;
+        }
+    }
+
+    let mut points = Vec::new();
+    for (lower, upper, sum, count) in blocks {
+        let probability = sum / count;
+        points.push(CurvePoint {
+            score: lower,
+            probability,
+        });
+        if upper > lower {
+            points.push(CurvePoint {
+                score: upper,
+                probability,
+            });
+        }
+    }
+    points
+}
+
+/// Platt scaling by Newton's method on the log loss
+///
+/// Success and failure targets are smoothed as in Platt (1999), which keeps
+/// the coefficients finite on separable data.
+fn fit_platt(sorted: &[(f64, f64)]) -> (f64, f64) {
+    let positives = sorted.iter().filter(|(_, value)| *value == 1.0).count() as f64;
+    let negatives = sorted.iter().filter(|(_, value)| *value == 0.0).count() as f64;
+    let high = (positives + 1.0) / (positives + 2.0);
+    let low = 1.0 / (negatives + 2.0);
+    let targets: Vec<(f64, f64)> = sorted
+        .iter()
+        .map(|&(score, value)| {
+            let target = if value == 1.0 {
+                high
+            } else if value == 0.0 {
+                low
+            } else {
+                value
+            };
+            (score, target)
+        })
+        .collect();
+
+    let loss = |a: f64, b: f64| -> f64 {
+        targets
+            .iter()
+            .map(|&(score, target)| {
+                let z = a * score + b;
+                // log(1 + e^z) - target * z, computed stably
+                z.max(0.0) + (-z.abs()).exp().ln_1p() - target * z
+            })
+            .sum()
+    };
+
+    let (mut a, mut b) = (0.0, 0.0);
+    let mut current = loss(a, b);
+    for _ in 0..100 {
+        let (mut ga, mut gb, mut haa, mut hab, mut hbb) = (0.0, 0.0, 1e-12, 0.0, 1e-12);
+        for &(score, target) in &targets {
+            let p = 1.0 / (1.0 + (-(a * score + b)).exp());
+            let w = p * (1.0 - p);
+            ga += (p - target) * score;
+            gb += p - target;
+            haa += w * score * score;
+            hab += w * score;
+            hbb += w;
+        }
+        let det = haa * hbb - hab * hab;
+        if det.abs() < f64::MIN_POSITIVE {
+            break;
+        }
+        let step_a = (hbb * ga - hab * gb) / det;
+        let step_b = (haa * gb - hab * ga) / det;
+
+        // Halve the step until the loss does not increase
+        let mut scale = 1.0;
+        let mut improved = false;
+        for _ in 0..30 {
+            let (next_a, next_b) = (a - scale * step_a, b - scale * step_b);
+            let next = loss(next_a, next_b);
+            if next <= current {
+                a = next_a;
+                b = next_b;
+                current = next;
+                improved = true;
+                break;
+            }
+            scale /= 2.0;
+        }
+        if !improved || (scale * step_a).abs().max((scale * step_b).abs()) < 1e-10 {
+            break;
+        }
+    }
+    (a, b)
+}
+
+/// Equal-width sample counts over the training score range
+fn support_bins(sorted: &[(f64, f64)], bins: usize) -> Vec<SupportBin> {
+    let lower = sorted[0].0;
+    let upper = sorted[sorted.len() - 1].0;
+    if lower == upper {
+        return vec![SupportBin {
+            lower,
+            upper,
+            count: sorted.len(),
+        }];
+    }
+
+    let width = (upper - lower) / bins as f64;
+    let mut support: Vec<SupportBin> = (0..bins)
+        .map(|index| SupportBin {
+            lower: lower + width * index as f64,
+            upper: if index + 1 == bins {
+                upper
+            } else {
+                lower + width * (index + 1) as f64
+            },
+            count: 0,
+        })
+        .collect();
+    for &(score, _) in sorted {
+        if let Some(index) = bin_index(&support, score) {
+            support[index].count += 1;
+        }
+    }
+    support
+}
+
+/// Index of the bin containing a score
+///
+/// Bins are `[lower, upper)` except the last, which also contains its upper
+/// bound, so a score on a shared boundary belongs to the upper bin.
+fn bin_index(support: &[SupportBin], score: f64) -> Option<usize> {
+    let last = support.len().checked_sub(1)?;
+    support.iter().enumerate().position(|(index, bin)| {
+        bin.lower <= score && (score < bin.upper || (index == last && score <= bin.upper))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base(id: &str) -> BaseMapperParams {
+        BaseMapperParams {
+            id: id.to_string(),
+            version: "1.0.0".to_string(),
+            mapper_type: MapperType::Calibration,
+            description: None,
+            metadata: None,
+            input_path: None,
+            deprecated: false,
+        }
+    }
+
+    /// Scores 0.0..1.0 where higher scores succeed more often, dense below 0.5
+    fn samples() -> Vec<(f64, OutcomeType)> {
+        let mut samples = Vec::new();
+        for i in 0..100 {
+            let score = i as f64 / 200.0;
+            let outcome = if i % 4 == 0 && score > 0.2 {
+                OutcomeType::Success
+            } else {
+                OutcomeType::Failure
+            };
+            samples.push((score, outcome));
+        }
+        for score in [0.6, 0.7, 0.8, 0.9] {
+            samples.push((score, OutcomeType::Success));
+        }
+        samples.push((0.95, OutcomeType::Partial));
+        samples
+    }
+
+    #[test]
+    fn test_isotonic_fit_is_monotone_and_low_data_is_indeterminate() {
+        let mapper = CalibrationMapper::fit(
+            base("risk-model"),
+            &samples(),
+            CalibrationOptions::default(),
+        )
+        .unwrap();
+        let mut previous = 0.0;
+        for i in 0..=20 {
+            let p = mapper.probability(i as f64 / 20.0);
+            assert!(p >= previous);
+            previous = p;
+        }
+        assert_eq!(mapper.probability(0.0), 0.0);
+
+        let dense = mapper.apply(0.1).unwrap();
+        let sparse = mapper.apply(0.85).unwrap();
+        let outside = mapper.apply(2.0).unwrap();
+        assert!(dense.i < 0.1);
+        assert!(sparse.i > dense.i);
+        assert_eq!(outside.i, 1.0);
+        assert!(sparse.is_valid() && dense.is_valid());
+
+        let metadata = dense.provenance_chain[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata["method"], "isotonic");
+        assert_eq!(
+            metadata["training_data_hash"],
+            mapper.params().training_data_hash.as_str()
+        );
+        assert_eq!(metadata["mapper_version"], "1.0.0");
+    }
+
+    #[test]
+    fn test_boundary_scores_fall_in_the_upper_bin() {
+        let history = [
+            (0.0, OutcomeType::Failure),
+            (0.5, OutcomeType::Success),
+            (0.5, OutcomeType::Success),
+            (1.0, OutcomeType::Success),
+        ];
+        let options = CalibrationOptions {
+            bins: 2,
+            ..CalibrationOptions::default()
+        };
+        let mapper = CalibrationMapper::fit(base("risk-model"), &history, options).unwrap();
+        let counts: Vec<usize> = mapper
+            .params()
+            .support
+            .iter()
+            .map(|bin| bin.count)
+            .collect();
+        assert_eq!(counts, vec![1, 3]);
+        assert_eq!(mapper.support(0.0), 1);
+        assert_eq!(mapper.support(0.5), 3);
+        assert_eq!(mapper.support(1.0), 3);
+        assert_eq!(mapper.support(1.5), 0);
+    }
+
+    #[test]
+    fn test_platt_fit_and_training_hash() {
+        let options = CalibrationOptions {
+            method: CalibrationMethod::Platt,
+            ..CalibrationOptions::default()
+        };
+        let mapper = CalibrationMapper::fit(base("risk-model"), &samples(), options).unwrap();
+        match mapper.params().curve {
+            CalibrationCurve::Platt { a, .. } => assert!(a > 0.0),
+            _ => panic!("expected a Platt curve"),
+        }
+        assert!(mapper.probability(0.9) > mapper.probability(0.1));
+
+        // The hash does not depend on sample order but on the data
+        let mut reversed = samples();
+        reversed.reverse();
+        let same = CalibrationMapper::fit(base("risk-model"), &reversed, options).unwrap();
+        assert_eq!(
+            same.params().training_data_hash,
+            mapper.params().training_data_hash
+        );
+        reversed[0].1 = OutcomeType::Failure;
+        let other = CalibrationMapper::fit(base("risk-model"), &reversed, options).unwrap();
+        assert_ne!(
+            other.params().training_data_hash,
+            mapper.params().training_data_hash
+        );
+
+        assert!(CalibrationMapper::fit(base("empty"), &[], options).is_err());
+    }
+
+    #[test]
+    fn test_calibration_params_round_trip_through_registry() {
+        let mapper = CalibrationMapper::fit(
+            base("risk-model"),
+            &samples(),
+            CalibrationOptions::default(),
+        )
+        .unwrap();
+        let config = crate::MapperParams::Calibration(mapper.params().clone());
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["mapper_type"], "calibration");
+        assert_eq!(json["params"]["curve"]["method"], "isotonic");
+
+        let registry = crate::mapper::registry::DefaultMapperRegistry::new();
+        use crate::mapper::types::MapperRegistry;
+        registry
+            .import(vec![serde_json::from_value(json).unwrap()])
+            .unwrap();
+        assert_eq!(registry.export(), vec![config]);
+        let judgment = registry.apply("risk-model@1.0.0", &0.3_f64).unwrap();
+        assert!(judgment.judgment_id.is_some());
+
+        let mut broken = mapper.params().clone();
+        broken.curve = CalibrationCurve::Isotonic {
+            points: vec![
+                CurvePoint {
+                    score: 0.0,
+                    probability: 0.8,
+                },
+                CurvePoint {
+                    score: 1.0,
+                    probability: 0.2,
+                },
+            ],
+        };
+        assert!(CalibrationMapper::new(broken).is_err());
+    }
+}
@@ -2,8 +2,9 @@
 
 use crate::judgment::NeutrosophicJudgment;
 use crate::mapper::types::{
-    create_judgment, create_timestamp, validate_judgment_values, CategoricalParams, Mapper,
-    MapperType, ProvenanceEntry, ValidationError,
+    create_judgment, create_timestamp, insert_mapper_identity, json_type_name, resolve_json_input,
+    validate_judgment_values, CategoricalParams, InputError, Mapper, MapperType, ProvenanceEntry,
+    ValidationError,
 };
 #[cfg(test)]
 use crate::mapper::types::{BaseMapperParams, JudgmentData};
@@ -58,6 +59,7 @@ impl CategoricalMapper {
             "mapper_type".to_string(),
             serde_json::Value::String("categorical".to_string()),
         );
+        insert_mapper_identity(&mut metadata, &self.params.base);
         metadata.insert(
             "input_category".to_string(),
             serde_json::Value::String(input_category.to_string()),
@@ -104,13 +106,20 @@ impl CategoricalMapper {
         }
 
         // No mapping and no default judgment
-        Err(crate::error::OpenTrustError::InvalidFusionInput {
-            message: format!(
-                "Input category '{}' not found in mapper and no default_judgment is defined",
-                input_category
-            ),
+        Err(crate::error::OpenTrustError::UnknownCategory {
+            category: input_category.to_string(),
         })
     }
+
+    /// Apply the mapper to a JSON input, following `input_path` if set
+    pub fn apply_json(&self, input: &serde_json::Value) -> crate::Result<NeutrosophicJudgment> {
+        let value = resolve_json_input(&self.params.base, input)?;
+        let category = value.as_str().ok_or_else(|| InputError::InvalidType {
+            expected: "string".to_string(),
+            actual: json_type_name(value).to_string(),
+        })?;
+        self.apply(category)
+    }
 }
 
 impl Mapper for CategoricalMapper {
@@ -119,12 +128,12 @@ impl Mapper for CategoricalMapper {
             self.apply(value)
         } else if let Some(value) = input.downcast_ref::<&str>() {
             self.apply(value)
+        } else if let Some(value) = input.downcast_ref::<serde_json::Value>() {
+            self.apply_json(value)
         } else {
-            Err(crate::error::OpenTrustError::InvalidFusionInput {
-                message: format!(
-                    "Input for CategoricalMapper must be a string, got {}",
-                    std::any::type_name_of_val(input)
-                ),
+            Err(crate::error::OpenTrustError::InputType {
+                expected: "string".to_string(),
+                actual: std::any::type_name_of_val(input).to_string(),
             })
         }
     }
@@ -138,10 +147,7 @@ impl Mapper for CategoricalMapper {
     }
 
     fn validate(&self) -> crate::Result<()> {
-        self.validate_parameters()
-            .map_err(|e| crate::error::OpenTrustError::InvalidFusionInput {
-                message: e.to_string(),
-            })
+        self.validate_parameters().map_err(Into::into)
     }
 }
 
@@ -176,6 +182,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: Some(JudgmentData {
@@ -198,6 +206,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings: HashMap::new(), // Empty mappings - should fail
             default_judgment: None,
@@ -234,6 +244,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: Some(JudgmentData {
@@ -279,6 +291,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: None,
@@ -290,4 +304,42 @@ mod tests {
         let result = mapper.apply("UNKNOWN");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_categorical_mapper_apply_json() {
+        let mut mappings = HashMap::new();
+        mappings.insert(
+            "VERIFIED".to_string(),
+            JudgmentData {
+                T: 0.9,
+                I: 0.1,
+                F: 0.0,
+            },
+        );
+
+        let params = CategoricalParams {
+            base: BaseMapperParams {
+                id: "test-categorical".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Categorical,
+                description: None,
+                metadata: None,
+                input_path: Some("/kyc/status".to_string()),
+                deprecated: false,
+            },
+            mappings,
+            default_judgment: None,
+        };
+
+        let mapper = CategoricalMapper::new(params).unwrap();
+
+        let event = serde_json::json!({"kyc": {"status": "VERIFIED"}});
+        let judgment = Mapper::apply(&mapper, &event).unwrap();
+        assert_eq!(judgment.t, 0.9);
+
+        // Wrong JSON type
+        let wrong_type = serde_json::json!({"kyc": {"status": 1}});
+        let error = mapper.apply_json(&wrong_type).unwrap_err();
+        assert!(error.to_string().contains("expected string, got number"));
+    }
 }
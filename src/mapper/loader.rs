@@ -0,0 +1,541 @@
+//! Loading and saving of mapper configurations from JSON and TOML files
+//!
+//! A definition file holds either a single [`MapperParams`] document, an array of
+//! them, or an object with a `mappers` array. TOML files are supported when the
+//! `toml` feature is enabled.
+//!
+//! The object form may also carry a `rule_sets` array (see
+//! [`crate::rules`]), so rule sets can live in the same files and
+//! directories as the mappers they consume. Files holding only rule sets,
+//! whether as a `rule_sets` array or as standalone rule set documents,
+//! contribute no mappers.
+
+use crate::mapper::types::MapperParams;
+use crate::mapper::validator::{MapperValidator, Severity};
+use crate::rules::is_rule_set_document;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A problem found in a mapper definition file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    /// File the definition was read from
+    pub file: PathBuf,
+    /// Path of the offending field within the file (e.g. `[1].params.true_map.T`)
+    pub field_path: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field_path.is_empty() {
+            write!(f, "{}: {}", self.file.display(), self.message)
+        } else {
+            write!(
+                f,
+                "{}: {}: {}",
+                self.file.display(),
+                self.field_path,
+                self.message
+            )
+        }
+    }
+}
+
+/// Errors that can occur while loading or saving mapper configurations
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("I/O error on {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unsupported mapper configuration format: {}", .path.display())]
+    UnsupportedFormat { path: PathBuf },
+
+    #[error("Invalid mapper configuration: {}", format_issues(.issues))]
+    Invalid { issues: Vec<ConfigIssue> },
+}
+
+fn format_issues(issues: &[ConfigIssue]) -> String {
+    issues
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Supported definition file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    Json,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl ConfigFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Some(ConfigFormat::Json),
+            #[cfg(feature = "toml")]
+            Some("toml") => Some(ConfigFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Load and validate all mapper definitions from a file
+pub fn load_mapper_file(path: impl AsRef<Path>) -> Result<Vec<MapperParams>, ConfigError> {
+    let path = path.as_ref();
+    let format = ConfigFormat::from_path(path).ok_or_else(|| ConfigError::UnsupportedFormat {
+        path: path.to_path_buf(),
+    })?;
+
+    let mut issues = Vec::new();
+    let configs = read_definitions(path, format, &mut issues)?;
+    validate_definitions(path, &configs, &mut issues);
+
+    if issues.is_empty() {
+        Ok(configs.into_iter().map(|(_, config)| config).collect())
+    } else {
+        Err(ConfigError::Invalid { issues })
+    }
+}
+
+/// Load and validate all mapper definitions from a directory
+///
+/// Files are read in file-name order; files with unsupported extensions are
+/// ignored. Problems in every file are collected before returning, and mapper
+/// IDs defined more than once across the directory are reported as well.
+pub fn load_mapper_dir(dir: impl AsRef<Path>) -> Result<Vec<MapperParams>, ConfigError> {
+    let dir = dir.as_ref();
+    let io_error = |source| ConfigError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_file() && ConfigFormat::from_path(&path).is_some() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut issues = Vec::new();
+    let mut configs = Vec::new();
+    let mut seen: HashMap<String, PathBuf> = HashMap::new();
+
+    for path in paths {
+        let format = ConfigFormat::from_path(&path).expect("filtered above");
+        let definitions = read_definitions(&path, format, &mut issues)?;
+        validate_definitions(&path, &definitions, &mut issues);
+
+        for (issue_path, config) in definitions {
+            let key = format!("{}@{}", config.id(), config.version());
+            if let Some(previous) = seen.get(&key) {
+                issues.push(ConfigIssue {
+                    file: path.clone(),
+                    field_path: issue_path.field("params.version"),
+                    message: format!(
+                        "mapper '{}' version {} is already defined in {}",
+                        config.id(),
+                        config.version(),
+                        previous.display()
+                    ),
+                });
+            } else {
+                seen.insert(key, path.clone());
+            }
+            configs.push(config);
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(configs)
+    } else {
+        Err(ConfigError::Invalid { issues })
+    }
+}
+
+/// Save mapper definitions to a single file, choosing the format by extension
+pub fn save_mapper_file(
+    path: impl AsRef<Path>,
+    configs: &[MapperParams],
+) -> Result<(), ConfigError> {
+    let path = path.as_ref();
+    let format = ConfigFormat::from_path(path).ok_or_else(|| ConfigError::UnsupportedFormat {
+        path: path.to_path_buf(),
+    })?;
+
+    let contents = match format {
+        ConfigFormat::Json => to_canonical_json(configs),
+        #[cfg(feature = "toml")]
+        ConfigFormat::Toml => to_toml(configs),
+    };
+
+    fs::write(path, contents).map_err(|source| ConfigError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Save mapper definitions to a directory, one `<id>@<version>.json` file per mapper
+///
+/// Bytes of the ID and version outside `[A-Za-z0-9._-]` are percent-encoded,
+/// so distinct mappers never share a file.
+///
+/// The output is deterministic (sorted keys, stable formatting) so that
+/// exported registries can be versioned and diffed.
+pub fn save_mapper_dir(dir: impl AsRef<Path>, configs: &[MapperParams]) -> Result<(), ConfigError> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir).map_err(|source| ConfigError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    for config in configs {
+        let path = dir.join(format!(
+            "{}@{}.json",
+            file_stem_for_id(config.id()),
+            file_stem_for_id(config.version())
+        ));
+        fs::write(&path, to_canonical_json(config)).map_err(|source| ConfigError::Io {
+            path: path.clone(),
+            source,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Location of a definition inside a file
+#[derive(Debug, Clone, Copy)]
+enum DefinitionPath {
+    /// The file holds a single definition
+    Root,
+    /// The file holds a list of definitions (top-level array or `mappers` array)
+    Index(&'static str, usize),
+}
+
+impl DefinitionPath {
+    /// Qualify a field path inside the definition with the definition's location
+    fn field(&self, field: &str) -> String {
+        let field = field.trim_start_matches('.');
+        match self {
+            DefinitionPath::Root => field.to_string(),
+            DefinitionPath::Index(list, index) if field.is_empty() => {
+                format!("{}[{}]", list, index)
+            }
+            DefinitionPath::Index(list, index) => format!("{}[{}].{}", list, index, field),
+        }
+    }
+}
+
+/// Read the definitions in a file, recording parse problems as issues
+fn read_definitions(
+    path: &Path,
+    format: ConfigFormat,
+    issues: &mut Vec<ConfigIssue>,
+) -> Result<Vec<(DefinitionPath, MapperParams)>, ConfigError> {
+    let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let parsed: Result<serde_json::Value, String> = match format {
+        ConfigFormat::Json => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        #[cfg(feature = "toml")]
+        ConfigFormat::Toml => toml::from_str(&contents).map_err(|e| e.to_string()),
+    };
+    let document = match parsed {
+        Ok(document) => document,
+        Err(message) => {
+            issues.push(ConfigIssue {
+                file: path.to_path_buf(),
+                field_path: String::new(),
+                message,
+            });
+            return Ok(Vec::new());
+        }
+    };
+
+    if is_rule_set_document(&document) {
+        return Ok(Vec::new());
+    }
+
+    let entries: Vec<(DefinitionPath, serde_json::Value)> = match document {
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| (DefinitionPath::Index("", index), item))
+            .collect(),
+        serde_json::Value::Object(mut object)
+            if !object.contains_key("mapper_type")
+                && (object.contains_key("mappers") || object.contains_key("rule_sets")) =>
+        {
+            match object.remove("mappers") {
+                Some(serde_json::Value::Array(items)) => items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| (DefinitionPath::Index("mappers", index), item))
+                    .collect(),
+                None => Vec::new(),
+                _ => {
+                    issues.push(ConfigIssue {
+                        file: path.to_path_buf(),
+                        field_path: "mappers".to_string(),
+                        message: "expected an array of mapper definitions".to_string(),
+                    });
+                    Vec::new()
+                }
+            }
+        }
+        other => vec![(DefinitionPath::Root, other)],
+    };
+
+    let mut definitions = Vec::new();
+    for (definition_path, value) in entries {
+        match serde_path_to_error::deserialize::<_, MapperParams>(value) {
+            Ok(config) => definitions.push((definition_path, config)),
+            Err(e) => issues.push(ConfigIssue {
+                file: path.to_path_buf(),
+                field_path: definition_path.field(&e.path().to_string()),
+                message: e.into_inner().to_string(),
+            }),
+        }
+    }
+
+    Ok(definitions)
+}
+
+/// Run `MapperValidator` over parsed definitions, recording failures as issues
+fn validate_definitions(
+    path: &Path,
+    definitions: &[(DefinitionPath, MapperParams)],
+    issues: &mut Vec<ConfigIssue>,
+) {
+    let validator = MapperValidator::new();
+    for (definition_path, config) in definitions {
+        let result = validator.validate(config);
+        for issue in result.with_severity(Severity::Error) {
+            issues.push(ConfigIssue {
+                file: path.to_path_buf(),
+                field_path: definition_path.field(&pointer_to_field(&issue.path)),
+                message: issue.message.clone(),
+            });
+        }
+    }
+}
+
+/// Convert a JSON pointer such as `/params/true_map/T` to `params.true_map.T`
+fn pointer_to_field(pointer: &str) -> String {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Serialize to pretty JSON with sorted object keys
+fn to_canonical_json<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    // Going through `serde_json::Value` sorts map keys (including HashMap fields)
+    let value = serde_json::to_value(value).expect("mapper configurations serialize to JSON");
+    let mut json = serde_json::to_string_pretty(&value).expect("JSON values serialize");
+    json.push('\n');
+    json
+}
+
+/// Serialize to TOML as a `mappers` array of tables
+///
+/// TOML has no null, so unset optional fields are omitted.
+#[cfg(feature = "toml")]
+fn to_toml(configs: &[MapperParams]) -> String {
+    fn strip_nulls(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(object) => serde_json::Value::Object(
+                object
+                    .into_iter()
+                    .filter(|(_, v)| !v.is_null())
+                    .map(|(k, v)| (k, strip_nulls(v)))
+                    .collect(),
+            ),
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(strip_nulls).collect())
+            }
+            other => other,
+        }
+    }
+
+    let value = strip_nulls(serde_json::json!({ "mappers": configs }));
+    toml::to_string_pretty(&value).expect("mapper configurations serialize to TOML")
+}
+
+/// Turn a mapper ID into a safe file stem
+fn file_stem_for_id(id: &str) -> String {
+    let mut stem = String::with_capacity(id.len());
+    for byte in id.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.') {
+            stem.push(byte as char);
+        } else {
+            stem.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    stem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapper::types::{BaseMapperParams, BooleanParams, JudgmentData, MapperType};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("otp-loader-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn boolean_config(id: &str) -> MapperParams {
+        MapperParams::Boolean(BooleanParams {
+            base: BaseMapperParams {
+                id: id.to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Boolean,
+                description: None,
+                metadata: None,
+                input_path: None,
+                deprecated: false,
+            },
+            true_map: JudgmentData {
+                T: 0.9,
+                I: 0.1,
+                F: 0.0,
+            },
+            false_map: JudgmentData {
+                T: 0.0,
+                I: 0.1,
+                F: 0.9,
+            },
+        })
+    }
+
+    #[test]
+    fn test_save_and_load_dir_round_trip() {
+        let dir = temp_dir("round-trip");
+        let configs = vec![boolean_config("a-mapper"), boolean_config("b-mapper")];
+
+        save_mapper_dir(&dir, &configs).unwrap();
+        let loaded = load_mapper_dir(&dir).unwrap();
+
+        assert_eq!(loaded, configs);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_dir_keeps_ids_that_differ_only_in_special_characters() {
+        let dir = temp_dir("special-ids");
+        let configs = vec![
+            boolean_config("a/b"),
+            boolean_config("a b"),
+            boolean_config("a_b"),
+        ];
+
+        save_mapper_dir(&dir, &configs).unwrap();
+        assert!(dir.join("a%2Fb@1.0.0.json").is_file());
+        let mut loaded = load_mapper_dir(&dir).unwrap();
+        loaded.sort_by(|a, b| a.id().cmp(b.id()));
+
+        assert_eq!(
+            loaded,
+            vec![
+                boolean_config("a b"),
+                boolean_config("a/b"),
+                boolean_config("a_b"),
+            ]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_reports_file_and_field_path() {
+        let dir = temp_dir("invalid");
+        let mut json = serde_json::to_value(boolean_config("bad-mapper")).unwrap();
+        json["params"]["true_map"]["T"] = serde_json::Value::String("high".to_string());
+        fs::write(dir.join("bad.json"), json.to_string()).unwrap();
+
+        let error = load_mapper_dir(&dir).unwrap_err();
+        match error {
+            ConfigError::Invalid { issues } => {
+                assert_eq!(issues.len(), 1);
+                assert!(issues[0].file.ends_with("bad.json"));
+                assert_eq!(issues[0].field_path, "params.true_map.T");
+            }
+            other => panic!("Expected Invalid error, got {:?}", other),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_file_round_trip() {
+        let dir = temp_dir("toml");
+        let path = dir.join("mappers.toml");
+        let configs = vec![boolean_config("a-mapper"), boolean_config("b-mapper")];
+
+        save_mapper_file(&path, &configs).unwrap();
+        assert_eq!(load_mapper_file(&path).unwrap(), configs);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rule_set_files_contribute_no_mappers() {
+        let dir = temp_dir("rule-sets");
+        save_mapper_file(dir.join("mappers.json"), &[boolean_config("a-mapper")]).unwrap();
+        let rules = serde_json::json!({
+            "mappers": [boolean_config("b-mapper")],
+            "rule_sets": [{"id": "loan", "version": "1.0.0", "rules": []}]
+        });
+        fs::write(dir.join("combined.json"), rules.to_string()).unwrap();
+        fs::write(
+            dir.join("rules.json"),
+            r#"{"rule_sets": [{"id": "kyc", "version": "1.0.0", "rules": []}]}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("standalone.json"),
+            r#"{"id": "aml", "version": "1.0.0", "rules": []}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("standalone-list.json"),
+            r#"[{"id": "fraud", "version": "1.0.0", "rules": []}]"#,
+        )
+        .unwrap();
+
+        let loaded = load_mapper_dir(&dir).unwrap();
+        assert_eq!(
+            loaded,
+            vec![boolean_config("b-mapper"), boolean_config("a-mapper")]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_reports_duplicate_ids_across_files() {
+        let dir = temp_dir("duplicate");
+        save_mapper_file(dir.join("one.json"), &[boolean_config("same")]).unwrap();
+        save_mapper_file(dir.join("two.json"), &[boolean_config("same")]).unwrap();
+
+        let error = load_mapper_dir(&dir).unwrap_err();
+        assert!(error.to_string().contains("already defined"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,24 +1,34 @@
 //! OTP Mapper module for OpenTrust Protocol Rust SDK
 //!
 //! This module provides mappers for transforming raw data into Neutrosophic Judgments.
-//! It includes Numerical, Categorical, and Boolean mappers with full provenance support.
+//! It includes Numerical, Categorical, Boolean and Calibration mappers with full provenance support.
 
 pub mod boolean;
+pub mod calibration;
 pub mod categorical;
+pub mod loader;
 pub mod numerical;
 pub mod registry;
 pub mod types;
 pub mod validator;
+pub mod watcher;
 
 // Re-export main types and traits
 pub use boolean::BooleanMapper;
+pub use calibration::{CalibrationMapper, CalibrationMethod, CalibrationOptions};
 pub use categorical::CategoricalMapper;
+pub use loader::{
+    load_mapper_dir, load_mapper_file, save_mapper_dir, save_mapper_file, ConfigError, ConfigIssue,
+};
 pub use numerical::NumericalMapper;
-pub use registry::{get_global_registry, reset_global_registry};
+pub use registry::{build_mapper, get_global_registry, reset_global_registry};
 pub use types::MapperRegistry;
 pub use types::{
-    create_judgment, create_timestamp, normalize_boolean_input, validate_judgment_values,
-    BaseMapperParams, BooleanParams, CategoricalParams, InputError, Mapper, MapperError,
-    MapperParams, MapperType, NumericalParams, ProvenanceEntry, ValidationError,
+    create_judgment, create_timestamp, normalize_boolean_input, normalize_json_boolean_input,
+    resolve_json_input, validate_judgment_values, BaseMapperParams, BooleanParams,
+    CalibrationCurve, CalibrationParams, CategoricalParams, CurvePoint, InputError, Mapper,
+    MapperError, MapperParams, MapperType, NumericalParams, ProvenanceEntry, SupportBin,
+    ValidationError,
 };
 pub use validator::MapperValidator;
+pub use watcher::{MapperDirWatcher, WatcherHandle};
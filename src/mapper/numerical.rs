@@ -4,8 +4,8 @@ use crate::judgment::NeutrosophicJudgment;
 #[cfg(test)]
 use crate::mapper::types::BaseMapperParams;
 use crate::mapper::types::{
-    create_judgment, create_timestamp, Mapper, MapperType, NumericalParams, ProvenanceEntry,
-    ValidationError,
+    create_judgment, create_timestamp, insert_mapper_identity, json_type_name, resolve_json_input,
+    InputError, Mapper, MapperType, NumericalParams, ProvenanceEntry, ValidationError,
 };
 use std::collections::HashMap;
 
@@ -164,6 +164,7 @@ impl NumericalMapper {
             "mapper_type".to_string(),
             serde_json::Value::String("numerical".to_string()),
         );
+        insert_mapper_identity(&mut metadata, &self.params.base);
         metadata.insert(
             "input_value".to_string(),
             serde_json::Value::Number(serde_json::Number::from_f64(input_value).unwrap()),
@@ -205,12 +206,13 @@ impl NumericalMapper {
             input_value.max(min_point).min(max_point)
         } else {
             if input_value < min_point || input_value > max_point {
-                return Err(crate::error::OpenTrustError::InvalidFusionInput {
-                    message: format!(
-                        "Input value {} is out of the defined mapper range [{}, {}] and clamp_to_range is false",
+                return Err(InputError::OutOfRange {
+                    value: format!(
+                        "{} is outside the mapper range [{}, {}] and clamp_to_range is false",
                         input_value, min_point, max_point
                     ),
-                });
+                }
+                .into());
             }
             input_value
         };
@@ -221,6 +223,16 @@ impl NumericalMapper {
 
         create_judgment(T, I, F, vec![provenance_entry])
     }
+
+    /// Apply the mapper to a JSON input, following `input_path` if set
+    pub fn apply_json(&self, input: &serde_json::Value) -> crate::Result<NeutrosophicJudgment> {
+        let value = resolve_json_input(&self.params.base, input)?;
+        let number = value.as_f64().ok_or_else(|| InputError::InvalidType {
+            expected: "number".to_string(),
+            actual: json_type_name(value).to_string(),
+        })?;
+        self.apply(number)
+    }
 }
 
 impl Mapper for NumericalMapper {
@@ -231,12 +243,12 @@ impl Mapper for NumericalMapper {
             self.apply(*value as f64)
         } else if let Some(value) = input.downcast_ref::<i64>() {
             self.apply(*value as f64)
+        } else if let Some(value) = input.downcast_ref::<serde_json::Value>() {
+            self.apply_json(value)
         } else {
-            Err(crate::error::OpenTrustError::InvalidFusionInput {
-                message: format!(
-                    "Input for NumericalMapper must be a number, got {}",
-                    std::any::type_name_of_val(input)
-                ),
+            Err(crate::error::OpenTrustError::InputType {
+                expected: "number".to_string(),
+                actual: std::any::type_name_of_val(input).to_string(),
             })
         }
     }
@@ -250,10 +262,7 @@ impl Mapper for NumericalMapper {
     }
 
     fn validate(&self) -> crate::Result<()> {
-        self.validate_parameters()
-            .map_err(|e| crate::error::OpenTrustError::InvalidFusionInput {
-                message: e.to_string(),
-            })
+        self.validate_parameters().map_err(Into::into)
     }
 }
 
@@ -270,6 +279,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -290,6 +301,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.0, // Same as falsity_point - should fail
@@ -310,6 +323,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -334,6 +349,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -352,4 +369,38 @@ mod tests {
         assert!(judgment2.is_valid());
         assert!(judgment3.is_valid());
     }
+
+    #[test]
+    fn test_numerical_mapper_apply_json() {
+        let params = NumericalParams {
+            base: BaseMapperParams {
+                id: "test-numerical".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Numerical,
+                description: None,
+                metadata: None,
+                input_path: Some("/account/health_factor".to_string()),
+                deprecated: false,
+            },
+            falsity_point: 1.0,
+            indeterminacy_point: 1.5,
+            truth_point: 3.0,
+            clamp_to_range: Some(true),
+        };
+
+        let mapper = NumericalMapper::new(params).unwrap();
+
+        let event = serde_json::json!({"account": {"health_factor": 3.0}});
+        let judgment = Mapper::apply(&mapper, &event).unwrap();
+        assert_eq!(judgment.t, 1.0);
+
+        // Missing field
+        let missing = serde_json::json!({"account": {}});
+        assert!(mapper.apply_json(&missing).is_err());
+
+        // Wrong JSON type
+        let wrong_type = serde_json::json!({"account": {"health_factor": "high"}});
+        let error = mapper.apply_json(&wrong_type).unwrap_err();
+        assert!(error.to_string().contains("expected number, got string"));
+    }
 }
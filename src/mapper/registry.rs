@@ -1,98 +1,167 @@
 //! MapperRegistry implementation for centralized mapper management
 
-use crate::mapper::types::{Mapper, MapperParams, MapperRegistry, MapperType};
-use std::collections::HashMap;
+use crate::mapper::types::{
+    config_problems, Mapper, MapperError, MapperParams, MapperRegistry, MapperType,
+};
+use crate::mapper::{BooleanMapper, CalibrationMapper, CategoricalMapper, NumericalMapper};
+use semver::{Version, VersionReq};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::RwLock;
 
+/// A registered version of a mapper
+struct VersionEntry {
+    mapper: Arc<dyn Mapper>,
+    deprecated: bool,
+}
+
 /// Default implementation of MapperRegistry
+///
+/// Mappers are keyed by ID and semantic version, so several versions of the
+/// same mapper can be registered side by side.
 pub struct DefaultMapperRegistry {
-    mappers: RwLock<HashMap<String, Arc<dyn Mapper>>>,
+    mappers: RwLock<BTreeMap<String, BTreeMap<Version, VersionEntry>>>,
 }
 
 impl DefaultMapperRegistry {
     /// Create a new DefaultMapperRegistry
     pub fn new() -> Self {
         Self {
-            mappers: RwLock::new(HashMap::new()),
+            mappers: RwLock::new(BTreeMap::new()),
         }
     }
 
-    /// Register a mapper with the given ID
-    fn register_internal(&self, id: String, mapper: Arc<dyn Mapper>) -> crate::Result<()> {
+    /// Register a mapper with the given ID and version
+    fn register_internal(
+        &self,
+        id: String,
+        version: Version,
+        entry: VersionEntry,
+    ) -> crate::Result<()> {
         let mut mappers = self.mappers.write().unwrap();
+        let versions = mappers.entry(id.clone()).or_default();
 
-        if mappers.contains_key(&id) {
-            return Err(crate::error::OpenTrustError::InvalidFusionInput {
-                message: format!("Mapper with ID '{}' already exists", id),
+        if versions.contains_key(&version) {
+            return Err(crate::error::OpenTrustError::DuplicateMapper {
+                id,
+                version: version.to_string(),
             });
         }
 
         // Validate the mapper before registering
-        mapper.validate()?;
+        if let Err(e) = entry.mapper.validate() {
+            if versions.is_empty() {
+                mappers.remove(&id);
+            }
+            return Err(e);
+        }
 
-        mappers.insert(id, mapper);
+        versions.insert(version, entry);
         Ok(())
     }
+
+    /// Extract the ID, version and registry entry for a mapper
+    fn entry_for(mapper: Box<dyn Mapper>) -> crate::Result<(String, Version, VersionEntry)> {
+        let config = mapper_config(mapper.as_ref()).ok_or_else(|| MapperError::Configuration {
+            message: format!(
+                "Failed to extract parameters from {:?} mapper",
+                mapper.get_type()
+            ),
+        })?;
+        let base = config.base();
+        let version = parse_version(&base.id, &base.version)?;
+        let deprecated = base.deprecated;
+
+        Ok((
+            base.id.clone(),
+            version,
+            VersionEntry {
+                mapper: Arc::from(mapper),
+                deprecated,
+            },
+        ))
+    }
+
+    /// Pick the version a bare ID resolves to: the latest non-deprecated
+    /// version, or the latest version if all are deprecated
+    fn latest(versions: &BTreeMap<Version, VersionEntry>) -> Option<&VersionEntry> {
+        versions
+            .values()
+            .rev()
+            .find(|entry| !entry.deprecated)
+            .or_else(|| versions.values().next_back())
+    }
+}
+
+/// Parse a mapper version string as a semantic version
+pub(crate) fn parse_version(id: &str, version: &str) -> crate::Result<Version> {
+    Version::parse(version).map_err(|e| crate::error::OpenTrustError::InvalidVersion {
+        id: id.to_string(),
+        version: version.to_string(),
+        source: Arc::new(e),
+    })
+}
+
+/// Recover the configuration of a built-in mapper
+fn mapper_config(mapper: &dyn Mapper) -> Option<MapperParams> {
+    match mapper.get_type() {
+        MapperType::Numerical => mapper
+            .get_params()
+            .downcast_ref::<crate::mapper::types::NumericalParams>()
+            .map(|params| MapperParams::Numerical(params.clone())),
+        MapperType::Categorical => mapper
+            .get_params()
+            .downcast_ref::<crate::mapper::types::CategoricalParams>()
+            .map(|params| MapperParams::Categorical(params.clone())),
+        MapperType::Boolean => mapper
+            .get_params()
+            .downcast_ref::<crate::mapper::types::BooleanParams>()
+            .map(|params| MapperParams::Boolean(params.clone())),
+        MapperType::Calibration => mapper
+            .get_params()
+            .downcast_ref::<crate::mapper::types::CalibrationParams>()
+            .map(|params| MapperParams::Calibration(params.clone())),
+    }
 }
 
 impl MapperRegistry for DefaultMapperRegistry {
     fn register(&self, mapper: Box<dyn Mapper>) -> crate::Result<()> {
-        // Get the ID from the mapper parameters
-        let id = match mapper.get_type() {
-            MapperType::Numerical => {
-                if let Some(params) = mapper
-                    .get_params()
-                    .downcast_ref::<crate::mapper::types::NumericalParams>()
-                {
-                    params.base.id.clone()
-                } else {
-                    return Err(crate::error::OpenTrustError::InvalidFusionInput {
-                        message: "Failed to extract ID from NumericalMapper parameters".to_string(),
-                    });
-                }
-            }
-            MapperType::Categorical => {
-                if let Some(params) = mapper
-                    .get_params()
-                    .downcast_ref::<crate::mapper::types::CategoricalParams>()
-                {
-                    params.base.id.clone()
-                } else {
-                    return Err(crate::error::OpenTrustError::InvalidFusionInput {
-                        message: "Failed to extract ID from CategoricalMapper parameters"
-                            .to_string(),
-                    });
-                }
-            }
-            MapperType::Boolean => {
-                if let Some(params) = mapper
-                    .get_params()
-                    .downcast_ref::<crate::mapper::types::BooleanParams>()
-                {
-                    params.base.id.clone()
-                } else {
-                    return Err(crate::error::OpenTrustError::InvalidFusionInput {
-                        message: "Failed to extract ID from BooleanMapper parameters".to_string(),
-                    });
-                }
-            }
-        };
-
-        self.register_internal(id, Arc::from(mapper))
+        let (id, version, entry) = Self::entry_for(mapper)?;
+        self.register_internal(id, version, entry)
     }
 
     fn get(&self, id: &str) -> Option<Arc<dyn Mapper>> {
         let mappers = self.mappers.read().unwrap();
-        mappers.get(id).cloned()
+
+        match id.rsplit_once('@') {
+            Some((id, pin)) => {
+                let requirement = VersionReq::parse(&format!("={}", pin)).ok()?;
+                mappers
+                    .get(id)?
+                    .iter()
+                    .rev()
+                    .find(|(version, _)| requirement.matches(version))
+                    .map(|(_, entry)| entry.mapper.clone())
+            }
+            None => Self::latest(mappers.get(id)?).map(|entry| entry.mapper.clone()),
+        }
+    }
+
+    fn versions(&self, id: &str) -> Vec<String> {
+        let mappers = self.mappers.read().unwrap();
+        mappers
+            .get(id)
+            .map(|versions| versions.keys().map(|v| v.to_string()).collect())
+            .unwrap_or_default()
     }
 
     fn get_by_type(&self, mapper_type: MapperType) -> Vec<Arc<dyn Mapper>> {
         let mappers = self.mappers.read().unwrap();
         mappers
             .values()
-            .filter(|mapper| mapper.get_type() == mapper_type)
-            .cloned()
+            .filter_map(Self::latest)
+            .filter(|entry| entry.mapper.get_type() == mapper_type)
+            .map(|entry| entry.mapper.clone())
             .collect()
     }
 
@@ -101,40 +170,86 @@ impl MapperRegistry for DefaultMapperRegistry {
         mappers.keys().cloned().collect()
     }
 
-    fn export(&self) -> Vec<MapperParams> {
-        let mappers = self.mappers.read().unwrap();
-        let mut configs = Vec::new();
-
-        for mapper in mappers.values() {
-            match mapper.get_type() {
-                MapperType::Numerical => {
-                    if let Some(params) = mapper
-                        .get_params()
-                        .downcast_ref::<crate::mapper::types::NumericalParams>()
-                    {
-                        configs.push(MapperParams::Numerical(params.clone()));
-                    }
-                }
-                MapperType::Categorical => {
-                    if let Some(params) = mapper
-                        .get_params()
-                        .downcast_ref::<crate::mapper::types::CategoricalParams>()
-                    {
-                        configs.push(MapperParams::Categorical(params.clone()));
+    fn unregister(&self, id: &str) -> crate::Result<()> {
+        let mut mappers = self.mappers.write().unwrap();
+
+        let removed = match id.rsplit_once('@') {
+            Some((base_id, pin)) => {
+                let requirement = VersionReq::parse(&format!("={}", pin)).map_err(|e| {
+                    crate::error::OpenTrustError::InvalidVersion {
+                        id: base_id.to_string(),
+                        version: pin.to_string(),
+                        source: Arc::new(e),
                     }
-                }
-                MapperType::Boolean => {
-                    if let Some(params) = mapper
-                        .get_params()
-                        .downcast_ref::<crate::mapper::types::BooleanParams>()
-                    {
-                        configs.push(MapperParams::Boolean(params.clone()));
+                })?;
+                let removed = match mappers.get_mut(base_id) {
+                    Some(versions) => {
+                        let before = versions.len();
+                        versions.retain(|version, _| !requirement.matches(version));
+                        before - versions.len()
                     }
+                    None => 0,
+                };
+                if mappers
+                    .get(base_id)
+                    .is_some_and(|versions| versions.is_empty())
+                {
+                    mappers.remove(base_id);
                 }
+                removed
             }
+            None => mappers
+                .remove(id)
+                .map(|versions| versions.len())
+                .unwrap_or(0),
+        };
+
+        if removed == 0 {
+            return Err(crate::error::OpenTrustError::MapperNotFound { id: id.to_string() });
         }
+        Ok(())
+    }
 
-        configs
+    fn replace(&self, mapper: Box<dyn Mapper>) -> crate::Result<Option<Arc<dyn Mapper>>> {
+        let (id, version, entry) = Self::entry_for(mapper)?;
+        entry.mapper.validate()?;
+
+        let mut mappers = self.mappers.write().unwrap();
+        Ok(mappers
+            .entry(id)
+            .or_default()
+            .insert(version, entry)
+            .map(|previous| previous.mapper))
+    }
+
+    fn swap_all(&self, configs: Vec<MapperParams>) -> crate::Result<()> {
+        let problems = config_problems(&configs);
+        if !problems.is_empty() {
+            return Err(MapperError::Configuration {
+                message: problems.join("; "),
+            }
+            .into());
+        }
+
+        // Build the complete replacement before taking the write lock
+        let mut next: BTreeMap<String, BTreeMap<Version, VersionEntry>> = BTreeMap::new();
+        for config in configs {
+            let (id, version, entry) = Self::entry_for(build_mapper(config)?)?;
+            entry.mapper.validate()?;
+            next.entry(id).or_default().insert(version, entry);
+        }
+
+        *self.mappers.write().unwrap() = next;
+        Ok(())
+    }
+
+    fn export(&self) -> Vec<MapperParams> {
+        let mappers = self.mappers.read().unwrap();
+        mappers
+            .values()
+            .flat_map(|versions| versions.values())
+            .filter_map(|entry| mapper_config(entry.mapper.as_ref()))
+            .collect()
     }
 }
 
@@ -144,6 +259,16 @@ impl Default for DefaultMapperRegistry {
     }
 }
 
+/// Build a mapper from its configuration
+pub fn build_mapper(config: MapperParams) -> crate::Result<Box<dyn Mapper>> {
+    Ok(match config {
+        MapperParams::Numerical(params) => Box::new(NumericalMapper::new(params)?),
+        MapperParams::Categorical(params) => Box::new(CategoricalMapper::new(params)?),
+        MapperParams::Boolean(params) => Box::new(BooleanMapper::new(params)?),
+        MapperParams::Calibration(params) => Box::new(CalibrationMapper::new(params)?),
+    })
+}
+
 /// Get the global mapper registry
 pub fn get_global_registry() -> Arc<dyn MapperRegistry> {
     use crate::mapper::types::get_global_registry;
@@ -160,7 +285,6 @@ pub fn reset_global_registry() {
 mod tests {
     use super::*;
     use crate::mapper::types::{BooleanParams, CategoricalParams, JudgmentData, NumericalParams};
-    use crate::mapper::{BooleanMapper, CategoricalMapper, NumericalMapper};
     use std::collections::HashMap;
 
     #[test]
@@ -180,6 +304,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -215,6 +341,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: None,
@@ -238,6 +366,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -269,6 +399,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -283,6 +415,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 2.0,
             indeterminacy_point: 2.5,
@@ -295,7 +429,11 @@ mod tests {
 
         registry.register(Box::new(mapper1)).unwrap();
         let result = registry.register(Box::new(mapper2));
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(crate::error::OpenTrustError::DuplicateMapper { ref id, ref version })
+                if id == "test" && version == "1.0.0"
+        ));
     }
 
     #[test]
@@ -310,6 +448,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -334,6 +474,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: None,
@@ -363,6 +505,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -383,4 +527,244 @@ mod tests {
             _ => panic!("Expected Numerical mapper"),
         }
     }
+
+    fn versioned_params(version: &str, truth_point: f64, deprecated: bool) -> NumericalParams {
+        NumericalParams {
+            base: crate::mapper::types::BaseMapperParams {
+                id: "versioned".to_string(),
+                version: version.to_string(),
+                mapper_type: MapperType::Numerical,
+                description: None,
+                metadata: None,
+                input_path: None,
+                deprecated,
+            },
+            falsity_point: 1.0,
+            indeterminacy_point: 1.5,
+            truth_point,
+            clamp_to_range: Some(true),
+        }
+    }
+
+    fn truth_point_of(mapper: &Arc<dyn Mapper>) -> f64 {
+        mapper
+            .get_params()
+            .downcast_ref::<NumericalParams>()
+            .unwrap()
+            .truth_point
+    }
+
+    #[test]
+    fn test_registry_side_by_side_versions() {
+        let registry = DefaultMapperRegistry::new();
+
+        for (version, truth_point, deprecated) in [
+            ("1.0.0", 2.0, false),
+            ("1.2.0", 3.0, false),
+            ("1.2.5", 4.0, false),
+            ("2.0.0", 5.0, true),
+        ] {
+            let mapper =
+                NumericalMapper::new(versioned_params(version, truth_point, deprecated)).unwrap();
+            registry.register(Box::new(mapper)).unwrap();
+        }
+
+        assert_eq!(registry.list(), vec!["versioned".to_string()]);
+        assert_eq!(
+            registry.versions("versioned"),
+            vec!["1.0.0", "1.2.0", "1.2.5", "2.0.0"]
+        );
+
+        // Bare ID resolves to the latest non-deprecated version
+        assert_eq!(truth_point_of(&registry.get("versioned").unwrap()), 4.0);
+        // Pins resolve to the latest matching version, deprecated or not
+        assert_eq!(truth_point_of(&registry.get("versioned@1.2").unwrap()), 4.0);
+        assert_eq!(
+            truth_point_of(&registry.get("versioned@1.2.0").unwrap()),
+            3.0
+        );
+        assert_eq!(truth_point_of(&registry.get("versioned@2").unwrap()), 5.0);
+        assert!(registry.get("versioned@3").is_none());
+
+        assert_eq!(registry.export().len(), 4);
+        assert_eq!(registry.get_by_type(MapperType::Numerical).len(), 1);
+    }
+
+    #[test]
+    fn test_registry_rejects_invalid_version() {
+        let registry = DefaultMapperRegistry::new();
+        let mapper = NumericalMapper::new(versioned_params("v1", 3.0, false)).unwrap();
+        assert!(registry.register(Box::new(mapper)).is_err());
+        assert!(registry.list().is_empty());
+    }
+
+    #[test]
+    fn test_mapped_judgment_records_exact_version() {
+        let registry = DefaultMapperRegistry::new();
+        let mapper = NumericalMapper::new(versioned_params("1.2.0", 3.0, false)).unwrap();
+        registry.register(Box::new(mapper)).unwrap();
+
+        let judgment = registry
+            .get("versioned@1.2")
+            .unwrap()
+            .apply(&2.0f64)
+            .unwrap();
+        let metadata = judgment.provenance_chain[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata["mapper_id"], "versioned");
+        assert_eq!(metadata["mapper_version"], "1.2.0");
+    }
+
+    #[test]
+    fn test_registry_unregister() {
+        let registry = DefaultMapperRegistry::new();
+        for version in ["1.0.0", "1.1.0", "2.0.0"] {
+            let mapper = NumericalMapper::new(versioned_params(version, 3.0, false)).unwrap();
+            registry.register(Box::new(mapper)).unwrap();
+        }
+        let held = registry.get("versioned").unwrap();
+
+        registry.unregister("versioned@1").unwrap();
+        assert_eq!(registry.versions("versioned"), vec!["2.0.0"]);
+
+        registry.unregister("versioned").unwrap();
+        assert!(registry.list().is_empty());
+        assert!(registry.unregister("versioned").is_err());
+
+        // Readers holding the mapper keep working
+        assert!(held.apply(&2.0f64).is_ok());
+    }
+
+    #[test]
+    fn test_registry_replace() {
+        let registry = DefaultMapperRegistry::new();
+        let original = NumericalMapper::new(versioned_params("1.0.0", 3.0, false)).unwrap();
+        assert!(registry.replace(Box::new(original)).unwrap().is_none());
+
+        let recalibrated = NumericalMapper::new(versioned_params("1.0.0", 4.0, false)).unwrap();
+        let previous = registry.replace(Box::new(recalibrated)).unwrap().unwrap();
+
+        assert_eq!(truth_point_of(&previous), 3.0);
+        assert_eq!(truth_point_of(&registry.get("versioned").unwrap()), 4.0);
+        assert_eq!(registry.versions("versioned").len(), 1);
+    }
+
+    #[test]
+    fn test_registry_swap_all() {
+        let registry = DefaultMapperRegistry::new();
+        let mapper = NumericalMapper::new(versioned_params("1.0.0", 3.0, false)).unwrap();
+        registry.register(Box::new(mapper)).unwrap();
+
+        // Invalid batch leaves the registry untouched
+        let invalid = vec![
+            MapperParams::Numerical(versioned_params("2.0.0", 4.0, false)),
+            MapperParams::Numerical(versioned_params("2.0.0", 5.0, false)),
+        ];
+        assert!(registry.swap_all(invalid).is_err());
+        assert_eq!(registry.versions("versioned"), vec!["1.0.0"]);
+
+        let next = vec![
+            MapperParams::Numerical(versioned_params("2.0.0", 4.0, false)),
+            MapperParams::Numerical(versioned_params("2.1.0", 5.0, false)),
+        ];
+        registry.swap_all(next).unwrap();
+        assert_eq!(registry.versions("versioned"), vec!["2.0.0", "2.1.0"]);
+        assert_eq!(truth_point_of(&registry.get("versioned").unwrap()), 5.0);
+    }
+
+    #[test]
+    fn test_registry_apply_and_apply_batch() {
+        let registry = DefaultMapperRegistry::new();
+        let mapper = NumericalMapper::new(versioned_params("1.0.0", 3.0, false)).unwrap();
+        registry.register(Box::new(mapper)).unwrap();
+
+        let judgment = registry.apply("versioned", &3.0f64).unwrap();
+        assert_eq!(judgment.t, 1.0);
+        assert_eq!(judgment.judgment_id.as_ref().unwrap().len(), 64);
+
+        let event = serde_json::json!(2.0);
+        let results = registry.apply_batch(&[
+            ("versioned", &1.0f64),
+            ("missing", &1.0f64),
+            ("versioned", &"not a number"),
+            ("versioned@1.0.0", &event),
+        ]);
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].as_ref().unwrap().judgment_id.is_some());
+        assert!(matches!(
+            results[1],
+            Err(crate::error::OpenTrustError::MapperNotFound { .. })
+        ));
+        assert!(matches!(
+            results[2],
+            Err(crate::error::OpenTrustError::InputType { ref expected, .. }) if expected == "number"
+        ));
+        assert!(results[3].is_ok());
+    }
+
+    /// A registry implementing only the required trait methods
+    struct ExportOnlyRegistry(Vec<MapperParams>);
+
+    impl MapperRegistry for ExportOnlyRegistry {
+        fn register(&self, _mapper: Box<dyn Mapper>) -> crate::Result<()> {
+            Ok(())
+        }
+
+        fn get(&self, _id: &str) -> Option<Arc<dyn Mapper>> {
+            None
+        }
+
+        fn get_by_type(&self, _mapper_type: MapperType) -> Vec<Arc<dyn Mapper>> {
+            Vec::new()
+        }
+
+        fn list(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        fn export(&self) -> Vec<MapperParams> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_default_registry_methods() {
+        let config = |version: &str| {
+            MapperParams::Boolean(BooleanParams {
+                base: crate::mapper::types::BaseMapperParams {
+                    id: "ssl".to_string(),
+                    version: version.to_string(),
+                    mapper_type: MapperType::Boolean,
+                    description: None,
+                    metadata: None,
+                    input_path: None,
+                    deprecated: false,
+                },
+                true_map: JudgmentData {
+                    T: 0.9,
+                    I: 0.1,
+                    F: 0.0,
+                },
+                false_map: JudgmentData {
+                    T: 0.0,
+                    I: 0.1,
+                    F: 0.9,
+                },
+            })
+        };
+        let registry = ExportOnlyRegistry(vec![config("1.10.0"), config("1.2.0")]);
+
+        assert_eq!(registry.versions("ssl"), vec!["1.2.0", "1.10.0"]);
+        assert!(registry.versions("other").is_empty());
+        assert!(matches!(
+            registry.unregister("ssl"),
+            Err(crate::error::OpenTrustError::UnsupportedRegistryOperation {
+                operation: "unregister"
+            })
+        ));
+        assert!(matches!(
+            registry.swap_all(Vec::new()),
+            Err(crate::error::OpenTrustError::UnsupportedRegistryOperation { .. })
+        ));
+    }
 }
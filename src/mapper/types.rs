@@ -4,7 +4,6 @@ use crate::judgment::NeutrosophicJudgment;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Enumeration of supported mapper types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,14 +14,16 @@ pub enum MapperType {
     Categorical,
     /// Boolean mapper for boolean values
     Boolean,
+    /// Calibration mapper fitted from historical outcomes
+    Calibration,
 }
 
 /// Base parameters for all mappers
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BaseMapperParams {
     /// Unique identifier for the mapper
     pub id: String,
-    /// Version of the mapper
+    /// Version of the mapper (semantic version, e.g. `"1.2.0"`)
     pub version: String,
     /// Type of the mapper
     pub mapper_type: MapperType,
@@ -30,10 +31,19 @@ pub struct BaseMapperParams {
     pub description: Option<String>,
     /// Optional metadata
     pub metadata: Option<HashMap<String, serde_json::Value>>,
+    /// Optional JSON pointer (RFC 6901) locating the field to map when the
+    /// input is a `serde_json::Value` document, e.g. `"/account/health_factor"`
+    pub input_path: Option<String>,
+    /// Whether this version is deprecated
+    ///
+    /// Deprecated versions stay resolvable by pinned lookups (`"id@1.0.0"`)
+    /// but are skipped when resolving the latest version of an ID.
+    #[serde(default)]
+    pub deprecated: bool,
 }
 
 /// Parameters for NumericalMapper
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NumericalParams {
     /// Base parameters
     #[serde(flatten)]
@@ -49,7 +59,7 @@ pub struct NumericalParams {
 }
 
 /// Parameters for CategoricalMapper
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategoricalParams {
     /// Base parameters
     #[serde(flatten)]
@@ -61,7 +71,7 @@ pub struct CategoricalParams {
 }
 
 /// Parameters for BooleanMapper
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BooleanParams {
     /// Base parameters
     #[serde(flatten)]
@@ -72,9 +82,69 @@ pub struct BooleanParams {
     pub false_map: JudgmentData,
 }
 
+/// Parameters for CalibrationMapper
+///
+/// Produced by [`CalibrationMapper::fit`](crate::mapper::CalibrationMapper::fit)
+/// from historical `(score, OutcomeType)` pairs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationParams {
+    /// Base parameters
+    #[serde(flatten)]
+    pub base: BaseMapperParams,
+    /// Fitted score-to-probability curve
+    pub curve: CalibrationCurve,
+    /// Training samples per score range, in ascending order
+    pub support: Vec<SupportBin>,
+    /// Evidence weight of the unknown: I = prior_weight / (count + prior_weight)
+    pub prior_weight: f64,
+    /// Number of training samples
+    pub sample_count: usize,
+    /// SHA-256 (hex) of the canonical training data
+    pub training_data_hash: String,
+}
+
+/// A fitted calibration curve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum CalibrationCurve {
+    /// Isotonic regression: non-decreasing points, interpolated linearly
+    Isotonic {
+        /// Curve points in ascending score order
+        points: Vec<CurvePoint>,
+    },
+    /// Platt scaling: probability = 1 / (1 + exp(-(a * score + b)))
+    Platt {
+        /// Slope
+        a: f64,
+        /// Intercept
+        b: f64,
+    },
+}
+
+/// A point of an isotonic calibration curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurvePoint {
+    /// Model score
+    pub score: f64,
+    /// Calibrated probability of success
+    pub probability: f64,
+}
+
+/// Number of training samples with a score in [lower, upper), or in
+/// [lower, upper] for the last bin
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SupportBin {
+    /// Lowest score of the bin
+    pub lower: f64,
+    /// Highest score of the bin
+    pub upper: f64,
+    /// Training samples in the bin
+    pub count: usize,
+}
+
 /// Judgment data structure
 #[allow(non_snake_case)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JudgmentData {
     /// Truth degree [0.0, 1.0]
     pub T: f64,
@@ -85,7 +155,7 @@ pub struct JudgmentData {
 }
 
 /// Union type for all mapper parameters
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "mapper_type", content = "params")]
 pub enum MapperParams {
     /// Numerical mapper parameters
@@ -97,6 +167,31 @@ pub enum MapperParams {
     /// Boolean mapper parameters
     #[serde(rename = "boolean")]
     Boolean(BooleanParams),
+    /// Calibration mapper parameters
+    #[serde(rename = "calibration")]
+    Calibration(CalibrationParams),
+}
+
+impl MapperParams {
+    /// Get the base parameters shared by all mapper types
+    pub fn base(&self) -> &BaseMapperParams {
+        match self {
+            MapperParams::Numerical(params) => &params.base,
+            MapperParams::Categorical(params) => &params.base,
+            MapperParams::Boolean(params) => &params.base,
+            MapperParams::Calibration(params) => &params.base,
+        }
+    }
+
+    /// Get the mapper ID
+    pub fn id(&self) -> &str {
+        &self.base().id
+    }
+
+    /// Get the mapper version
+    pub fn version(&self) -> &str {
+        &self.base().version
+    }
 }
 
 /// Provenance entry for tracking transformations
@@ -157,6 +252,9 @@ pub enum InputError {
 
     #[error("Invalid input format: {message}")]
     InvalidFormat { message: String },
+
+    #[error("Input field not found at JSON pointer '{path}'")]
+    MissingField { path: String },
 }
 
 /// Validation-related errors
@@ -166,10 +264,13 @@ pub enum ValidationError {
     InvalidJudgment { message: String },
 
     #[error("Conservation constraint violated: T + I + F = {sum} > 1.0")]
-    ConservationViolation { sum: f64 },
+    ConservationViolation { t: f64, i: f64, f: f64, sum: f64 },
 
     #[error("Missing required parameter: {param}")]
     MissingParameter { param: String },
+
+    #[error("Invalid parameter {param}: {message}")]
+    InvalidParameter { param: String, message: String },
 }
 
 /// Global mapper registry
@@ -181,25 +282,196 @@ pub trait MapperRegistry: Send + Sync {
     fn register(&self, mapper: Box<dyn Mapper>) -> crate::Result<()>;
 
     /// Get a mapper by ID
+    ///
+    /// A bare ID (`"health-factor"`) resolves to the latest non-deprecated
+    /// version; `"health-factor@1.2"` pins the latest `1.2.x` version and
+    /// `"health-factor@1.2.3"` pins an exact version.
     fn get(&self, id: &str) -> Option<Arc<dyn Mapper>>;
 
-    /// Get all mappers of a specific type
+    /// List the registered versions of a mapper ID, oldest first
+    ///
+    /// The default implementation collects the versions from
+    /// [`export`](Self::export).
+    fn versions(&self, id: &str) -> Vec<String> {
+        let mut versions: Vec<semver::Version> = self
+            .export()
+            .iter()
+            .filter(|config| config.id() == id)
+            .filter_map(|config| semver::Version::parse(config.version()).ok())
+            .collect();
+        versions.sort();
+        versions.dedup();
+        versions.iter().map(ToString::to_string).collect()
+    }
+
+    /// Get the latest version of every mapper of a specific type
     fn get_by_type(&self, mapper_type: MapperType) -> Vec<Arc<dyn Mapper>>;
 
-    /// List all registered mappers
+    /// List all registered mapper IDs
     fn list(&self) -> Vec<String>;
 
-    /// Export all mappers as configuration
+    /// Export all mapper versions as configuration, sorted by ID and version
     fn export(&self) -> Vec<MapperParams>;
+
+    /// Register mappers from exported configurations
+    ///
+    /// Every configuration is checked with [`MapperValidator`](crate::mapper::MapperValidator)
+    /// before any mapper is registered, so a failed import leaves the registry unchanged.
+    fn import(&self, configs: Vec<MapperParams>) -> crate::Result<()> {
+        let mut problems = config_problems(&configs);
+        for config in &configs {
+            let key = format!("{}@{}", config.id(), config.version());
+            if self.get(&key).is_some() {
+                problems.push(format!(
+                    "{}: version {} already exists",
+                    config.id(),
+                    config.version()
+                ));
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(MapperError::Configuration {
+                message: problems.join("; "),
+            }
+            .into());
+        }
+
+        let mappers = configs
+            .into_iter()
+            .map(crate::mapper::registry::build_mapper)
+            .collect::<crate::Result<Vec<_>>>()?;
+        for mapper in mappers {
+            self.register(mapper)?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply the mapper resolved from `id` (see [`get`](Self::get)) to an input
+    ///
+    /// The returned judgment has its judgment ID assigned.
+    fn apply(&self, id: &str, input: &dyn std::any::Any) -> crate::Result<NeutrosophicJudgment> {
+        let mapper = self
+            .get(id)
+            .ok_or_else(|| crate::error::OpenTrustError::MapperNotFound { id: id.to_string() })?;
+        crate::judgment_id::ensure_judgment_id(mapper.apply(input)?)
+    }
+
+    /// Apply mappers to a batch of `(id, input)` pairs
+    ///
+    /// Every item is processed; the result for each item is returned in order,
+    /// so one failing input does not prevent the others from being mapped.
+    fn apply_batch(
+        &self,
+        items: &[(&str, &dyn std::any::Any)],
+    ) -> Vec<crate::Result<NeutrosophicJudgment>> {
+        items
+            .iter()
+            .map(|(id, input)| self.apply(id, *input))
+            .collect()
+    }
+
+    /// Remove a mapper
+    ///
+    /// A bare ID removes every version; `"id@1.2"` removes the matching
+    /// versions only. Callers already holding an `Arc<dyn Mapper>` keep a
+    /// working mapper.
+    ///
+    /// The default implementation returns
+    /// `OpenTrustError::UnsupportedRegistryOperation`.
+    fn unregister(&self, id: &str) -> crate::Result<()> {
+        let _ = id;
+        Err(crate::error::OpenTrustError::UnsupportedRegistryOperation {
+            operation: "unregister",
+        })
+    }
+
+    /// Register a mapper, replacing the mapper with the same ID and version
+    ///
+    /// Returns the replaced mapper, if there was one. The default
+    /// implementation returns `OpenTrustError::UnsupportedRegistryOperation`.
+    fn replace(&self, mapper: Box<dyn Mapper>) -> crate::Result<Option<Arc<dyn Mapper>>> {
+        let _ = mapper;
+        Err(crate::error::OpenTrustError::UnsupportedRegistryOperation {
+            operation: "replace",
+        })
+    }
+
+    /// Atomically replace the whole registry contents
+    ///
+    /// Every configuration is validated and built before the swap, so on error
+    /// the registry is left unchanged and readers never observe a partial state.
+    /// The default implementation returns
+    /// `OpenTrustError::UnsupportedRegistryOperation`.
+    fn swap_all(&self, configs: Vec<MapperParams>) -> crate::Result<()> {
+        let _ = configs;
+        Err(crate::error::OpenTrustError::UnsupportedRegistryOperation {
+            operation: "swap_all",
+        })
+    }
+
+    /// Load every mapper definition in a directory and register it
+    ///
+    /// Returns the number of mappers registered.
+    fn import_dir(&self, dir: &std::path::Path) -> crate::Result<usize> {
+        let configs = crate::mapper::loader::load_mapper_dir(dir)?;
+        let count = configs.len();
+        self.import(configs)?;
+        Ok(count)
+    }
+}
+
+/// Collect validation problems and duplicate versions in a batch of configurations
+pub(crate) fn config_problems(configs: &[MapperParams]) -> Vec<String> {
+    let validator = crate::mapper::validator::MapperValidator::new();
+    let mut problems = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for config in configs {
+        let result = validator.validate(config);
+        for error in result.errors {
+            problems.push(format!("{}: {}", config.id(), error));
+        }
+        if !seen.insert((config.id(), config.version())) {
+            problems.push(format!(
+                "{}: version {} is defined more than once",
+                config.id(),
+                config.version()
+            ));
+        }
+    }
+
+    problems
 }
 
-/// Create a timestamp string
+/// Create a timestamp string (seconds since the Unix epoch)
+///
+/// Uses `chrono`'s clock, which reads `Date.now()` on wasm32 with the `wasm`
+/// feature, where `SystemTime::now` is unavailable.
 pub fn create_timestamp() -> String {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        .to_string()
+    chrono::Utc::now().timestamp().to_string()
+}
+
+/// Insert the mapper identity (ID, exact version, deprecation) into provenance metadata
+pub fn insert_mapper_identity(
+    metadata: &mut HashMap<String, serde_json::Value>,
+    base: &BaseMapperParams,
+) {
+    metadata.insert(
+        "mapper_id".to_string(),
+        serde_json::Value::String(base.id.clone()),
+    );
+    metadata.insert(
+        "mapper_version".to_string(),
+        serde_json::Value::String(base.version.clone()),
+    );
+    if base.deprecated {
+        metadata.insert(
+            "mapper_deprecated".to_string(),
+            serde_json::Value::Bool(true),
+        );
+    }
 }
 
 /// Validate judgment values (T, I, F)
@@ -225,7 +497,12 @@ pub fn validate_judgment_values(T: f64, I: f64, F: f64) -> Result<(), Validation
 
     let sum = T + I + F;
     if sum > 1.0 {
-        return Err(ValidationError::ConservationViolation { sum });
+        return Err(ValidationError::ConservationViolation {
+            t: T,
+            i: I,
+            f: F,
+            sum,
+        });
     }
 
     Ok(())
@@ -241,12 +518,82 @@ pub fn create_judgment(
 ) -> crate::Result<NeutrosophicJudgment> {
     validate_judgment_values(T, I, F)?;
 
-    let provenance: Vec<(String, String)> = provenance_chain
+    let provenance: Vec<crate::judgment::ProvenanceEntry> = provenance_chain
         .into_iter()
-        .map(|entry| (entry.source_id, entry.timestamp))
+        .map(|entry| crate::judgment::ProvenanceEntry {
+            source_id: entry.source_id,
+            timestamp: entry.timestamp,
+            description: entry.description,
+            metadata: entry
+                .metadata
+                .map(|metadata| serde_json::Value::Object(metadata.into_iter().collect())),
+            conformance_seal: entry.conformance_seal,
+        })
         .collect();
 
-    NeutrosophicJudgment::new(T, I, F, provenance)
+    NeutrosophicJudgment::new_with_entries(T, I, F, provenance)
+}
+
+/// Resolve the value to map from a JSON input, following `input_path` if set
+pub fn resolve_json_input<'a>(
+    base: &BaseMapperParams,
+    input: &'a serde_json::Value,
+) -> Result<&'a serde_json::Value, InputError> {
+    match base.input_path {
+        Some(ref path) => input
+            .pointer(path)
+            .ok_or_else(|| InputError::MissingField { path: path.clone() }),
+        None => Ok(input),
+    }
+}
+
+/// Get the JSON type name of a value for error reporting
+pub fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// Parse a textual boolean representation
+fn parse_boolean_str(val: &str) -> Result<bool, InputError> {
+    let lower = val.to_lowercase().trim().to_string();
+    match lower.as_str() {
+        "true" | "yes" | "1" | "on" | "enabled" => Ok(true),
+        "false" | "no" | "0" | "off" | "disabled" => Ok(false),
+        _ => Err(InputError::InvalidFormat {
+            message: format!(
+                "String input must be a valid boolean representation, got '{}'",
+                val
+            ),
+        }),
+    }
+}
+
+/// Normalize boolean input from a JSON value
+pub fn normalize_json_boolean_input(input: &serde_json::Value) -> Result<bool, InputError> {
+    match input {
+        serde_json::Value::Bool(val) => Ok(*val),
+        serde_json::Value::Number(val) => match val.as_i64() {
+            Some(1) => Ok(true),
+            Some(0) => Ok(false),
+            _ => Err(InputError::InvalidFormat {
+                message: format!(
+                    "Numeric input for BooleanMapper must be 0 or 1, got {}",
+                    val
+                ),
+            }),
+        },
+        serde_json::Value::String(val) => parse_boolean_str(val),
+        other => Err(InputError::InvalidType {
+            expected: "boolean, number, or string".to_string(),
+            actual: json_type_name(other).to_string(),
+        }),
+    }
 }
 
 /// Normalize boolean input from various types
@@ -255,6 +602,10 @@ pub fn normalize_boolean_input(input: &dyn std::any::Any) -> Result<bool, InputE
         return Ok(*val);
     }
 
+    if let Some(val) = input.downcast_ref::<serde_json::Value>() {
+        return normalize_json_boolean_input(val);
+    }
+
     if let Some(val) = input.downcast_ref::<i32>() {
         match *val {
             1 => Ok(true),
@@ -267,20 +618,10 @@ pub fn normalize_boolean_input(input: &dyn std::any::Any) -> Result<bool, InputE
             }),
         }
     } else if let Some(val) = input.downcast_ref::<String>() {
-        let lower = val.to_lowercase().trim().to_string();
-        match lower.as_str() {
-            "true" | "yes" | "1" | "on" | "enabled" => Ok(true),
-            "false" | "no" | "0" | "off" | "disabled" => Ok(false),
-            _ => Err(InputError::InvalidFormat {
-                message: format!(
-                    "String input must be a valid boolean representation, got '{}'",
-                    val
-                ),
-            }),
-        }
+        parse_boolean_str(val)
     } else {
         Err(InputError::InvalidType {
-            expected: "bool, i32, or String".to_string(),
+            expected: "bool, i32, String, or serde_json::Value".to_string(),
             actual: std::any::type_name_of_val(input).to_string(),
         })
     }
@@ -1,184 +1,417 @@
 //! MapperValidator implementation for JSON Schema validation
+//!
+//! Configurations are first checked against the published mapper schemas (see
+//! [`crate::schema`]); rules JSON Schema cannot express, such as the
+//! conservation constraint, are checked in Rust afterwards. Every problem is
+//! reported as a [`ValidationIssue`] with a stable [`IssueCode`], a
+//! [`Severity`], a JSON pointer into the serialized configuration and, where
+//! possible, a suggested fix.
+//!
+//! Warnings and infos are lints: they flag suspicious but legal configurations
+//! and never make a result invalid. Callers that want stricter checks (for
+//! example in CI) can fail on chosen codes with [`ValidationResult::has_any`].
 
-use crate::mapper::types::MapperParams;
 #[cfg(test)]
 use crate::mapper::types::MapperType;
+use crate::mapper::types::{JudgmentData, MapperParams};
+use crate::schema::{
+    boolean_mapper_schema, calibration_mapper_schema, categorical_mapper_schema,
+    mapper_params_schema, numerical_mapper_schema, CompiledSchema, SchemaViolation,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Noteworthy, no action needed
+    Info,
+    /// Legal but probably a mistake
+    Warning,
+    /// The configuration cannot be used
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Stable identifier for a kind of validation issue
+///
+/// The string form returned by [`IssueCode::as_str`] (e.g. `OTP-E004`) never
+/// changes meaning once released, so it is safe to reference from CI
+/// configuration. New codes may be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "String")]
+#[non_exhaustive]
+pub enum IssueCode {
+    /// The JSON text or value could not be read as a mapper configuration
+    Malformed,
+    /// A schema rule not covered by a more specific code failed
+    SchemaViolation,
+    /// A required field is missing
+    MissingField,
+    /// A number is outside its allowed range
+    OutOfRange,
+    /// The mapper ID is empty
+    EmptyId,
+    /// The version is not a semantic version
+    InvalidVersion,
+    /// A judgment's T + I + F exceeds 1
+    ConservationViolation,
+    /// Two numerical anchor points are equal
+    PointsNotDistinct,
+    /// A boolean mapper maps `true` and `false` to the same judgment
+    IdenticalBooleanMaps,
+    /// A boolean mapper gives `false` more truth than `true`
+    InvertedBooleanMaps,
+    /// The numerical indeterminacy point is not between the falsity and truth points
+    IndeterminacyOutsideRange,
+    /// A categorical judgment has T, I and F all zero
+    ZeroJudgment,
+    /// Category names differ only by letter case
+    CaseCollidingCategories,
+    /// A categorical mapper has no default judgment, so unknown categories fail
+    MissingDefaultJudgment,
+    /// The mapper version is marked deprecated
+    DeprecatedVersion,
+    /// A calibration curve or its support bins are not ordered or not finite
+    InvalidCalibration,
+}
+
+impl IssueCode {
+    /// Every known code
+    pub const ALL: &'static [IssueCode] = &[
+        IssueCode::Malformed,
+        IssueCode::SchemaViolation,
+        IssueCode::MissingField,
+        IssueCode::OutOfRange,
+        IssueCode::EmptyId,
+        IssueCode::InvalidVersion,
+        IssueCode::ConservationViolation,
+        IssueCode::PointsNotDistinct,
+        IssueCode::IdenticalBooleanMaps,
+        IssueCode::InvertedBooleanMaps,
+        IssueCode::IndeterminacyOutsideRange,
+        IssueCode::ZeroJudgment,
+        IssueCode::CaseCollidingCategories,
+        IssueCode::MissingDefaultJudgment,
+        IssueCode::DeprecatedVersion,
+        IssueCode::InvalidCalibration,
+    ];
+
+    /// The stable string form of the code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueCode::Malformed => "OTP-E001",
+            IssueCode::SchemaViolation => "OTP-E002",
+            IssueCode::MissingField => "OTP-E003",
+            IssueCode::OutOfRange => "OTP-E004",
+            IssueCode::EmptyId => "OTP-E005",
+            IssueCode::InvalidVersion => "OTP-E006",
+            IssueCode::ConservationViolation => "OTP-E007",
+            IssueCode::PointsNotDistinct => "OTP-E008",
+            IssueCode::InvalidCalibration => "OTP-E009",
+            IssueCode::IdenticalBooleanMaps => "OTP-W001",
+            IssueCode::InvertedBooleanMaps => "OTP-W002",
+            IssueCode::IndeterminacyOutsideRange => "OTP-W003",
+            IssueCode::ZeroJudgment => "OTP-W004",
+            IssueCode::CaseCollidingCategories => "OTP-W005",
+            IssueCode::MissingDefaultJudgment => "OTP-I001",
+            IssueCode::DeprecatedVersion => "OTP-I002",
+        }
+    }
+
+    /// The severity issues with this code are reported at
+    pub fn severity(&self) -> Severity {
+        match self.as_str().as_bytes()[4] {
+            b'E' => Severity::Error,
+            b'W' => Severity::Warning,
+            _ => Severity::Info,
+        }
+    }
+}
+
+impl fmt::Display for IssueCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IssueCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IssueCode::ALL
+            .iter()
+            .find(|code| code.as_str() == s)
+            .copied()
+            .ok_or_else(|| format!("unknown validation issue code '{}'", s))
+    }
+}
+
+impl From<IssueCode> for &'static str {
+    fn from(code: IssueCode) -> Self {
+        code.as_str()
+    }
+}
+
+impl TryFrom<String> for IssueCode {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
 
 /// Validation result for mapper configurations
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
-    /// Whether the configuration is valid
+    /// Whether the configuration is valid (no error-severity issues)
     pub valid: bool,
-    /// List of validation errors
+    /// Messages of the error-severity issues
     pub errors: Vec<String>,
+    /// Every issue found, including warnings and infos
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationResult {
+    fn from_issues(issues: Vec<ValidationIssue>) -> Self {
+        let errors: Vec<String> = issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .map(ToString::to_string)
+            .collect();
+        Self {
+            valid: errors.is_empty(),
+            errors,
+            issues,
+        }
+    }
+
+    /// Issues at exactly the given severity
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.severity == severity)
+    }
+
+    /// Whether any issue has one of the given codes
+    pub fn has_any(&self, codes: &[IssueCode]) -> bool {
+        self.issues.iter().any(|issue| codes.contains(&issue.code))
+    }
+}
+
+/// A single validation problem in a mapper configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// Stable identifier of the kind of problem
+    pub code: IssueCode,
+    /// How serious the problem is
+    pub severity: Severity,
+    /// JSON pointer into the serialized configuration, e.g. `/params/true_map/T`
+    pub path: String,
+    /// Description of the problem
+    pub message: String,
+    /// How the problem could be fixed, when there is an obvious fix
+    pub suggestion: Option<String>,
+}
+
+impl ValidationIssue {
+    fn new(code: IssueCode, path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            path: path.into(),
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+impl From<SchemaViolation> for ValidationIssue {
+    fn from(violation: SchemaViolation) -> Self {
+        let path = violation.instance_path;
+        let (code, suggestion) = match violation.keyword.as_str() {
+            "required" => (IssueCode::MissingField, None),
+            "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" => (
+                IssueCode::OutOfRange,
+                Some("use a value between 0.0 and 1.0"),
+            ),
+            "minLength" if path == "/params/id" => {
+                (IssueCode::EmptyId, Some("give the mapper a non-empty id"))
+            }
+            "pattern" if path == "/params/version" => (
+                IssueCode::InvalidVersion,
+                Some("use a semantic version such as \"1.0.0\""),
+            ),
+            _ => (IssueCode::SchemaViolation, None),
+        };
+
+        let issue = Self::new(code, path, violation.message);
+        match suggestion {
+            Some(suggestion) => issue.suggest(suggestion),
+            None => issue,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
 }
 
 /// MapperValidator for validating mapper configurations against JSON Schema
 pub struct MapperValidator {
-    // JSON Schema definitions for each mapper type (currently unused but kept for future schema validation)
-    #[allow(dead_code)]
-    numerical_schema: Value,
-    #[allow(dead_code)]
-    categorical_schema: Value,
-    #[allow(dead_code)]
-    boolean_schema: Value,
+    numerical_schema: CompiledSchema,
+    categorical_schema: CompiledSchema,
+    boolean_schema: CompiledSchema,
+    calibration_schema: CompiledSchema,
+    any_schema: CompiledSchema,
 }
 
 impl MapperValidator {
     /// Create a new MapperValidator
     pub fn new() -> Self {
         Self {
-            numerical_schema: Self::create_numerical_schema(),
-            categorical_schema: Self::create_categorical_schema(),
-            boolean_schema: Self::create_boolean_schema(),
+            numerical_schema: CompiledSchema::compile(Self::create_numerical_schema()),
+            categorical_schema: CompiledSchema::compile(Self::create_categorical_schema()),
+            boolean_schema: CompiledSchema::compile(Self::create_boolean_schema()),
+            calibration_schema: CompiledSchema::compile(calibration_mapper_schema()),
+            any_schema: CompiledSchema::compile(mapper_params_schema()),
         }
     }
 
     /// Create JSON Schema for NumericalMapper
     fn create_numerical_schema() -> Value {
-        serde_json::json!({
-            "type": "object",
-            "properties": {
-                "id": {"type": "string"},
-                "version": {"type": "string"},
-                "mapper_type": {"const": "numerical"},
-                "falsity_point": {"type": "number"},
-                "indeterminacy_point": {"type": "number"},
-                "truth_point": {"type": "number"},
-                "clamp_to_range": {"type": "boolean"}
-            },
-            "required": ["id", "version", "mapper_type", "falsity_point", "indeterminacy_point", "truth_point"]
-        })
+        numerical_mapper_schema()
     }
 
     /// Create JSON Schema for CategoricalMapper
     fn create_categorical_schema() -> Value {
-        serde_json::json!({
-            "type": "object",
-            "properties": {
-                "id": {"type": "string"},
-                "version": {"type": "string"},
-                "mapper_type": {"const": "categorical"},
-                "mappings": {
-                    "type": "object",
-                    "additionalProperties": {
-                        "type": "object",
-                        "properties": {
-                            "T": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                            "I": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                            "F": {"type": "number", "minimum": 0.0, "maximum": 1.0}
-                        },
-                        "required": ["T", "I", "F"]
-                    }
-                },
-                "default_judgment": {
-                    "type": "object",
-                    "properties": {
-                        "T": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "I": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "F": {"type": "number", "minimum": 0.0, "maximum": 1.0}
-                    },
-                    "required": ["T", "I", "F"]
-                }
-            },
-            "required": ["id", "version", "mapper_type", "mappings"]
-        })
+        categorical_mapper_schema()
     }
 
     /// Create JSON Schema for BooleanMapper
     fn create_boolean_schema() -> Value {
-        serde_json::json!({
-            "type": "object",
-            "properties": {
-                "id": {"type": "string"},
-                "version": {"type": "string"},
-                "mapper_type": {"const": "boolean"},
-                "true_map": {
-                    "type": "object",
-                    "properties": {
-                        "T": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "I": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "F": {"type": "number", "minimum": 0.0, "maximum": 1.0}
-                    },
-                    "required": ["T", "I", "F"]
-                },
-                "false_map": {
-                    "type": "object",
-                    "properties": {
-                        "T": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "I": {"type": "number", "minimum": 0.0, "maximum": 1.0},
-                        "F": {"type": "number", "minimum": 0.0, "maximum": 1.0}
-                    },
-                    "required": ["T", "I", "F"]
-                }
-            },
-            "required": ["id", "version", "mapper_type", "true_map", "false_map"]
-        })
+        boolean_mapper_schema()
     }
 
     /// Validate a mapper configuration
     pub fn validate(&self, config: &MapperParams) -> ValidationResult {
-        let mut errors = Vec::new();
+        let value = serde_json::to_value(config).expect("mapper configurations serialize to JSON");
+        let schema = match config {
+            MapperParams::Numerical(_) => &self.numerical_schema,
+            MapperParams::Categorical(_) => &self.categorical_schema,
+            MapperParams::Boolean(_) => &self.boolean_schema,
+            MapperParams::Calibration(_) => &self.calibration_schema,
+        };
+
+        let mut issues: Vec<ValidationIssue> = schema
+            .validate(&value)
+            .into_iter()
+            .map(ValidationIssue::from)
+            .collect();
 
         match config {
             MapperParams::Numerical(params) => {
-                self.validate_numerical(params, &mut errors);
+                self.validate_numerical(params, &mut issues);
             }
             MapperParams::Categorical(params) => {
-                self.validate_categorical(params, &mut errors);
+                self.validate_categorical(params, &mut issues);
             }
             MapperParams::Boolean(params) => {
-                self.validate_boolean(params, &mut errors);
+                self.validate_boolean(params, &mut issues);
+            }
+            MapperParams::Calibration(params) => {
+                self.validate_calibration(params, &mut issues);
             }
         }
 
-        ValidationResult {
-            valid: errors.is_empty(),
-            errors,
+        if config.base().deprecated {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::DeprecatedVersion,
+                    "/params/deprecated",
+                    format!(
+                        "version {} is deprecated and will not be resolved as the latest",
+                        config.version()
+                    ),
+                )
+                .suggest("migrate consumers to a newer version"),
+            );
         }
+
+        ValidationResult::from_issues(issues)
     }
 
     /// Validate NumericalMapper parameters
     fn validate_numerical(
         &self,
         params: &crate::mapper::types::NumericalParams,
-        errors: &mut Vec<String>,
+        issues: &mut Vec<ValidationIssue>,
     ) {
         // Check if points are distinct (using epsilon comparison for f64)
         let points = [
-            params.falsity_point,
-            params.indeterminacy_point,
-            params.truth_point,
+            ("falsity_point", params.falsity_point),
+            ("indeterminacy_point", params.indeterminacy_point),
+            ("truth_point", params.truth_point),
         ];
         let epsilon = 1e-10;
-        let mut distinct_count = 0;
-        for i in 0..3 {
-            let mut is_unique = true;
-            for j in (i + 1)..3 {
-                if (points[i] - points[j]).abs() < epsilon {
-                    is_unique = false;
-                    break;
-                }
+        for (j, (name, value)) in points.iter().enumerate() {
+            if let Some((other, _)) = points[..j]
+                .iter()
+                .find(|(_, earlier)| (earlier - value).abs() < epsilon)
+            {
+                issues.push(
+                    ValidationIssue::new(
+                        IssueCode::PointsNotDistinct,
+                        format!("/params/{}", name),
+                        format!("numerical {} equals {} ({})", name, other, value),
+                    )
+                    .suggest(
+                        "falsity_point, indeterminacy_point, and truth_point must be distinct",
+                    ),
+                );
             }
-            if is_unique {
-                distinct_count += 1;
-            }
-        }
-        if distinct_count < 3 {
-            errors.push(
-                "falsity_point, indeterminacy_point, and truth_point must be distinct".to_string(),
-            );
-        }
-
-        // Check if ID is not empty
-        if params.base.id.is_empty() {
-            errors.push("id cannot be empty".to_string());
         }
 
-        // Check if version is not empty
-        if params.base.version.is_empty() {
-            errors.push("version cannot be empty".to_string());
+        let (low, high) = if params.falsity_point <= params.truth_point {
+            (params.falsity_point, params.truth_point)
+        } else {
+            (params.truth_point, params.falsity_point)
+        };
+        if params.indeterminacy_point < low || params.indeterminacy_point > high {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::IndeterminacyOutsideRange,
+                    "/params/indeterminacy_point",
+                    format!(
+                        "numerical indeterminacy_point {} is not between falsity_point {} and truth_point {}",
+                        params.indeterminacy_point, params.falsity_point, params.truth_point
+                    ),
+                )
+                .suggest("place indeterminacy_point between falsity_point and truth_point"),
+            );
         }
     }
 
@@ -186,46 +419,63 @@ impl MapperValidator {
     fn validate_categorical(
         &self,
         params: &crate::mapper::types::CategoricalParams,
-        errors: &mut Vec<String>,
+        issues: &mut Vec<ValidationIssue>,
     ) {
-        // Check if mappings exist
-        if params.mappings.is_empty() {
-            errors.push("mappings cannot be empty".to_string());
+        let mut categories: Vec<&String> = params.mappings.keys().collect();
+        categories.sort();
+        for category in &categories {
+            let data = &params.mappings[*category];
+            let path = format!("/params/mappings/{}", escape_pointer_token(category));
+            check_conservation(
+                data,
+                &path,
+                &format!("categorical mapping '{}'", category),
+                issues,
+            );
+            check_zero(
+                data,
+                &path,
+                &format!("categorical mapping '{}'", category),
+                issues,
+            );
         }
 
-        // Validate each mapping
-        for (category, judgment_data) in &params.mappings {
-            if let Err(e) = crate::mapper::types::validate_judgment_values(
-                judgment_data.T,
-                judgment_data.I,
-                judgment_data.F,
-            ) {
-                errors.push(format!(
-                    "Invalid judgment for category '{}': {}",
-                    category, e
-                ));
+        for (j, category) in categories.iter().enumerate() {
+            if let Some(other) = categories[..j]
+                .iter()
+                .find(|earlier| earlier.to_lowercase() == category.to_lowercase())
+            {
+                issues.push(
+                    ValidationIssue::new(
+                        IssueCode::CaseCollidingCategories,
+                        format!("/params/mappings/{}", escape_pointer_token(category)),
+                        format!(
+                            "categories '{}' and '{}' differ only by letter case",
+                            other, category
+                        ),
+                    )
+                    .suggest("category lookup is case-sensitive; merge or rename one of them"),
+                );
             }
         }
 
-        // Validate default judgment if present
-        if let Some(ref default_judgment) = params.default_judgment {
-            if let Err(e) = crate::mapper::types::validate_judgment_values(
-                default_judgment.T,
-                default_judgment.I,
-                default_judgment.F,
-            ) {
-                errors.push(format!("Invalid default_judgment: {}", e));
+        match params.default_judgment {
+            Some(ref default_judgment) => {
+                check_conservation(
+                    default_judgment,
+                    "/params/default_judgment",
+                    "categorical default_judgment",
+                    issues,
+                );
             }
-        }
-
-        // Check if ID is not empty
-        if params.base.id.is_empty() {
-            errors.push("id cannot be empty".to_string());
-        }
-
-        // Check if version is not empty
-        if params.base.version.is_empty() {
-            errors.push("version cannot be empty".to_string());
+            None => issues.push(
+                ValidationIssue::new(
+                    IssueCode::MissingDefaultJudgment,
+                    "/params/default_judgment",
+                    "categorical mapper has no default_judgment; unknown categories are rejected",
+                )
+                .suggest("add a default_judgment such as {\"T\": 0.0, \"I\": 1.0, \"F\": 0.0}"),
+            ),
         }
     }
 
@@ -233,34 +483,67 @@ impl MapperValidator {
     fn validate_boolean(
         &self,
         params: &crate::mapper::types::BooleanParams,
-        errors: &mut Vec<String>,
+        issues: &mut Vec<ValidationIssue>,
     ) {
-        // Validate true_map
-        if let Err(e) = crate::mapper::types::validate_judgment_values(
-            params.true_map.T,
-            params.true_map.I,
-            params.true_map.F,
-        ) {
-            errors.push(format!("Invalid true_map: {}", e));
-        }
+        check_conservation(
+            &params.true_map,
+            "/params/true_map",
+            "boolean true_map",
+            issues,
+        );
+        check_conservation(
+            &params.false_map,
+            "/params/false_map",
+            "boolean false_map",
+            issues,
+        );
 
-        // Validate false_map
-        if let Err(e) = crate::mapper::types::validate_judgment_values(
-            params.false_map.T,
-            params.false_map.I,
-            params.false_map.F,
-        ) {
-            errors.push(format!("Invalid false_map: {}", e));
+        if params.true_map == params.false_map {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::IdenticalBooleanMaps,
+                    "/params/false_map",
+                    "boolean true_map identical to false_map; the input has no effect",
+                )
+                .suggest("give true and false inputs different judgments"),
+            );
+        } else if params.false_map.T > params.true_map.T && params.false_map.F < params.true_map.F {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::InvertedBooleanMaps,
+                    "/params/true_map",
+                    "boolean false_map has more truth and less falsity than true_map",
+                )
+                .suggest("check whether true_map and false_map were swapped"),
+            );
         }
+    }
 
-        // Check if ID is not empty
-        if params.base.id.is_empty() {
-            errors.push("id cannot be empty".to_string());
+    /// Validate CalibrationMapper parameters
+    fn validate_calibration(
+        &self,
+        params: &crate::mapper::types::CalibrationParams,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if let Some(message) = crate::mapper::calibration::curve_problem(&params.curve) {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::InvalidCalibration,
+                    "/params/curve",
+                    format!("calibration curve: {}", message),
+                )
+                .suggest("refit the mapper with CalibrationMapper::fit"),
+            );
         }
-
-        // Check if version is not empty
-        if params.base.version.is_empty() {
-            errors.push("version cannot be empty".to_string());
+        if let Some(message) = crate::mapper::calibration::support_problem(&params.support) {
+            issues.push(
+                ValidationIssue::new(
+                    IssueCode::InvalidCalibration,
+                    "/params/support",
+                    format!("calibration support: {}", message),
+                )
+                .suggest("refit the mapper with CalibrationMapper::fit"),
+            );
         }
     }
 
@@ -276,12 +559,95 @@ impl MapperValidator {
     }
 
     /// Validate a JSON configuration
+    ///
+    /// The document is checked against the mapper schema before it is
+    /// deserialized, so structural problems (wrong types, missing fields,
+    /// out-of-range values) are reported with their JSON pointer instead of
+    /// failing deserialization. Only malformed JSON text is an error.
     pub fn validate_json(&self, json: &str) -> Result<ValidationResult, serde_json::Error> {
-        let config: MapperParams = serde_json::from_str(json)?;
-        Ok(self.validate(&config))
+        let value: Value = serde_json::from_str(json)?;
+        Ok(self.validate_value(&value))
+    }
+
+    /// Validate a configuration given as a JSON value
+    pub fn validate_value(&self, value: &Value) -> ValidationResult {
+        let schema = match value.get("mapper_type").and_then(Value::as_str) {
+            Some("numerical") => &self.numerical_schema,
+            Some("categorical") => &self.categorical_schema,
+            Some("boolean") => &self.boolean_schema,
+            Some("calibration") => &self.calibration_schema,
+            _ => &self.any_schema,
+        };
+
+        let violations = schema.validate(value);
+        if !violations.is_empty() {
+            return ValidationResult::from_issues(
+                violations.into_iter().map(ValidationIssue::from).collect(),
+            );
+        }
+
+        match serde_json::from_value::<MapperParams>(value.clone()) {
+            Ok(config) => self.validate(&config),
+            Err(e) => ValidationResult::from_issues(vec![ValidationIssue::new(
+                IssueCode::Malformed,
+                "",
+                e.to_string(),
+            )]),
+        }
+    }
+}
+
+/// Report a conservation violation (`T + I + F > 1`) at the given pointer
+///
+/// Range checks are left to the schema, so only in-range values are summed.
+fn check_conservation(
+    data: &JudgmentData,
+    path: &str,
+    what: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let in_range = [data.T, data.I, data.F]
+        .iter()
+        .all(|v| (0.0..=1.0).contains(v));
+    if !in_range {
+        return;
+    }
+    if crate::mapper::types::validate_judgment_values(data.T, data.I, data.F).is_err() {
+        let sum = data.T + data.I + data.F;
+        issues.push(
+            ValidationIssue::new(
+                IssueCode::ConservationViolation,
+                path,
+                format!(
+                    "{} sums to {} (T + I + F must not exceed 1.0)",
+                    what,
+                    (sum * 1e6).round() / 1e6
+                ),
+            )
+            .suggest("lower T, I or F so they sum to at most 1.0"),
+        );
+    }
+}
+
+/// Report a judgment that carries no evidence at all
+fn check_zero(data: &JudgmentData, path: &str, what: &str, issues: &mut Vec<ValidationIssue>) {
+    if data.T == 0.0 && data.I == 0.0 && data.F == 0.0 {
+        issues.push(
+            ValidationIssue::new(
+                IssueCode::ZeroJudgment,
+                path,
+                format!("{} has T, I and F all 0.0", what),
+            )
+            .suggest("use I = 1.0 to express complete uncertainty"),
+        );
     }
 }
 
+/// Escape a key for use as a JSON pointer reference token (RFC 6901)
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
 impl Default for MapperValidator {
     fn default() -> Self {
         Self::new()
@@ -297,9 +663,9 @@ mod tests {
     #[test]
     fn test_validator_creation() {
         let validator = MapperValidator::new();
-        assert!(validator.numerical_schema.is_object());
-        assert!(validator.categorical_schema.is_object());
-        assert!(validator.boolean_schema.is_object());
+        assert!(validator.numerical_schema.schema().is_object());
+        assert!(validator.categorical_schema.schema().is_object());
+        assert!(validator.boolean_schema.schema().is_object());
     }
 
     #[test]
@@ -313,6 +679,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.5,
@@ -336,6 +704,8 @@ mod tests {
                 mapper_type: MapperType::Numerical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             falsity_point: 1.0,
             indeterminacy_point: 1.0, // Same as falsity_point
@@ -346,6 +716,134 @@ mod tests {
         let result = validator.validate(&MapperParams::Numerical(params));
         assert!(!result.valid);
         assert!(!result.errors.is_empty());
+        assert!(result.has_any(&[IssueCode::EmptyId]));
+        let distinct = result
+            .issues
+            .iter()
+            .find(|issue| issue.code == IssueCode::PointsNotDistinct)
+            .unwrap();
+        assert_eq!(distinct.path, "/params/indeterminacy_point");
+        assert_eq!(
+            distinct.message,
+            "numerical indeterminacy_point equals falsity_point (1)"
+        );
+    }
+
+    #[test]
+    fn test_validate_json_reports_pointer_paths() {
+        let validator = MapperValidator::new();
+
+        let result = validator
+            .validate_json(
+                r#"{
+                    "mapper_type": "boolean",
+                    "params": {
+                        "id": "ssl",
+                        "version": "1.0",
+                        "mapper_type": "Boolean",
+                        "true_map": {"T": 1.5, "I": 0.0, "F": 0.0},
+                        "false_map": {"T": 0.0, "I": 0.0}
+                    }
+                }"#,
+            )
+            .unwrap();
+
+        assert!(!result.valid);
+        let paths: Vec<&str> = result.issues.iter().map(|i| i.path.as_str()).collect();
+        assert!(paths.contains(&"/params/version"));
+        assert!(paths.contains(&"/params/true_map/T"));
+        assert!(paths.contains(&"/params/false_map"));
+        assert!(result
+            .issues
+            .iter()
+            .all(|issue| issue.severity == Severity::Error));
+        assert_eq!(result.errors.len(), result.issues.len());
+    }
+
+    #[test]
+    fn test_validate_conservation_path() {
+        let validator = MapperValidator::new();
+
+        let mut mappings = HashMap::new();
+        mappings.insert(
+            "a/b".to_string(),
+            JudgmentData {
+                T: 0.7,
+                I: 0.3,
+                F: 0.2,
+            },
+        );
+        let params = CategoricalParams {
+            base: crate::mapper::types::BaseMapperParams {
+                id: "test-categorical".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Categorical,
+                description: None,
+                metadata: None,
+                input_path: None,
+                deprecated: false,
+            },
+            mappings,
+            default_judgment: None,
+        };
+
+        let result = validator.validate(&MapperParams::Categorical(params));
+        let errors: Vec<&ValidationIssue> = result.with_severity(Severity::Error).collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, IssueCode::ConservationViolation);
+        assert_eq!(errors[0].path, "/params/mappings/a~1b");
+        assert_eq!(
+            errors[0].message,
+            "categorical mapping 'a/b' sums to 1.2 (T + I + F must not exceed 1.0)"
+        );
+        assert!(errors[0].suggestion.is_some());
+        assert!(result.has_any(&[IssueCode::MissingDefaultJudgment]));
+    }
+
+    #[test]
+    fn test_lint_warnings_keep_result_valid() {
+        let validator = MapperValidator::new();
+
+        let same = JudgmentData {
+            T: 0.5,
+            I: 0.5,
+            F: 0.0,
+        };
+        let params = BooleanParams {
+            base: crate::mapper::types::BaseMapperParams {
+                id: "test-boolean".to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Boolean,
+                description: None,
+                metadata: None,
+                input_path: None,
+                deprecated: false,
+            },
+            true_map: same.clone(),
+            false_map: same,
+        };
+
+        let result = validator.validate(&MapperParams::Boolean(params));
+        assert!(result.valid);
+        let warnings: Vec<&ValidationIssue> = result.with_severity(Severity::Warning).collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, IssueCode::IdenticalBooleanMaps);
+        assert_eq!(warnings[0].path, "/params/false_map");
+        // CI can still fail on chosen lint codes
+        assert!(result.has_any(&["OTP-W001".parse().unwrap()]));
+    }
+
+    #[test]
+    fn test_issue_code_strings_are_stable() {
+        for code in IssueCode::ALL {
+            assert_eq!(code.as_str().parse::<IssueCode>().unwrap(), *code);
+        }
+        assert_eq!(IssueCode::PointsNotDistinct.severity(), Severity::Error);
+        assert_eq!(
+            serde_json::to_value(IssueCode::IdenticalBooleanMaps).unwrap(),
+            serde_json::json!("OTP-W001")
+        );
+        assert!("OTP-X999".parse::<IssueCode>().is_err());
     }
 
     #[test]
@@ -369,6 +867,8 @@ mod tests {
                 mapper_type: MapperType::Categorical,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             mappings,
             default_judgment: None,
@@ -390,6 +890,8 @@ mod tests {
                 mapper_type: MapperType::Boolean,
                 description: None,
                 metadata: None,
+                input_path: None,
+                deprecated: false,
             },
             true_map: JudgmentData {
                 T: 0.9,
@@ -420,6 +922,8 @@ mod tests {
                     mapper_type: MapperType::Numerical,
                     description: None,
                     metadata: None,
+                    input_path: None,
+                    deprecated: false,
                 },
                 falsity_point: 1.0,
                 indeterminacy_point: 1.5,
@@ -433,6 +937,8 @@ mod tests {
                     mapper_type: MapperType::Numerical,
                     description: None,
                     metadata: None,
+                    input_path: None,
+                    deprecated: false,
                 },
                 falsity_point: 1.0,
                 indeterminacy_point: 1.0, // Invalid
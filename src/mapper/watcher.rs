@@ -0,0 +1,251 @@
+//! Polling-based reloading of a mapper registry from a directory of definitions
+//!
+//! The watcher fingerprints the definition files (names and contents) on every
+//! poll and, when anything changed, reloads the directory and swaps the registry
+//! contents atomically with [`MapperRegistry::swap_all`]. It uses no
+//! OS-specific file notification APIs.
+
+use crate::mapper::loader::load_mapper_dir;
+use crate::mapper::types::MapperRegistry;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Reloads a registry from a directory whenever its definition files change
+pub struct MapperDirWatcher {
+    registry: Arc<dyn MapperRegistry>,
+    dir: PathBuf,
+    fingerprint: Option<String>,
+}
+
+impl MapperDirWatcher {
+    /// Create a watcher for the given registry and directory
+    ///
+    /// Nothing is loaded until the first [`poll`](Self::poll).
+    pub fn new(registry: Arc<dyn MapperRegistry>, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            registry,
+            dir: dir.into(),
+            fingerprint: None,
+        }
+    }
+
+    /// Check the directory once and reload the registry if it changed
+    ///
+    /// Returns `Ok(true)` when the registry was reloaded. If the new definitions
+    /// fail to load or validate, the registry keeps its previous contents and
+    /// the error is returned; the same broken state is not retried until the
+    /// files change again.
+    pub fn poll(&mut self) -> crate::Result<bool> {
+        let fingerprint = self.fingerprint_dir()?;
+        if self.fingerprint.as_ref() == Some(&fingerprint) {
+            return Ok(false);
+        }
+        self.fingerprint = Some(fingerprint);
+
+        let configs = load_mapper_dir(&self.dir)?;
+        self.registry.swap_all(configs)?;
+        Ok(true)
+    }
+
+    /// Poll on a background thread at the given interval
+    pub fn spawn(mut self, interval: Duration) -> WatcherHandle {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let last_error = Arc::new(Mutex::new(None));
+
+        let thread_reloads = Arc::clone(&reloads);
+        let thread_last_error = Arc::clone(&last_error);
+        let thread = std::thread::spawn(move || loop {
+            match self.poll() {
+                Ok(true) => {
+                    thread_reloads.fetch_add(1, Ordering::SeqCst);
+                    *thread_last_error.lock().unwrap() = None;
+                }
+                Ok(false) => {}
+                Err(e) => *thread_last_error.lock().unwrap() = Some(e),
+            }
+
+            match stopped.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => continue,
+                _ => break,
+            }
+        });
+
+        WatcherHandle {
+            stop: Some(stop),
+            thread: Some(thread),
+            reloads,
+            last_error,
+        }
+    }
+
+    /// Hash the names and contents of the definition files in the directory
+    fn fingerprint_dir(&self) -> crate::Result<String> {
+        let io_error = |e: std::io::Error| crate::error::OpenTrustError::io(&self.dir, e);
+
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(&self.dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.is_file() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut hasher = Sha256::new();
+        for path in paths {
+            hasher.update(path.to_string_lossy().as_bytes());
+            hasher.update(fs::read(&path).map_err(io_error)?);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+/// Handle to a background watcher; stops the watcher when dropped
+pub struct WatcherHandle {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+    reloads: Arc<AtomicUsize>,
+    last_error: Arc<Mutex<Option<crate::error::OpenTrustError>>>,
+}
+
+impl WatcherHandle {
+    /// Number of successful reloads so far (including the initial load)
+    pub fn reload_count(&self) -> usize {
+        self.reloads.load(Ordering::SeqCst)
+    }
+
+    /// Error from the most recent poll, if it failed
+    pub fn last_error(&self) -> Option<crate::error::OpenTrustError> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    /// Stop polling and wait for the background thread to exit
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        // Dropping the sender wakes the thread up immediately
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for WatcherHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapper::loader::save_mapper_dir;
+    use crate::mapper::registry::DefaultMapperRegistry;
+    use crate::mapper::types::{
+        BaseMapperParams, BooleanParams, JudgmentData, MapperParams, MapperType,
+    };
+
+    fn boolean_config(id: &str, true_t: f64) -> MapperParams {
+        MapperParams::Boolean(BooleanParams {
+            base: BaseMapperParams {
+                id: id.to_string(),
+                version: "1.0.0".to_string(),
+                mapper_type: MapperType::Boolean,
+                description: None,
+                metadata: None,
+                input_path: None,
+                deprecated: false,
+            },
+            true_map: JudgmentData {
+                T: true_t,
+                I: 0.0,
+                F: 0.0,
+            },
+            false_map: JudgmentData {
+                T: 0.0,
+                I: 0.0,
+                F: 1.0,
+            },
+        })
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("otp-watcher-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_poll_reloads_on_change_only() {
+        let dir = temp_dir("poll");
+        save_mapper_dir(&dir, &[boolean_config("ssl", 0.9)]).unwrap();
+
+        let registry: Arc<dyn MapperRegistry> = Arc::new(DefaultMapperRegistry::new());
+        let mut watcher = MapperDirWatcher::new(Arc::clone(&registry), &dir);
+
+        assert!(watcher.poll().unwrap());
+        assert!(!watcher.poll().unwrap());
+        let old_mapper = registry.get("ssl").unwrap();
+
+        // Recalibrate the mapper on disk
+        save_mapper_dir(&dir, &[boolean_config("ssl", 0.7)]).unwrap();
+        assert!(watcher.poll().unwrap());
+
+        assert_eq!(registry.get("ssl").unwrap().apply(&true).unwrap().t, 0.7);
+        // Readers holding the old mapper keep working
+        assert_eq!(old_mapper.apply(&true).unwrap().t, 0.9);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_poll_keeps_registry_on_invalid_definitions() {
+        let dir = temp_dir("invalid");
+        save_mapper_dir(&dir, &[boolean_config("ssl", 0.9)]).unwrap();
+
+        let registry: Arc<dyn MapperRegistry> = Arc::new(DefaultMapperRegistry::new());
+        let mut watcher = MapperDirWatcher::new(Arc::clone(&registry), &dir);
+        watcher.poll().unwrap();
+
+        save_mapper_dir(&dir, &[boolean_config("ssl", 1.5)]).unwrap();
+        assert!(watcher.poll().is_err());
+        assert_eq!(registry.get("ssl").unwrap().apply(&true).unwrap().t, 0.9);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_spawned_watcher_loads_and_stops() {
+        let dir = temp_dir("spawn");
+        save_mapper_dir(&dir, &[boolean_config("ssl", 0.9)]).unwrap();
+
+        let registry: Arc<dyn MapperRegistry> = Arc::new(DefaultMapperRegistry::new());
+        let handle =
+            MapperDirWatcher::new(Arc::clone(&registry), &dir).spawn(Duration::from_millis(10));
+
+        for _ in 0..500 {
+            if handle.reload_count() > 0 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(handle.reload_count(), 1);
+        assert!(handle.last_error().is_none());
+        assert!(registry.get("ssl").is_some());
+        handle.stop();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,687 @@
+//! Paraconsistent judgments (T + I + F up to 3)
+//!
+//! [`NeutrosophicJudgment`] enforces T + I + F <= 1, and the point
+//! optimistic and pessimistic operators rescale results that exceed it.
+//! Neutrosophic theory allows sums up to 3 for paraconsistent information,
+//! e.g. strong evidence for and against the same claim from different
+//! sources. This module makes that opt-in:
+//!
+//! - per judgment: a [`ParaconsistentJudgment`] keeps each component in
+//!   [0, 1] but only requires T + I + F <= 3;
+//! - per pipeline: a [`FusionPipeline`] fuses paraconsistent judgments and,
+//!   in [`ConservationMode::Standard`], normalizes each result back to a sum
+//!   of at most 1, or in [`ConservationMode::Paraconsistent`] keeps it as is.
+//!
+//! Normalization is always explicit: [`Normalization`] names the method, and
+//! every normalization that changes a judgment is noted in its provenance.
+//! Seals and judgment IDs are computed exactly as for point judgments with
+//! the same components.
+//!
+//! ```rust
+//! use opentrustprotocol::{ConservationMode, FusionPipeline, Normalization, ParaconsistentJudgment};
+//!
+//! let audit = ParaconsistentJudgment::from_source(0.9, 0.1, 0.0, "audit", "2023-01-01T00:00:00Z")?;
+//! let disputed =
+//!     ParaconsistentJudgment::from_source(0.8, 0.2, 0.7, "whistleblower", "2023-01-01T00:00:00Z")?;
+//!
+//! // Keep the contradiction through fusion
+//! let pipeline = FusionPipeline::new(ConservationMode::Paraconsistent);
+//! let fused = pipeline.cawa(&[&audit, &disputed], &[0.5, 0.5])?;
+//! assert!(fused.is_paraconsistent());
+//!
+//! // Explicitly normalize to a standard judgment when needed
+//! let standard = fused.normalize(Normalization::Proportional)?;
+//! assert!(standard.total() <= 1.0);
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::conformance::{create_fusion_provenance_entry, generate_conformance_seal};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{note_normalization, validate_inputs, FUSION_METADATA_VERSION};
+use crate::judgment::{validate_provenance, NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::generate_judgment_id;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Upper bound of T + I + F for paraconsistent judgments
+pub const PARACONSISTENT_BOUND: f64 = 3.0;
+
+/// Operator ID recorded by [`FusionPipeline::cawa`]
+pub const PARACONSISTENT_CAWA_OPERATOR_ID: &str = "otp-para-cawa-v1";
+/// Operator ID recorded by [`FusionPipeline::optimistic`]
+pub const PARACONSISTENT_OPTIMISTIC_OPERATOR_ID: &str = "otp-para-optimistic-v1";
+/// Operator ID recorded by [`FusionPipeline::pessimistic`]
+pub const PARACONSISTENT_PESSIMISTIC_OPERATOR_ID: &str = "otp-para-pessimistic-v1";
+
+/// Which conservation constraint a pipeline enforces on its results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConservationMode {
+    /// T + I + F <= 1; results above it are normalized
+    #[default]
+    Standard,
+    /// T + I + F <= 3; results are kept as computed
+    Paraconsistent,
+}
+
+impl ConservationMode {
+    /// The largest allowed T + I + F
+    pub fn bound(self) -> f64 {
+        match self {
+            ConservationMode::Standard => 1.0,
+            ConservationMode::Paraconsistent => PARACONSISTENT_BOUND,
+        }
+    }
+}
+
+/// How to bring T + I + F down to at most 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Normalization {
+    /// Divide every component by the sum (what the point optimistic and
+    /// pessimistic operators do)
+    #[default]
+    Proportional,
+    /// Lower I by the excess first; if T + F alone exceeds 1, scale T and F
+    /// proportionally and set I to 0. Keeps the T:F balance intact.
+    IndeterminacyFirst,
+}
+
+impl Normalization {
+    /// Name recorded in provenance
+    pub fn name(self) -> &'static str {
+        match self {
+            Normalization::Proportional => "proportional",
+            Normalization::IndeterminacyFirst => "indeterminacy_first",
+        }
+    }
+
+    /// Source ID of the entry added by [`ParaconsistentJudgment::normalize`]
+    pub fn operator_id(self) -> &'static str {
+        match self {
+            Normalization::Proportional => "otp-normalize-proportional-v1",
+            Normalization::IndeterminacyFirst => "otp-normalize-indeterminacy-first-v1",
+        }
+    }
+
+    /// Normalizes (T, I, F); sums of at most 1 are returned unchanged
+    pub fn apply(self, t: f64, i: f64, f: f64) -> (f64, f64, f64) {
+        let sum = t + i + f;
+        if sum <= 1.0 {
+            return (t, i, f);
+        }
+        let (t, i, f) = match self {
+            Normalization::Proportional => (t / sum, i / sum, f / sum),
+            Normalization::IndeterminacyFirst if t + f <= 1.0 => (t, (i - (sum - 1.0)).max(0.0), f),
+            Normalization::IndeterminacyFirst => (t / (t + f), 0.0, f / (t + f)),
+        };
+        // Keep rounding from pushing the sum just above 1
+        (t, i.min(1.0 - t - f).max(0.0), f)
+    }
+}
+
+/// A judgment that may carry paraconsistent information
+///
+/// Each component is in [0, 1] and T + I + F <= 3. Serializes like
+/// [`NeutrosophicJudgment`]; deserializing checks these invariants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ParaconsistentJudgmentData")]
+pub struct ParaconsistentJudgment {
+    /// Unique identifier for the Circle of Trust
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub judgment_id: Option<String>,
+    /// Truth degree [0.0, 1.0]
+    pub t: f64,
+    /// Indeterminacy degree [0.0, 1.0]
+    pub i: f64,
+    /// Falsity degree [0.0, 1.0]
+    pub f: f64,
+    /// Immutable audit trail
+    pub provenance_chain: Vec<ProvenanceEntry>,
+}
+
+/// Unchecked wire form of [`ParaconsistentJudgment`]
+#[derive(Deserialize)]
+struct ParaconsistentJudgmentData {
+    #[serde(default)]
+    judgment_id: Option<String>,
+    t: f64,
+    i: f64,
+    f: f64,
+    provenance_chain: Vec<ProvenanceEntry>,
+}
+
+impl TryFrom<ParaconsistentJudgmentData> for ParaconsistentJudgment {
+    type Error = OpenTrustError;
+
+    fn try_from(data: ParaconsistentJudgmentData) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: data.judgment_id,
+            t: data.t,
+            i: data.i,
+            f: data.f,
+            provenance_chain: data.provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+}
+
+impl ParaconsistentJudgment {
+    /// Creates a new paraconsistent judgment
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidValue` for a component outside
+    /// [0, 1], or a provenance error. Sums above 3 cannot occur with
+    /// components in range.
+    pub fn new(t: f64, i: f64, f: f64, provenance_chain: Vec<ProvenanceEntry>) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: None,
+            t,
+            i,
+            f,
+            provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+
+    /// Creates a paraconsistent judgment with a single provenance entry
+    pub fn from_source(
+        t: f64,
+        i: f64,
+        f: f64,
+        source_id: impl Into<String>,
+        timestamp: impl Into<String>,
+    ) -> Result<Self> {
+        Self::new(
+            t,
+            i,
+            f,
+            vec![ProvenanceEntry::new(source_id.into(), timestamp.into())],
+        )
+    }
+
+    /// Re-runs the constructor checks
+    pub fn validate(&self) -> Result<()> {
+        for (field, value) in [("T", self.t), ("I", self.i), ("F", self.f)] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(OpenTrustError::InvalidValue {
+                    field: field.to_string(),
+                    value,
+                    message: format!("{} value must be between 0 and 1", field),
+                });
+            }
+        }
+        validate_provenance(&self.provenance_chain)
+    }
+
+    /// Returns true if the judgment passes all constructor checks
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Returns the sum T + I + F
+    pub fn total(&self) -> f64 {
+        self.t + self.i + self.f
+    }
+
+    /// Returns true if T + I + F > 1, i.e. the judgment is not a valid
+    /// [`NeutrosophicJudgment`]
+    pub fn is_paraconsistent(&self) -> bool {
+        self.total() > 1.0
+    }
+
+    /// Returns a JSON representation of the judgment
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| OpenTrustError::serialization("paraconsistent judgment", e))
+    }
+
+    /// Creates a judgment from JSON, checking its invariants
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| OpenTrustError::deserialization("paraconsistent judgment", e))
+    }
+
+    /// Converts to a standard judgment, normalizing if T + I + F > 1
+    ///
+    /// When normalization changes the components, an entry with the method's
+    /// [`operator_id`](Normalization::operator_id) as source ID is appended,
+    /// recording the method, the components before normalization and the
+    /// paraconsistent judgment ID, and a new judgment ID is assigned. A
+    /// judgment already within the standard bound converts unchanged,
+    /// keeping its ID.
+    pub fn normalize(&self, method: Normalization) -> Result<NeutrosophicJudgment> {
+        if !self.is_paraconsistent() {
+            return NeutrosophicJudgment::new_with_entries(
+                self.t,
+                self.i,
+                self.f,
+                self.provenance_chain.clone(),
+            )
+            .map(|mut judgment| {
+                judgment.judgment_id = self.judgment_id.clone();
+                judgment
+            });
+        }
+
+        let (t, i, f) = method.apply(self.t, self.i, self.f);
+        let mut entry = ProvenanceEntry::with_description(
+            method.operator_id().to_string(),
+            chrono::Utc::now().to_rfc3339(),
+            format!(
+                "Normalization of paraconsistent judgment using {}",
+                method.name()
+            ),
+        );
+        entry.metadata = Some(serde_json::json!({
+            "paraconsistent_judgment_id": self.judgment_id,
+            "before": { "t": self.t, "i": self.i, "f": self.f },
+        }));
+        note_normalization(&mut entry, method.name(), self.total());
+
+        let mut provenance = self.provenance_chain.clone();
+        provenance.push(entry);
+        crate::judgment_id::ensure_judgment_id(NeutrosophicJudgment::new_with_entries(
+            t, i, f, provenance,
+        )?)
+    }
+
+    /// The same data as an unchecked [`NeutrosophicJudgment`], for the
+    /// shared seal and ID functions
+    fn as_unchecked(&self) -> NeutrosophicJudgment {
+        NeutrosophicJudgment {
+            judgment_id: self.judgment_id.clone(),
+            t: self.t,
+            i: self.i,
+            f: self.f,
+            provenance_chain: self.provenance_chain.clone(),
+        }
+    }
+}
+
+impl From<NeutrosophicJudgment> for ParaconsistentJudgment {
+    fn from(judgment: NeutrosophicJudgment) -> Self {
+        Self {
+            judgment_id: judgment.judgment_id,
+            t: judgment.t,
+            i: judgment.i,
+            f: judgment.f,
+            provenance_chain: judgment.provenance_chain,
+        }
+    }
+}
+
+impl fmt::Display for ParaconsistentJudgment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ParaconsistentJudgment(T={:.3}, I={:.3}, F={:.3})",
+            self.t, self.i, self.f
+        )
+    }
+}
+
+/// Generates the judgment ID of a paraconsistent judgment
+///
+/// Identical to [`generate_judgment_id`] of a point judgment with the same
+/// components and provenance.
+pub fn generate_paraconsistent_judgment_id(judgment: &ParaconsistentJudgment) -> Result<String> {
+    generate_judgment_id(&judgment.as_unchecked())
+}
+
+/// Generates a conformance seal over paraconsistent judgments
+///
+/// Identical to [`generate_conformance_seal`] over point judgments with the
+/// same components and provenance.
+pub fn generate_paraconsistent_conformance_seal(
+    judgments: &[&ParaconsistentJudgment],
+    weights: &[f64],
+    operator_id: &str,
+) -> Result<String> {
+    let unchecked: Vec<NeutrosophicJudgment> = judgments.iter().map(|j| j.as_unchecked()).collect();
+    generate_conformance_seal(&unchecked.iter().collect::<Vec<_>>(), weights, operator_id)
+}
+
+/// Fuses paraconsistent judgments under one conservation mode
+///
+/// The operators never rescale on their own: in
+/// [`ConservationMode::Paraconsistent`] results are kept as computed (any
+/// sum up to 3), in [`ConservationMode::Standard`] a result above 1 is
+/// brought down with the pipeline's [`Normalization`] and the fusion entry's
+/// metadata records it under `normalization`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FusionPipeline {
+    /// Conservation constraint enforced on results
+    pub mode: ConservationMode,
+    /// Normalization applied in [`ConservationMode::Standard`]
+    #[serde(default)]
+    pub normalization: Normalization,
+}
+
+impl FusionPipeline {
+    /// A pipeline with proportional normalization
+    pub fn new(mode: ConservationMode) -> Self {
+        Self {
+            mode,
+            normalization: Normalization::default(),
+        }
+    }
+
+    /// Use `normalization` in standard mode
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Conflict-aware weighted average
+    ///
+    /// Same weighting as
+    /// [`conflict_aware_weighted_average`](crate::conflict_aware_weighted_average).
+    pub fn cawa(
+        &self,
+        judgments: &[&ParaconsistentJudgment],
+        weights: &[f64],
+    ) -> Result<ParaconsistentJudgment> {
+        validate_inputs(judgments, Some(weights))?;
+
+        let mut adjusted: Vec<f64> = judgments
+            .iter()
+            .zip(weights)
+            .map(|(judgment, &weight)| weight * (1.0 - judgment.t * judgment.f))
+            .collect();
+        if adjusted.iter().sum::<f64>() == 0.0 {
+            adjusted = vec![1.0; judgments.len()];
+        }
+        let total: f64 = adjusted.iter().sum();
+        let average = |component: fn(&ParaconsistentJudgment) -> f64| {
+            judgments
+                .iter()
+                .zip(&adjusted)
+                .map(|(judgment, weight)| component(judgment) * weight)
+                .sum::<f64>()
+                / total
+        };
+
+        let fused = (average(|j| j.t), average(|j| j.i), average(|j| j.f));
+        self.seal(
+            PARACONSISTENT_CAWA_OPERATOR_ID,
+            judgments,
+            Some(weights),
+            fused,
+        )
+    }
+
+    /// Max T, min F, average I, without rescaling
+    pub fn optimistic(
+        &self,
+        judgments: &[&ParaconsistentJudgment],
+    ) -> Result<ParaconsistentJudgment> {
+        validate_inputs(judgments, None)?;
+        let fused = (
+            judgments.iter().map(|j| j.t).fold(0.0, f64::max),
+            average_indeterminacy(judgments),
+            judgments.iter().map(|j| j.f).fold(1.0, f64::min),
+        );
+        self.seal(
+            PARACONSISTENT_OPTIMISTIC_OPERATOR_ID,
+            judgments,
+            None,
+            fused,
+        )
+    }
+
+    /// Min T, max F, average I, without rescaling
+    pub fn pessimistic(
+        &self,
+        judgments: &[&ParaconsistentJudgment],
+    ) -> Result<ParaconsistentJudgment> {
+        validate_inputs(judgments, None)?;
+        let fused = (
+            judgments.iter().map(|j| j.t).fold(1.0, f64::min),
+            average_indeterminacy(judgments),
+            judgments.iter().map(|j| j.f).fold(0.0, f64::max),
+        );
+        self.seal(
+            PARACONSISTENT_PESSIMISTIC_OPERATOR_ID,
+            judgments,
+            None,
+            fused,
+        )
+    }
+
+    /// Fuses with the operator named by `operator`
+    ///
+    /// Accepts the operator IDs or the short names `"cawa"`, `"optimistic"`
+    /// and `"pessimistic"`, like [`fuse`](crate::fuse).
+    pub fn fuse(
+        &self,
+        operator: &str,
+        judgments: &[&ParaconsistentJudgment],
+        weights: Option<&[f64]>,
+    ) -> Result<ParaconsistentJudgment> {
+        match (operator, weights) {
+            ("cawa" | PARACONSISTENT_CAWA_OPERATOR_ID, Some(weights)) => {
+                self.cawa(judgments, weights)
+            }
+            ("cawa" | PARACONSISTENT_CAWA_OPERATOR_ID, None) => {
+                self.cawa(judgments, &vec![1.0; judgments.len()])
+            }
+            (
+                "optimistic"
+                | PARACONSISTENT_OPTIMISTIC_OPERATOR_ID
+                | "pessimistic"
+                | PARACONSISTENT_PESSIMISTIC_OPERATOR_ID,
+                Some(_),
+            ) => Err(OpenTrustError::InvalidFusionInput {
+                message: format!("Operator '{}' does not take weights", operator),
+            }),
+            ("optimistic" | PARACONSISTENT_OPTIMISTIC_OPERATOR_ID, None) => {
+                self.optimistic(judgments)
+            }
+            ("pessimistic" | PARACONSISTENT_PESSIMISTIC_OPERATOR_ID, None) => {
+                self.pessimistic(judgments)
+            }
+            _ => Err(OpenTrustError::UnknownOperator {
+                operator_id: operator.to_string(),
+            }),
+        }
+    }
+
+    fn seal(
+        &self,
+        operator: &str,
+        judgments: &[&ParaconsistentJudgment],
+        weights: Option<&[f64]>,
+        (t, i, f): (f64, f64, f64),
+    ) -> Result<ParaconsistentJudgment> {
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("operator".to_string(), operator.into());
+        metadata.insert("input_count".to_string(), judgments.len().into());
+        metadata.insert(
+            "weights".to_string(),
+            weights.map_or(serde_json::Value::Null, |weights| weights.into()),
+        );
+        metadata.insert(
+            "mode".to_string(),
+            serde_json::to_value(self.mode)
+                .map_err(|e| OpenTrustError::serialization("conservation mode", e))?,
+        );
+        metadata.insert("version".to_string(), FUSION_METADATA_VERSION.into());
+
+        let seal_weights = weights.map_or_else(|| vec![1.0; judgments.len()], <[f64]>::to_vec);
+        let seal = generate_paraconsistent_conformance_seal(judgments, &seal_weights, operator)?;
+        let mut entry = create_fusion_provenance_entry(
+            operator,
+            &chrono::Utc::now().to_rfc3339(),
+            &seal,
+            Some(format!(
+                "Fusion operation using {} with Conformance Seal",
+                operator
+            )),
+            Some(serde_json::Value::Object(metadata)),
+        );
+
+        let sum = t + i + f;
+        let (t, i, f) = if sum > self.mode.bound() {
+            note_normalization(&mut entry, self.normalization.name(), sum);
+            self.normalization.apply(t, i, f)
+        } else {
+            (t, i, f)
+        };
+
+        let mut provenance = Vec::new();
+        for judgment in judgments {
+            provenance.extend(judgment.provenance_chain.clone());
+        }
+        provenance.push(entry);
+
+        let mut fused = ParaconsistentJudgment::new(t, i, f, provenance)?;
+        fused.judgment_id = Some(generate_paraconsistent_judgment_id(&fused)?);
+        Ok(fused)
+    }
+}
+
+fn average_indeterminacy(judgments: &[&ParaconsistentJudgment]) -> f64 {
+    judgments.iter().map(|j| j.i).sum::<f64>() / judgments.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+
+    fn judgment(t: f64, i: f64, f: f64, source: &str) -> ParaconsistentJudgment {
+        ParaconsistentJudgment::from_source(t, i, f, source, "2023-01-01T00:00:00Z").unwrap()
+    }
+
+    #[test]
+    fn test_sums_up_to_three_are_accepted() {
+        let contradictory = judgment(0.9, 0.5, 0.8, "merged");
+        assert!(contradictory.is_paraconsistent());
+        assert!((contradictory.total() - 2.2).abs() < 1e-12);
+
+        let json = contradictory.to_json().unwrap();
+        assert_eq!(
+            ParaconsistentJudgment::from_json(&json).unwrap(),
+            contradictory
+        );
+        // The point type still rejects it
+        assert!(NeutrosophicJudgment::from_json(&json)
+            .unwrap()
+            .validate()
+            .is_err());
+        assert!(
+            ParaconsistentJudgment::from_source(1.2, 0.0, 0.0, "s", "2023-01-01T00:00:00Z")
+                .is_err()
+        );
+
+        let point = NeutrosophicJudgment::new(
+            0.8,
+            0.2,
+            0.0,
+            vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        let lifted = ParaconsistentJudgment::from(point.clone());
+        assert_eq!(
+            generate_paraconsistent_judgment_id(&lifted).unwrap(),
+            generate_judgment_id(&point).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_normalization_methods_and_provenance() {
+        let contradictory = judgment(0.9, 0.4, 0.7, "merged");
+
+        let proportional = contradictory
+            .normalize(Normalization::Proportional)
+            .unwrap();
+        assert!(proportional.total() <= 1.0);
+        assert!((proportional.t / proportional.f - 0.9 / 0.7).abs() < 1e-9);
+        let entry = proportional.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, "otp-normalize-proportional-v1");
+        let metadata = entry.metadata.as_ref().unwrap();
+        assert_eq!(metadata["normalization"]["method"], "proportional");
+        assert_eq!(metadata["before"]["t"], 0.9);
+        assert!(proportional.judgment_id.is_some());
+
+        let indeterminacy_first = judgment(0.5, 0.4, 0.3, "s")
+            .normalize(Normalization::IndeterminacyFirst)
+            .unwrap();
+        assert_eq!((indeterminacy_first.t, indeterminacy_first.f), (0.5, 0.3));
+        assert!((indeterminacy_first.i - 0.2).abs() < 1e-9);
+        let saturated = contradictory
+            .normalize(Normalization::IndeterminacyFirst)
+            .unwrap();
+        assert_eq!(saturated.i, 0.0);
+
+        // Nothing to normalize: no extra entry
+        let standard = judgment(0.5, 0.2, 0.1, "s");
+        let unchanged = standard.normalize(Normalization::Proportional).unwrap();
+        assert_eq!(unchanged.provenance_chain, standard.provenance_chain);
+    }
+
+    #[test]
+    fn test_pipeline_modes() {
+        let audit = judgment(0.9, 0.2, 0.0, "audit");
+        let whistleblower = judgment(0.1, 0.2, 0.9, "whistleblower");
+        let inputs = [&audit, &whistleblower];
+
+        let paraconsistent = FusionPipeline::new(ConservationMode::Paraconsistent);
+        let kept = paraconsistent.pessimistic(&inputs).unwrap();
+        assert_eq!((kept.t, kept.f), (0.1, 0.9));
+        let kept = paraconsistent.fuse("optimistic", &inputs, None).unwrap();
+        assert_eq!((kept.t, kept.f), (0.9, 0.0));
+
+        let contradiction = ParaconsistentJudgment::new(
+            0.9,
+            0.2,
+            0.9,
+            vec![ProvenanceEntry::new(
+                "merged".into(),
+                "2023-01-01T00:00:00Z".into(),
+            )],
+        )
+        .unwrap();
+        let para_cawa = paraconsistent
+            .cawa(&[&contradiction, &audit], &[1.0, 1.0])
+            .unwrap();
+        assert!(para_cawa.is_paraconsistent());
+        assert!(para_cawa
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .as_ref()
+            .unwrap()
+            .get("normalization")
+            .is_none());
+
+        let standard = FusionPipeline::new(ConservationMode::Standard)
+            .with_normalization(Normalization::IndeterminacyFirst);
+        let normalized = standard
+            .cawa(&[&contradiction, &audit], &[1.0, 1.0])
+            .unwrap();
+        assert!(normalized.total() <= 1.0);
+        let fusion_entry = normalized.provenance_chain.last().unwrap();
+        let note = &fusion_entry.metadata.as_ref().unwrap()["normalization"];
+        assert_eq!(note["method"], "indeterminacy_first");
+        assert!(note["sum"].as_f64().unwrap() > 1.0);
+
+        // Seals match the point seal algorithm
+        let unchecked: Vec<NeutrosophicJudgment> = [&contradiction, &audit]
+            .iter()
+            .map(|j| j.as_unchecked())
+            .collect();
+        let normalized_point = normalized.normalize(Normalization::Proportional).unwrap();
+        assert!(verify_conformance_seal_with_inputs(
+            &normalized_point,
+            &unchecked.iter().collect::<Vec<_>>(),
+            &[1.0, 1.0]
+        )
+        .unwrap());
+    }
+}
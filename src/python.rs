@@ -0,0 +1,537 @@
+//! Python extension module (feature `python`)
+//!
+//! Exposes the Rust core to Python through PyO3, so judgments produced from
+//! Python carry byte-identical IDs and conformance seals. Build the wheel with
+//! `maturin build --release` (see `pyproject.toml`).
+//!
+//! ```python
+//! from opentrustprotocol import (
+//!     NeutrosophicJudgment, conflict_aware_weighted_average, verify_conformance_seal_with_inputs,
+//! )
+//!
+//! a = NeutrosophicJudgment(0.8, 0.2, 0.0, [("sensor1", "2023-01-01T00:00:00Z")])
+//! b = NeutrosophicJudgment(0.6, 0.3, 0.1, [{"source_id": "sensor2", "timestamp": "2023-01-01T00:00:00Z"}])
+//! fused = conflict_aware_weighted_average([a, b], [0.6, 0.4])
+//! assert verify_conformance_seal_with_inputs(fused, [a, b])
+//! ```
+//!
+//! Dictionaries, lists and other JSON-like values are converted through
+//! Python's `json` module, which keeps `1.0` and `1` distinct exactly as the
+//! canonical form used for IDs and seals expects.
+
+use crate::conformance::{
+    generate_conformance_seal as seal, recorded_seal_weights, verify_conformance_seal as verify,
+    verify_conformance_seal_with_inputs as verify_with_inputs,
+};
+use crate::error::OpenTrustError;
+use crate::fusion;
+use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id;
+use crate::mapper::registry::{build_mapper, DefaultMapperRegistry};
+use crate::mapper::types::{Mapper, MapperParams, MapperRegistry};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyString, PyTuple};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+create_exception!(
+    opentrustprotocol,
+    OpenTrustProtocolError,
+    PyValueError,
+    "Raised when an OpenTrust Protocol operation fails."
+);
+
+fn to_py_err(error: OpenTrustError) -> PyErr {
+    OpenTrustProtocolError::new_err(error.to_string())
+}
+
+/// Convert a JSON-like Python object to a JSON value
+fn to_json_value(object: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let text: String = object
+        .py()
+        .import("json")?
+        .call_method1("dumps", (object,))?
+        .extract()?;
+    serde_json::from_str(&text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Convert a JSON value to the equivalent Python object
+fn to_py_object<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    py.import("json")?
+        .call_method1("loads", (value.to_string(),))
+}
+
+/// Deserialize a Python object, or a JSON string, into `T`
+fn extract_json<T: DeserializeOwned>(object: &Bound<'_, PyAny>, what: &'static str) -> PyResult<T> {
+    let result = match object.cast::<PyString>() {
+        Ok(text) => serde_json::from_str(&text.to_cow()?),
+        Err(_) => serde_json::from_value(to_json_value(object)?),
+    };
+    result.map_err(|e| to_py_err(OpenTrustError::deserialization(what, e)))
+}
+
+/// A neutrosophic judgment (T, I, F) with its provenance chain
+#[pyclass(
+    name = "NeutrosophicJudgment",
+    module = "opentrustprotocol",
+    frozen,
+    eq
+)]
+#[derive(PartialEq)]
+pub struct PyNeutrosophicJudgment {
+    inner: NeutrosophicJudgment,
+}
+
+impl From<NeutrosophicJudgment> for PyNeutrosophicJudgment {
+    fn from(inner: NeutrosophicJudgment) -> Self {
+        Self { inner }
+    }
+}
+
+/// Read one provenance entry: a dict or a `(source_id, timestamp)` tuple
+fn extract_provenance_entry(entry: &Bound<'_, PyAny>) -> PyResult<ProvenanceEntry> {
+    if let Ok(tuple) = entry.cast::<PyTuple>() {
+        let (source_id, timestamp): (String, String) = tuple.extract()?;
+        return Ok(ProvenanceEntry::new(source_id, timestamp));
+    }
+    extract_json(entry, "provenance entry")
+}
+
+#[pymethods]
+impl PyNeutrosophicJudgment {
+    /// Create and validate a judgment
+    ///
+    /// Provenance entries are dicts (`source_id`, `timestamp`, optional
+    /// `description`, `metadata`) or `(source_id, timestamp)` tuples.
+    #[new]
+    fn new(t: f64, i: f64, f: f64, provenance_chain: &Bound<'_, PyList>) -> PyResult<Self> {
+        let entries = provenance_chain
+            .iter()
+            .map(|entry| extract_provenance_entry(&entry))
+            .collect::<PyResult<Vec<_>>>()?;
+        NeutrosophicJudgment::new_with_entries(t, i, f, entries)
+            .map(Self::from)
+            .map_err(to_py_err)
+    }
+
+    /// Parse and validate a judgment from JSON text
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        let parsed = NeutrosophicJudgment::from_json(json).map_err(to_py_err)?;
+        Self::validated(parsed)
+    }
+
+    /// Build and validate a judgment from a dict
+    #[staticmethod]
+    fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::validated(extract_json(data, "judgment")?)
+    }
+
+    #[getter]
+    fn t(&self) -> f64 {
+        self.inner.t
+    }
+
+    #[getter]
+    fn i(&self) -> f64 {
+        self.inner.i
+    }
+
+    #[getter]
+    fn f(&self) -> f64 {
+        self.inner.f
+    }
+
+    #[getter]
+    fn judgment_id(&self) -> Option<String> {
+        self.inner.judgment_id.clone()
+    }
+
+    /// The provenance chain as a list of dicts
+    #[getter]
+    fn provenance_chain<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let chain = serde_json::to_value(&self.inner.provenance_chain)
+            .map_err(|e| to_py_err(OpenTrustError::serialization("provenance chain", e)))?;
+        to_py_object(py, &chain)
+    }
+
+    /// Serialize as pretty-printed JSON
+    fn to_json(&self) -> PyResult<String> {
+        self.inner.to_json().map_err(to_py_err)
+    }
+
+    /// Convert to a dict
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let value = serde_json::to_value(&self.inner)
+            .map_err(|e| to_py_err(OpenTrustError::serialization("judgment", e)))?;
+        to_py_object(py, &value)
+    }
+
+    /// Compute the judgment ID (the stored one is not consulted)
+    fn generate_id(&self) -> PyResult<String> {
+        judgment_id::generate_judgment_id(&self.inner).map_err(to_py_err)
+    }
+
+    /// Return a copy with `judgment_id` set
+    fn with_id(&self) -> PyResult<Self> {
+        judgment_id::ensure_judgment_id(self.inner.clone())
+            .map(Self::from)
+            .map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "NeutrosophicJudgment(t={}, i={}, f={}, sources={})",
+            self.inner.t,
+            self.inner.i,
+            self.inner.f,
+            self.inner.provenance_chain.len()
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+impl PyNeutrosophicJudgment {
+    /// Run the constructor checks on a parsed judgment, keeping its ID and seals
+    fn validated(parsed: NeutrosophicJudgment) -> PyResult<Self> {
+        NeutrosophicJudgment::new_with_entries(
+            parsed.t,
+            parsed.i,
+            parsed.f,
+            parsed.provenance_chain.clone(),
+        )
+        .map_err(to_py_err)?;
+        Ok(parsed.into())
+    }
+}
+
+fn inner_judgments<'a>(
+    judgments: &'a [PyRef<'_, PyNeutrosophicJudgment>],
+) -> Vec<&'a NeutrosophicJudgment> {
+    judgments.iter().map(|judgment| &judgment.inner).collect()
+}
+
+/// Conflict-aware weighted average
+#[pyfunction]
+fn conflict_aware_weighted_average(
+    judgments: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+    weights: Vec<f64>,
+) -> PyResult<PyNeutrosophicJudgment> {
+    fusion::conflict_aware_weighted_average(&inner_judgments(&judgments), &weights)
+        .map(Into::into)
+        .map_err(to_py_err)
+}
+
+/// Optimistic fusion (max T, min F)
+#[pyfunction]
+fn optimistic_fusion(
+    judgments: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+) -> PyResult<PyNeutrosophicJudgment> {
+    fusion::optimistic_fusion(&inner_judgments(&judgments))
+        .map(Into::into)
+        .map_err(to_py_err)
+}
+
+/// Pessimistic fusion (min T, max F)
+#[pyfunction]
+fn pessimistic_fusion(
+    judgments: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+) -> PyResult<PyNeutrosophicJudgment> {
+    fusion::pessimistic_fusion(&inner_judgments(&judgments))
+        .map(Into::into)
+        .map_err(to_py_err)
+}
+
+/// Fuse with an operator given by short name or full operator ID
+#[pyfunction]
+#[pyo3(signature = (operator, judgments, weights = None))]
+fn fuse(
+    operator: &str,
+    judgments: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+    weights: Option<Vec<f64>>,
+) -> PyResult<PyNeutrosophicJudgment> {
+    fusion::fuse(operator, &inner_judgments(&judgments), weights.as_deref())
+        .map(Into::into)
+        .map_err(to_py_err)
+}
+
+/// Compute the judgment ID of a judgment
+#[pyfunction]
+fn generate_judgment_id(judgment: PyRef<'_, PyNeutrosophicJudgment>) -> PyResult<String> {
+    judgment.generate_id()
+}
+
+/// Generate the conformance seal for fusing `judgments` with `operator_id`
+#[pyfunction]
+fn generate_conformance_seal(
+    judgments: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+    weights: Vec<f64>,
+    operator_id: &str,
+) -> PyResult<String> {
+    seal(&inner_judgments(&judgments), &weights, operator_id).map_err(to_py_err)
+}
+
+/// Check that a fused judgment carries a well-formed conformance seal
+#[pyfunction]
+fn verify_conformance_seal(judgment: PyRef<'_, PyNeutrosophicJudgment>) -> PyResult<bool> {
+    verify(&judgment.inner).map_err(to_py_err)
+}
+
+/// Verify a fused judgment's seal against its inputs
+///
+/// Without `weights`, the weights recorded in the fusion metadata are used.
+#[pyfunction]
+#[pyo3(signature = (fused, inputs, weights = None))]
+fn verify_conformance_seal_with_inputs(
+    fused: PyRef<'_, PyNeutrosophicJudgment>,
+    inputs: Vec<PyRef<'_, PyNeutrosophicJudgment>>,
+    weights: Option<Vec<f64>>,
+) -> PyResult<bool> {
+    let weights = weights
+        .or_else(|| recorded_seal_weights(&fused.inner))
+        .ok_or_else(|| {
+            OpenTrustProtocolError::new_err(
+                "no weights given and none recorded in the fusion metadata",
+            )
+        })?;
+    verify_with_inputs(&fused.inner, &inner_judgments(&inputs), &weights).map_err(to_py_err)
+}
+
+/// Read one configuration, or a list of them, from a dict/list or JSON text
+fn extract_configs(configs: &Bound<'_, PyAny>) -> PyResult<Vec<MapperParams>> {
+    match extract_json::<Value>(configs, "mapper configuration")? {
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| {
+                serde_json::from_value(item).map_err(|e| {
+                    to_py_err(OpenTrustError::deserialization("mapper configuration", e))
+                })
+            })
+            .collect(),
+        value => Ok(vec![serde_json::from_value(value).map_err(|e| {
+            to_py_err(OpenTrustError::deserialization("mapper configuration", e))
+        })?]),
+    }
+}
+
+/// A mapper built from its configuration
+#[pyclass(name = "Mapper", module = "opentrustprotocol", frozen)]
+pub struct PyMapper {
+    id: String,
+    inner: Box<dyn Mapper>,
+}
+
+#[pymethods]
+impl PyMapper {
+    /// Build a mapper from a configuration dict or JSON text
+    #[new]
+    fn new(config: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let config: MapperParams = extract_json(config, "mapper configuration")?;
+        let id = config.id().to_string();
+        build_mapper(config)
+            .map(|inner| Self { id, inner })
+            .map_err(to_py_err)
+    }
+
+    #[getter]
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    /// Map a raw input value (number, bool, string or JSON document)
+    fn apply(&self, input: &Bound<'_, PyAny>) -> PyResult<PyNeutrosophicJudgment> {
+        let input = to_json_value(input)?;
+        self.inner.apply(&input).map(Into::into).map_err(to_py_err)
+    }
+}
+
+/// A versioned mapper registry
+#[pyclass(name = "MapperRegistry", module = "opentrustprotocol", frozen)]
+pub struct PyMapperRegistry {
+    inner: DefaultMapperRegistry,
+}
+
+#[pymethods]
+impl PyMapperRegistry {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: DefaultMapperRegistry::new(),
+        }
+    }
+
+    /// Register one configuration or a list of them (dicts or JSON text)
+    fn register(&self, configs: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.inner
+            .import(extract_configs(configs)?)
+            .map_err(to_py_err)
+    }
+
+    /// Register every mapper configuration file in a directory; returns the count
+    fn load_dir(&self, path: std::path::PathBuf) -> PyResult<usize> {
+        self.inner.import_dir(&path).map_err(to_py_err)
+    }
+
+    /// Apply the mapper `id` (or `id@version`) to a raw input value
+    fn apply(&self, id: &str, input: &Bound<'_, PyAny>) -> PyResult<PyNeutrosophicJudgment> {
+        let input = to_json_value(input)?;
+        self.inner
+            .apply(id, &input)
+            .map(Into::into)
+            .map_err(to_py_err)
+    }
+
+    /// IDs of the registered mappers
+    fn list(&self) -> Vec<String> {
+        self.inner.list()
+    }
+
+    /// Registered versions of a mapper ID, oldest first
+    fn versions(&self, id: &str) -> Vec<String> {
+        self.inner.versions(id)
+    }
+
+    /// All registered configurations as a list of dicts
+    fn export<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let configs = serde_json::to_value(self.inner.export())
+            .map_err(|e| to_py_err(OpenTrustError::serialization("mapper configurations", e)))?;
+        to_py_object(py, &configs)
+    }
+}
+
+/// The `opentrustprotocol` Python module
+#[pymodule]
+fn opentrustprotocol(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyNeutrosophicJudgment>()?;
+    m.add_class::<PyMapper>()?;
+    m.add_class::<PyMapperRegistry>()?;
+    m.add_function(wrap_pyfunction!(conflict_aware_weighted_average, m)?)?;
+    m.add_function(wrap_pyfunction!(optimistic_fusion, m)?)?;
+    m.add_function(wrap_pyfunction!(pessimistic_fusion, m)?)?;
+    m.add_function(wrap_pyfunction!(fuse, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_judgment_id, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_conformance_seal, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_conformance_seal, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_conformance_seal_with_inputs, m)?)?;
+    m.add(
+        "OpenTrustProtocolError",
+        m.py().get_type::<OpenTrustProtocolError>(),
+    )?;
+    m.add("CAWA_OPERATOR_ID", fusion::CAWA_OPERATOR_ID)?;
+    m.add("OPTIMISTIC_OPERATOR_ID", fusion::OPTIMISTIC_OPERATOR_ID)?;
+    m.add("PESSIMISTIC_OPERATOR_ID", fusion::PESSIMISTIC_OPERATOR_ID)?;
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn test_module_matches_rust_ids_and_seals() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "opentrustprotocol").unwrap();
+            opentrustprotocol(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("otp", &module).unwrap();
+            py.run(
+                cr#"
+a = otp.NeutrosophicJudgment(0.8, 0.2, 0.0, [("sensor1", "2023-01-01T00:00:00Z")])
+b = otp.NeutrosophicJudgment.from_dict(
+    {"t": 0.6, "i": 0.3, "f": 0.1,
+     "provenance_chain": [{"source_id": "sensor2", "timestamp": "2023-01-01T00:00:00Z"}]})
+fused = otp.conflict_aware_weighted_average([a, b], [0.6, 0.4])
+assert otp.verify_conformance_seal_with_inputs(fused, [a, b])
+assert not otp.verify_conformance_seal_with_inputs(fused, [a, b], [0.5, 0.5])
+fused_json = fused.to_json()
+fused_id = fused.generate_id()
+try:
+    otp.NeutrosophicJudgment(0.8, 0.5, 0.0, [("s", "t")])
+    raise AssertionError("conservation violation accepted")
+except otp.OpenTrustProtocolError:
+    pass
+"#,
+                Some(&globals),
+                None,
+            )
+            .unwrap();
+
+            // The Python-produced judgment has the same ID and seal as in Rust
+            let fused_json: String = globals
+                .get_item("fused_json")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            let fused_id: String = globals
+                .get_item("fused_id")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            let fused = NeutrosophicJudgment::from_json(&fused_json).unwrap();
+            assert_eq!(judgment_id::generate_judgment_id(&fused).unwrap(), fused_id);
+
+            let a = NeutrosophicJudgment::new(
+                0.8,
+                0.2,
+                0.0,
+                vec![("sensor1".into(), "2023-01-01T00:00:00Z".into())],
+            )
+            .unwrap();
+            let b = NeutrosophicJudgment::new(
+                0.6,
+                0.3,
+                0.1,
+                vec![("sensor2".into(), "2023-01-01T00:00:00Z".into())],
+            )
+            .unwrap();
+            let expected = fusion::conflict_aware_weighted_average(&[&a, &b], &[0.6, 0.4]).unwrap();
+            assert_eq!(
+                fused.provenance_chain.last().unwrap().conformance_seal,
+                expected.provenance_chain.last().unwrap().conformance_seal
+            );
+        });
+    }
+
+    #[test]
+    fn test_registry_and_mapper_classes() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "opentrustprotocol").unwrap();
+            opentrustprotocol(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("otp", &module).unwrap();
+            py.run(
+                cr#"
+config = {"mapper_type": "boolean", "params": {
+    "id": "ssl", "version": "1.0.0", "mapper_type": "Boolean",
+    "true_map": {"T": 0.9, "I": 0.1, "F": 0.0},
+    "false_map": {"T": 0.0, "I": 0.0, "F": 1.0}}}
+registry = otp.MapperRegistry()
+registry.register(config)
+assert registry.list() == ["ssl"]
+assert registry.apply("ssl", True).t == 0.9
+assert otp.Mapper(config).apply("no").f == 1.0
+assert registry.export()[0]["params"]["id"] == "ssl"
+try:
+    registry.apply("missing", True)
+    raise AssertionError("unknown mapper accepted")
+except otp.OpenTrustProtocolError:
+    pass
+"#,
+                Some(&globals),
+                None,
+            )
+            .unwrap();
+        });
+    }
+}
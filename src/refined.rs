@@ -0,0 +1,637 @@
+//! Refined neutrosophic judgments
+//!
+//! A refined judgment splits T, I and F into labelled sub-components
+//! T1..Tp, I1..Ir, F1..Fs, e.g. indeterminacy from `missing_data` versus
+//! `contradictory_data`. Every sub-component is in [0, 1] and all of them
+//! together sum to at most 1, so summing each family gives a valid
+//! [`NeutrosophicJudgment`].
+//!
+//! [`refined_cawa`] fuses refined judgments label by label, and
+//! [`RefinedNeutrosophicJudgment::collapse`] reduces one to a point judgment.
+//! The collapse entry records the method and the refined components and is
+//! sealed over the refined judgment, so [`verify_collapse`] can check a
+//! collapsed judgment against its source.
+//!
+//! ```rust
+//! use opentrustprotocol::{verify_collapse, CollapseMethod, RefinedNeutrosophicJudgment};
+//! use std::collections::BTreeMap;
+//!
+//! let refined = RefinedNeutrosophicJudgment::from_source(
+//!     BTreeMap::from([("identity".to_string(), 0.4), ("credit".to_string(), 0.2)]),
+//!     BTreeMap::from([("missing_data".to_string(), 0.2), ("contradictory_data".to_string(), 0.1)]),
+//!     BTreeMap::from([("fraud_signal".to_string(), 0.05)]),
+//!     "kyc-pipeline",
+//!     "2023-01-01T00:00:00Z",
+//! )?;
+//!
+//! let collapsed = refined.collapse(CollapseMethod::Sum)?;
+//! assert!((collapsed.i - 0.3).abs() < 1e-9);
+//! assert!(verify_collapse(&collapsed, &refined)?);
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::conformance::{
+    canonical_entries, create_fusion_provenance_entry, seal_canonical, CanonicalEntryRef,
+};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{validate_inputs, FUSION_METADATA_VERSION};
+use crate::judgment::{validate_provenance, NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::{ensure_judgment_id, id_canonical};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Operator ID recorded by [`refined_cawa`]
+pub const REFINED_CAWA_OPERATOR_ID: &str = "otp-refined-cawa-v1";
+/// Operator ID recorded by a [`CollapseMethod::Sum`] collapse
+pub const COLLAPSE_SUM_OPERATOR_ID: &str = "otp-refined-collapse-sum-v1";
+/// Operator ID recorded by a [`CollapseMethod::Max`] collapse
+pub const COLLAPSE_MAX_OPERATOR_ID: &str = "otp-refined-collapse-max-v1";
+
+/// Labelled sub-components of one of T, I or F
+pub type Components = BTreeMap<String, f64>;
+
+/// How [`RefinedNeutrosophicJudgment::collapse`] reduces each family of
+/// sub-components to one value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollapseMethod {
+    /// Sum the sub-components; keeps all the mass of the refined judgment
+    Sum,
+    /// Take the largest sub-component
+    Max,
+}
+
+impl CollapseMethod {
+    /// The operator ID recorded in the collapse provenance entry
+    pub fn operator_id(self) -> &'static str {
+        match self {
+            CollapseMethod::Sum => COLLAPSE_SUM_OPERATOR_ID,
+            CollapseMethod::Max => COLLAPSE_MAX_OPERATOR_ID,
+        }
+    }
+
+    /// The method recorded under `operator_id`, if any
+    pub fn from_operator_id(operator_id: &str) -> Option<Self> {
+        match operator_id {
+            COLLAPSE_SUM_OPERATOR_ID => Some(CollapseMethod::Sum),
+            COLLAPSE_MAX_OPERATOR_ID => Some(CollapseMethod::Max),
+            _ => None,
+        }
+    }
+
+    fn apply(self, components: &Components) -> f64 {
+        match self {
+            CollapseMethod::Sum => components.values().sum(),
+            CollapseMethod::Max => components.values().copied().fold(0.0, f64::max),
+        }
+    }
+}
+
+/// A neutrosophic judgment with labelled T, I and F sub-components
+///
+/// Deserializing checks the same invariants as [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RefinedJudgmentData")]
+pub struct RefinedNeutrosophicJudgment {
+    /// Unique identifier for the Circle of Trust, see
+    /// [`generate_refined_judgment_id`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub judgment_id: Option<String>,
+    /// Truth sub-components
+    pub t: Components,
+    /// Indeterminacy sub-components
+    pub i: Components,
+    /// Falsity sub-components
+    pub f: Components,
+    /// Immutable audit trail
+    pub provenance_chain: Vec<ProvenanceEntry>,
+}
+
+/// Unchecked wire form of [`RefinedNeutrosophicJudgment`]
+#[derive(Deserialize)]
+struct RefinedJudgmentData {
+    #[serde(default)]
+    judgment_id: Option<String>,
+    #[serde(default)]
+    t: Components,
+    #[serde(default)]
+    i: Components,
+    #[serde(default)]
+    f: Components,
+    provenance_chain: Vec<ProvenanceEntry>,
+}
+
+impl TryFrom<RefinedJudgmentData> for RefinedNeutrosophicJudgment {
+    type Error = OpenTrustError;
+
+    fn try_from(data: RefinedJudgmentData) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: data.judgment_id,
+            t: data.t,
+            i: data.i,
+            f: data.f,
+            provenance_chain: data.provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+}
+
+impl RefinedNeutrosophicJudgment {
+    /// Creates a new refined judgment
+    ///
+    /// A family may be empty, which counts as 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidValue` for an empty label or a
+    /// sub-component outside [0, 1] (the field is reported as e.g.
+    /// `I.missing_data`), `OpenTrustError::ConservationViolation` if all
+    /// sub-components sum to more than 1, or a provenance error.
+    pub fn new(
+        t: Components,
+        i: Components,
+        f: Components,
+        provenance_chain: Vec<ProvenanceEntry>,
+    ) -> Result<Self> {
+        let judgment = Self {
+            judgment_id: None,
+            t,
+            i,
+            f,
+            provenance_chain,
+        };
+        judgment.validate()?;
+        Ok(judgment)
+    }
+
+    /// Creates a refined judgment with a single provenance entry
+    pub fn from_source(
+        t: Components,
+        i: Components,
+        f: Components,
+        source_id: impl Into<String>,
+        timestamp: impl Into<String>,
+    ) -> Result<Self> {
+        Self::new(
+            t,
+            i,
+            f,
+            vec![ProvenanceEntry::new(source_id.into(), timestamp.into())],
+        )
+    }
+
+    /// Re-runs the constructor checks
+    pub fn validate(&self) -> Result<()> {
+        for (family, components) in [("T", &self.t), ("I", &self.i), ("F", &self.f)] {
+            for (label, &value) in components {
+                if label.trim().is_empty() {
+                    return Err(OpenTrustError::InvalidValue {
+                        field: family.to_string(),
+                        value,
+                        message: format!("{} sub-component labels must not be empty", family),
+                    });
+                }
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(OpenTrustError::InvalidValue {
+                        field: format!("{}.{}", family, label),
+                        value,
+                        message: format!("{} sub-components must be between 0 and 1", family),
+                    });
+                }
+            }
+        }
+
+        let (t, i, f) = (self.truth(), self.indeterminacy(), self.falsity());
+        let sum = t + i + f;
+        if sum > 1.0 {
+            return Err(OpenTrustError::ConservationViolation { t, i, f, sum });
+        }
+        validate_provenance(&self.provenance_chain)
+    }
+
+    /// Returns true if the judgment passes all constructor checks
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Sum of the truth sub-components
+    pub fn truth(&self) -> f64 {
+        self.t.values().sum()
+    }
+
+    /// Sum of the indeterminacy sub-components
+    pub fn indeterminacy(&self) -> f64 {
+        self.i.values().sum()
+    }
+
+    /// Sum of the falsity sub-components
+    pub fn falsity(&self) -> f64 {
+        self.f.values().sum()
+    }
+
+    /// Returns a JSON representation of the judgment
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| OpenTrustError::serialization("refined judgment", e))
+    }
+
+    /// Creates a judgment from JSON, checking its invariants
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| OpenTrustError::deserialization("refined judgment", e))
+    }
+
+    /// Collapses to a point judgment
+    ///
+    /// The result carries this judgment's provenance plus a collapse entry
+    /// whose source ID is the method's operator ID, whose metadata records
+    /// the method, the refined judgment ID and the sub-components, and whose
+    /// conformance seal covers this refined judgment. Its judgment ID is
+    /// assigned.
+    pub fn collapse(&self, method: CollapseMethod) -> Result<NeutrosophicJudgment> {
+        let operator = method.operator_id();
+        let seal = generate_refined_conformance_seal(&[self], &[1.0], operator)?;
+
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("operator".to_string(), operator.into());
+        metadata.insert(
+            "method".to_string(),
+            serde_json::to_value(method)
+                .map_err(|e| OpenTrustError::serialization("collapse method", e))?,
+        );
+        metadata.insert(
+            "refined_judgment_id".to_string(),
+            self.judgment_id.clone().into(),
+        );
+        for (name, components) in [("t", &self.t), ("i", &self.i), ("f", &self.f)] {
+            metadata.insert(
+                name.to_string(),
+                serde_json::to_value(components)
+                    .map_err(|e| OpenTrustError::serialization("refined components", e))?,
+            );
+        }
+
+        let mut provenance = self.provenance_chain.clone();
+        provenance.push(create_fusion_provenance_entry(
+            operator,
+            &chrono::Utc::now().to_rfc3339(),
+            &seal,
+            Some(format!("Collapse of refined judgment using {}", operator)),
+            Some(serde_json::Value::Object(metadata)),
+        ));
+
+        ensure_judgment_id(NeutrosophicJudgment::new_with_entries(
+            method.apply(&self.t),
+            method.apply(&self.i),
+            method.apply(&self.f),
+            provenance,
+        )?)
+    }
+}
+
+impl fmt::Display for RefinedNeutrosophicJudgment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RefinedNeutrosophicJudgment(T={:.3} [{}], I={:.3} [{}], F={:.3} [{}])",
+            self.truth(),
+            self.t.len(),
+            self.indeterminacy(),
+            self.i.len(),
+            self.falsity(),
+            self.f.len()
+        )
+    }
+}
+
+/// Verifies that `collapsed` is a collapse of `refined`
+///
+/// Checks the collapse entry's seal against `refined` and that the
+/// components match the recorded method. Returns `false` on a mismatch.
+///
+/// # Errors
+///
+/// Returns `OpenTrustError::EmptyProvenanceChain` or
+/// `OpenTrustError::MissingSeal` if `collapsed` has no sealed last entry, and
+/// `OpenTrustError::UnknownOperator` if that entry is not a collapse.
+pub fn verify_collapse(
+    collapsed: &NeutrosophicJudgment,
+    refined: &RefinedNeutrosophicJudgment,
+) -> Result<bool> {
+    let last_entry = collapsed
+        .provenance_chain
+        .last()
+        .ok_or(OpenTrustError::EmptyProvenanceChain)?;
+    let stored_seal = last_entry
+        .conformance_seal
+        .as_ref()
+        .ok_or(OpenTrustError::MissingSeal)?;
+    let method = CollapseMethod::from_operator_id(&last_entry.source_id).ok_or_else(|| {
+        OpenTrustError::UnknownOperator {
+            operator_id: last_entry.source_id.clone(),
+        }
+    })?;
+
+    let regenerated = generate_refined_conformance_seal(&[refined], &[1.0], &last_entry.source_id)?;
+    Ok(stored_seal == &regenerated
+        && collapsed.t == method.apply(&refined.t)
+        && collapsed.i == method.apply(&refined.i)
+        && collapsed.f == method.apply(&refined.f))
+}
+
+/// Canonical refined judgment for ID generation
+#[derive(Serialize)]
+struct CanonicalIdJudgment<'a> {
+    t: &'a Components,
+    i: &'a Components,
+    f: &'a Components,
+    provenance_chain: Vec<CanonicalEntryRef<'a>>,
+}
+
+/// Canonical refined judgment for seal generation
+#[derive(Serialize)]
+struct CanonicalSealJudgment<'a> {
+    #[serde(rename = "T")]
+    t: &'a Components,
+    #[serde(rename = "I")]
+    i: &'a Components,
+    #[serde(rename = "F")]
+    f: &'a Components,
+    provenance_chain: Vec<CanonicalEntryRef<'a>>,
+}
+
+/// Generates the judgment ID of a refined judgment
+///
+/// SHA-256 of the canonical JSON of the sub-components (labels in sorted
+/// order) and provenance chain, excluding the judgment ID and conformance
+/// seals.
+pub fn generate_refined_judgment_id(judgment: &RefinedNeutrosophicJudgment) -> Result<String> {
+    let canonical = CanonicalIdJudgment {
+        t: &judgment.t,
+        i: &judgment.i,
+        f: &judgment.f,
+        provenance_chain: canonical_entries(&judgment.provenance_chain),
+    };
+    id_canonical(&canonical, "refined judgment for ID generation")
+}
+
+/// Assigns the judgment ID of a refined judgment if it is missing
+pub fn ensure_refined_judgment_id(
+    mut judgment: RefinedNeutrosophicJudgment,
+) -> Result<RefinedNeutrosophicJudgment> {
+    if judgment.judgment_id.is_none() {
+        judgment.judgment_id = Some(generate_refined_judgment_id(&judgment)?);
+    }
+    Ok(judgment)
+}
+
+/// Generates a conformance seal over refined judgments
+///
+/// Follows the algorithm of
+/// [`generate_conformance_seal`](crate::generate_conformance_seal), with
+/// each family serialized as its label map.
+pub fn generate_refined_conformance_seal(
+    judgments: &[&RefinedNeutrosophicJudgment],
+    weights: &[f64],
+    operator_id: &str,
+) -> Result<String> {
+    let inputs = judgments
+        .iter()
+        .map(|judgment| {
+            let key = judgment
+                .provenance_chain
+                .last()
+                .map_or("", |entry| entry.source_id.as_str());
+            let canonical = CanonicalSealJudgment {
+                t: &judgment.t,
+                i: &judgment.i,
+                f: &judgment.f,
+                provenance_chain: canonical_entries(&judgment.provenance_chain),
+            };
+            (key, canonical)
+        })
+        .collect();
+    seal_canonical(inputs, weights, operator_id)
+}
+
+/// Refinement-aware conflict-aware weighted average
+///
+/// Each judgment's weight is reduced by the conflict between its total
+/// truth and total falsity (`weight * (1 - T * F)`), then every label is
+/// averaged separately; a label missing from a judgment counts as 0 for it.
+/// The result has the union of the input labels, a sealed fusion entry and
+/// its judgment ID.
+///
+/// # Errors
+///
+/// The same input errors as
+/// [`conflict_aware_weighted_average`](crate::conflict_aware_weighted_average).
+pub fn refined_cawa(
+    judgments: &[&RefinedNeutrosophicJudgment],
+    weights: &[f64],
+) -> Result<RefinedNeutrosophicJudgment> {
+    validate_inputs(judgments, Some(weights))?;
+
+    let mut adjusted: Vec<f64> = judgments
+        .iter()
+        .zip(weights)
+        .map(|(judgment, &weight)| weight * (1.0 - judgment.truth() * judgment.falsity()))
+        .collect();
+    if adjusted.iter().sum::<f64>() == 0.0 {
+        adjusted = vec![1.0; judgments.len()];
+    }
+    let total: f64 = adjusted.iter().sum();
+
+    let average = |family: fn(&RefinedNeutrosophicJudgment) -> &Components| -> Components {
+        let labels: BTreeSet<&String> = judgments.iter().flat_map(|j| family(j).keys()).collect();
+        labels
+            .into_iter()
+            .map(|label| {
+                let value = judgments
+                    .iter()
+                    .zip(&adjusted)
+                    .map(|(judgment, weight)| family(judgment).get(label).unwrap_or(&0.0) * weight)
+                    .sum::<f64>()
+                    / total;
+                (label.clone(), value.min(1.0))
+            })
+            .collect()
+    };
+
+    let mut metadata = serde_json::Map::new();
+    metadata.insert("operator".to_string(), REFINED_CAWA_OPERATOR_ID.into());
+    metadata.insert("input_count".to_string(), judgments.len().into());
+    metadata.insert("weights".to_string(), weights.into());
+    metadata.insert("version".to_string(), FUSION_METADATA_VERSION.into());
+    let seal = generate_refined_conformance_seal(judgments, weights, REFINED_CAWA_OPERATOR_ID)?;
+
+    let mut provenance = Vec::new();
+    for judgment in judgments {
+        provenance.extend(judgment.provenance_chain.clone());
+    }
+    provenance.push(create_fusion_provenance_entry(
+        REFINED_CAWA_OPERATOR_ID,
+        &chrono::Utc::now().to_rfc3339(),
+        &seal,
+        Some(format!(
+            "Fusion operation using {} with Conformance Seal",
+            REFINED_CAWA_OPERATOR_ID
+        )),
+        Some(serde_json::Value::Object(metadata)),
+    ));
+
+    ensure_refined_judgment_id(RefinedNeutrosophicJudgment::new(
+        average(|j| &j.t),
+        average(|j| &j.i),
+        average(|j| &j.f),
+        provenance,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn components(pairs: &[(&str, f64)]) -> Components {
+        pairs
+            .iter()
+            .map(|&(label, value)| (label.to_string(), value))
+            .collect()
+    }
+
+    fn refined(
+        t: &[(&str, f64)],
+        i: &[(&str, f64)],
+        f: &[(&str, f64)],
+        source: &str,
+    ) -> RefinedNeutrosophicJudgment {
+        RefinedNeutrosophicJudgment::from_source(
+            components(t),
+            components(i),
+            components(f),
+            source,
+            "2023-01-01T00:00:00Z",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validation_and_json() {
+        let judgment = refined(
+            &[("identity", 0.5)],
+            &[("missing_data", 0.2), ("contradictory_data", 0.1)],
+            &[],
+            "kyc",
+        );
+        assert!((judgment.indeterminacy() - 0.3).abs() < 1e-12);
+        assert_eq!(judgment.falsity(), 0.0);
+
+        let json = judgment.to_json().unwrap();
+        assert_eq!(
+            RefinedNeutrosophicJudgment::from_json(&json).unwrap(),
+            judgment
+        );
+
+        let error = RefinedNeutrosophicJudgment::from_source(
+            components(&[("identity", 1.5)]),
+            Components::new(),
+            Components::new(),
+            "kyc",
+            "2023-01-01T00:00:00Z",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, OpenTrustError::InvalidValue { ref field, .. } if field == "T.identity")
+        );
+        assert!(matches!(
+            RefinedNeutrosophicJudgment::from_source(
+                components(&[("a", 0.5), ("b", 0.3)]),
+                components(&[("missing_data", 0.3)]),
+                Components::new(),
+                "kyc",
+                "2023-01-01T00:00:00Z",
+            ),
+            Err(OpenTrustError::ConservationViolation { .. })
+        ));
+        let overfull = r#"{"t": {"a": 0.7}, "i": {"b": 0.4}, "f": {},
+            "provenance_chain": [{"source_id": "kyc", "timestamp": "2023-01-01T00:00:00Z"}]}"#;
+        assert!(RefinedNeutrosophicJudgment::from_json(overfull).is_err());
+    }
+
+    #[test]
+    fn test_collapse_records_method_and_verifies() {
+        let judgment = ensure_refined_judgment_id(refined(
+            &[("identity", 0.4), ("credit", 0.2)],
+            &[("missing_data", 0.2), ("contradictory_data", 0.1)],
+            &[("fraud", 0.05)],
+            "kyc",
+        ))
+        .unwrap();
+
+        let summed = judgment.collapse(CollapseMethod::Sum).unwrap();
+        assert!((summed.t - 0.6).abs() < 1e-12);
+        assert!(summed.judgment_id.is_some());
+        let entry = summed.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, COLLAPSE_SUM_OPERATOR_ID);
+        let metadata = entry.metadata.as_ref().unwrap();
+        assert_eq!(metadata["method"], "sum");
+        assert_eq!(
+            metadata["refined_judgment_id"],
+            serde_json::json!(judgment.judgment_id)
+        );
+        assert_eq!(metadata["i"]["missing_data"], 0.2);
+        assert!(verify_collapse(&summed, &judgment).unwrap());
+
+        let maxed = judgment.collapse(CollapseMethod::Max).unwrap();
+        assert_eq!((maxed.t, maxed.i, maxed.f), (0.4, 0.2, 0.05));
+        assert!(verify_collapse(&maxed, &judgment).unwrap());
+
+        // Tampering with the components or the source is detected
+        let mut tampered = summed.clone();
+        tampered.t = 0.7;
+        assert!(!verify_collapse(&tampered, &judgment).unwrap());
+        let other = refined(&[("identity", 0.6)], &[], &[], "kyc");
+        assert!(!verify_collapse(&summed, &other).unwrap());
+    }
+
+    #[test]
+    fn test_refined_cawa_fuses_label_by_label() {
+        let a = refined(
+            &[("identity", 0.6)],
+            &[("missing_data", 0.2)],
+            &[("fraud", 0.0)],
+            "kyc",
+        );
+        let b = refined(
+            &[("identity", 0.4)],
+            &[("contradictory_data", 0.4)],
+            &[],
+            "bureau",
+        );
+
+        let fused = refined_cawa(&[&a, &b], &[1.0, 1.0]).unwrap();
+        assert!(fused.is_valid());
+        assert_eq!(fused.t["identity"], 0.5);
+        assert_eq!(fused.i["missing_data"], 0.1);
+        assert_eq!(fused.i["contradictory_data"], 0.2);
+        assert_eq!(fused.f["fraud"], 0.0);
+        assert_eq!(fused.provenance_chain.len(), 3);
+        assert_eq!(
+            fused.judgment_id,
+            Some(generate_refined_judgment_id(&fused).unwrap())
+        );
+        assert_eq!(
+            fused.provenance_chain.last().unwrap().conformance_seal,
+            Some(
+                generate_refined_conformance_seal(&[&b, &a], &[1.0, 1.0], REFINED_CAWA_OPERATOR_ID)
+                    .unwrap()
+            )
+        );
+
+        // The collapsed fusion has the same totals as the label-wise result
+        let collapsed = fused.collapse(CollapseMethod::Sum).unwrap();
+        assert!((collapsed.i - 0.3).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,1120 @@
+//! Neutrosophic logic rules over named judgments
+//!
+//! A [`RuleSet`] holds rules such as
+//! `approve := (identity_ok AND credit_ok) OR manual_override`, where each
+//! atom names a [`NeutrosophicJudgment`] supplied at evaluation time (or the
+//! result of an earlier rule in the same set). Expressions use `AND`, `OR`,
+//! `NOT` and parentheses; `NOT` binds tightest, then `AND`, then `OR`.
+//!
+//! The connectives come from one [`NormFamily`] per rule set:
+//!
+//! - `A AND B`: T = N(Ta, Tb), I = S(Ia, Ib), F = S(Fa, Fb)
+//! - `A OR B`: T = S(Ta, Tb), I = N(Ia, Ib), F = N(Fa, Fb)
+//! - `NOT A`: (F, I, T)
+//!
+//! as in [`NeutrosophicJudgment::intersection`], `union` and `complement`,
+//! but the whole expression is computed in one step: the result carries the
+//! provenance of every atom plus a single sealed entry that references the
+//! rule set, the rule ID and each atom's judgment ID. A result above
+//! T + I + F = 1 is scaled down proportionally and the entry records it
+//! under `normalization`.
+//!
+//! Rule sets are JSON documents, loaded with [`load_rule_set_file`] or
+//! [`load_rule_set_dir`]. They can sit in the mapper configuration directory,
+//! either in their own files or as a `rule_sets` array next to `mappers`:
+//!
+//! ```json
+//! {
+//!   "mappers": [],
+//!   "rule_sets": [{
+//!     "id": "loan-approval",
+//!     "version": "1.0.0",
+//!     "connectives": "minimum",
+//!     "rules": [
+//!       {"id": "approve", "expression": "(identity_ok AND credit_ok) OR manual_override"}
+//!     ]
+//!   }]
+//! }
+//! ```
+//!
+//! ```rust
+//! use opentrustprotocol::{NeutrosophicJudgment, RuleSet};
+//! use std::collections::HashMap;
+//!
+//! let rules: RuleSet = serde_json::from_str(r#"{
+//!     "id": "loan-approval", "version": "1.0.0", "connectives": "minimum",
+//!     "rules": [{"id": "approve", "expression": "(identity_ok AND credit_ok) OR manual_override"}]
+//! }"#)?;
+//!
+//! let atom = |t, i, f, source: &str| {
+//!     NeutrosophicJudgment::new(t, i, f, vec![(source.to_string(), "2023-01-01T00:00:00Z".to_string())])
+//! };
+//! let atoms = HashMap::from([
+//!     ("identity_ok".to_string(), atom(0.9, 0.1, 0.0, "kyc")?),
+//!     ("credit_ok".to_string(), atom(0.7, 0.2, 0.1, "bureau")?),
+//!     ("manual_override".to_string(), atom(0.0, 0.0, 1.0, "ops")?),
+//! ]);
+//!
+//! let approve = rules.evaluate("approve", &atoms)?;
+//! assert_eq!(approve.t, 0.7);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::algebra::NormFamily;
+use crate::conformance::{create_fusion_provenance_entry, generate_conformance_seal};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::note_normalization;
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::{ensure_judgment_id, generate_judgment_id};
+use crate::mapper::loader::{ConfigError, ConfigIssue};
+use crate::paraconsistent::Normalization;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Prefix of the operator ID recorded by rule evaluation
+///
+/// The full ID is `otp-rule-v1:<rule set>@<version>#<rule>`, so the seal
+/// covers which rule produced the result.
+pub const RULE_OPERATOR_PREFIX: &str = "otp-rule-v1";
+
+/// A parsed rule expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// A named judgment, or the result of an earlier rule
+    Atom(String),
+    /// Negation
+    Not(Box<Expr>),
+    /// Conjunction of two or more operands
+    And(Vec<Expr>),
+    /// Disjunction of two or more operands
+    Or(Vec<Expr>),
+}
+
+impl Expr {
+    /// Names referenced by the expression
+    pub fn atoms(&self) -> BTreeSet<&str> {
+        let mut atoms = BTreeSet::new();
+        self.collect_atoms(&mut atoms);
+        atoms
+    }
+
+    fn collect_atoms<'a>(&'a self, atoms: &mut BTreeSet<&'a str>) {
+        match self {
+            Expr::Atom(name) => {
+                atoms.insert(name);
+            }
+            Expr::Not(operand) => operand.collect_atoms(atoms),
+            Expr::And(operands) | Expr::Or(operands) => {
+                for operand in operands {
+                    operand.collect_atoms(atoms);
+                }
+            }
+        }
+    }
+
+    fn evaluate(
+        &self,
+        family: NormFamily,
+        values: &BTreeMap<&str, &NeutrosophicJudgment>,
+    ) -> (f64, f64, f64) {
+        match self {
+            Expr::Atom(name) => {
+                let judgment = values[name.as_str()];
+                (judgment.t, judgment.i, judgment.f)
+            }
+            Expr::Not(operand) => {
+                let (t, i, f) = operand.evaluate(family, values);
+                (f, i, t)
+            }
+            Expr::And(operands) => operands
+                .iter()
+                .map(|operand| operand.evaluate(family, values))
+                .reduce(|(ta, ia, fa), (tb, ib, fb)| {
+                    (
+                        family.t_norm(ta, tb),
+                        family.t_conorm(ia, ib),
+                        family.t_conorm(fa, fb),
+                    )
+                })
+                .expect("parser never builds an empty conjunction"),
+            Expr::Or(operands) => operands
+                .iter()
+                .map(|operand| operand.evaluate(family, values))
+                .reduce(|(ta, ia, fa), (tb, ib, fb)| {
+                    (
+                        family.t_conorm(ta, tb),
+                        family.t_norm(ia, ib),
+                        family.t_norm(fa, fb),
+                    )
+                })
+                .expect("parser never builds an empty disjunction"),
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, operands: &[Expr], keyword: &str| {
+            write!(f, "(")?;
+            for (index, operand) in operands.iter().enumerate() {
+                if index > 0 {
+                    write!(f, " {} ", keyword)?;
+                }
+                write!(f, "{}", operand)?;
+            }
+            write!(f, ")")
+        };
+        match self {
+            Expr::Atom(name) => write!(f, "{}", name),
+            Expr::Not(operand) => write!(f, "NOT {}", operand),
+            Expr::And(operands) => join(f, operands, "AND"),
+            Expr::Or(operands) => join(f, operands, "OR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> std::result::Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.char_indices().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '(' {
+            tokens.push(Token::Open);
+            chars.next();
+        } else if c == ')' {
+            tokens.push(Token::Close);
+            chars.next();
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut word = String::new();
+            while let Some(&(_, c)) = chars.peek() {
+                if c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':') {
+                    word.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(match word.to_ascii_uppercase().as_str() {
+                "AND" => Token::And,
+                "OR" => Token::Or,
+                "NOT" => Token::Not,
+                _ => Token::Name(word),
+            });
+        } else {
+            return Err(format!("unexpected character '{}' at {}", c, position));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Deepest nesting of `NOT` and parentheses a rule expression may use
+const MAX_EXPRESSION_DEPTH: usize = 64;
+
+/// Recursive-descent parser: or := and (OR and)*, and := unary (AND unary)*,
+/// unary := NOT unary | name | ( or )
+///
+/// `depth` counts the enclosing `NOT`s and parentheses, so hostile input
+/// cannot recurse past [`MAX_EXPRESSION_DEPTH`].
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn parse(expression: &str) -> std::result::Result<Expr, String> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+            depth: 0,
+        };
+        if parser.tokens.is_empty() {
+            return Err("expression is empty".to_string());
+        }
+        let expr = parser.or()?;
+        match parser.tokens.get(parser.position) {
+            None => Ok(expr),
+            Some(token) => Err(format!("unexpected {:?} after complete expression", token)),
+        }
+    }
+
+    fn next_is(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.position) == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> std::result::Result<Expr, String> {
+        let mut operands = vec![self.and()?];
+        while self.next_is(&Token::Or) {
+            operands.push(self.and()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().expect("one operand")
+        } else {
+            Expr::Or(operands)
+        })
+    }
+
+    fn and(&mut self) -> std::result::Result<Expr, String> {
+        let mut operands = vec![self.unary()?];
+        while self.next_is(&Token::And) {
+            operands.push(self.unary()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().expect("one operand")
+        } else {
+            Expr::And(operands)
+        })
+    }
+
+    fn unary(&mut self) -> std::result::Result<Expr, String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        match token {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.nested(Self::unary)?))),
+            Some(Token::Name(name)) => Ok(Expr::Atom(name)),
+            Some(Token::Open) => {
+                let expr = self.nested(Self::or)?;
+                if self.next_is(&Token::Close) {
+                    Ok(expr)
+                } else {
+                    Err("missing ')'".to_string())
+                }
+            }
+            Some(token) => Err(format!("expected an atom, NOT or '(', found {:?}", token)),
+            None => Err("expression ends unexpectedly".to_string()),
+        }
+    }
+
+    /// Parse one level deeper, failing past [`MAX_EXPRESSION_DEPTH`]
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> std::result::Result<Expr, String>,
+    ) -> std::result::Result<Expr, String> {
+        if self.depth == MAX_EXPRESSION_DEPTH {
+            return Err(format!(
+                "expression nests NOT and parentheses deeper than {} levels",
+                MAX_EXPRESSION_DEPTH
+            ));
+        }
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+}
+
+/// A named rule: `id := expression`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RuleData", into = "RuleData")]
+pub struct Rule {
+    id: String,
+    expression: String,
+    description: Option<String>,
+    expr: Expr,
+}
+
+/// Wire form of [`Rule`]
+#[derive(Serialize, Deserialize)]
+struct RuleData {
+    id: String,
+    expression: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl TryFrom<RuleData> for Rule {
+    type Error = OpenTrustError;
+
+    fn try_from(data: RuleData) -> Result<Self> {
+        let mut rule = Rule::new(data.id, data.expression)?;
+        rule.description = data.description;
+        Ok(rule)
+    }
+}
+
+impl From<Rule> for RuleData {
+    fn from(rule: Rule) -> Self {
+        RuleData {
+            id: rule.id,
+            expression: rule.expression,
+            description: rule.description,
+        }
+    }
+}
+
+impl Rule {
+    /// Parse a rule
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidRule` for an empty ID or an
+    /// expression that does not parse.
+    pub fn new(id: impl Into<String>, expression: impl Into<String>) -> Result<Self> {
+        let id = id.into();
+        let expression = expression.into();
+        if id.trim().is_empty() {
+            return Err(OpenTrustError::InvalidRule {
+                rule_id: id,
+                message: "rule ID must not be empty".to_string(),
+            });
+        }
+        let expr = Parser::parse(&expression).map_err(|message| OpenTrustError::InvalidRule {
+            rule_id: id.clone(),
+            message,
+        })?;
+        Ok(Self {
+            id,
+            expression,
+            description: None,
+            expr,
+        })
+    }
+
+    /// Parse `id := expression`
+    pub fn parse(definition: &str) -> Result<Self> {
+        match definition.split_once(":=") {
+            Some((id, expression)) => Rule::new(id.trim(), expression.trim()),
+            None => Err(OpenTrustError::InvalidRule {
+                rule_id: definition.to_string(),
+                message: "expected 'id := expression'".to_string(),
+            }),
+        }
+    }
+
+    /// Set a description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Rule ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Expression as written
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Description, if any
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Parsed expression
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} := {}", self.id, self.expression)
+    }
+}
+
+/// A versioned set of rules sharing one connective family
+///
+/// A rule may reference the ID of an earlier rule in the set, which then
+/// stands for that rule's result; forward references are rejected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RuleSetData", into = "RuleSetData")]
+pub struct RuleSet {
+    id: String,
+    version: String,
+    connectives: NormFamily,
+    description: Option<String>,
+    rules: Vec<Rule>,
+}
+
+/// Wire form of [`RuleSet`]
+#[derive(Serialize, Deserialize)]
+struct RuleSetData {
+    id: String,
+    version: String,
+    #[serde(default)]
+    connectives: NormFamily,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    rules: Vec<Rule>,
+}
+
+impl TryFrom<RuleSetData> for RuleSet {
+    type Error = OpenTrustError;
+
+    fn try_from(data: RuleSetData) -> Result<Self> {
+        let mut rule_set = RuleSet::new(data.id, data.version, data.connectives, data.rules)?;
+        rule_set.description = data.description;
+        Ok(rule_set)
+    }
+}
+
+impl From<RuleSet> for RuleSetData {
+    fn from(rule_set: RuleSet) -> Self {
+        RuleSetData {
+            id: rule_set.id,
+            version: rule_set.version,
+            connectives: rule_set.connectives,
+            description: rule_set.description,
+            rules: rule_set.rules,
+        }
+    }
+}
+
+impl RuleSet {
+    /// Create a rule set
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidVersion` if `version` is not a
+    /// semantic version, and `OpenTrustError::InvalidRule` for an empty ID,
+    /// duplicate rule IDs or a reference to a later rule.
+    pub fn new(
+        id: impl Into<String>,
+        version: impl Into<String>,
+        connectives: NormFamily,
+        rules: Vec<Rule>,
+    ) -> Result<Self> {
+        let id = id.into();
+        let version = version.into();
+        if id.trim().is_empty() {
+            return Err(OpenTrustError::InvalidRule {
+                rule_id: String::new(),
+                message: "rule set ID must not be empty".to_string(),
+            });
+        }
+        semver::Version::parse(&version).map_err(|e| OpenTrustError::InvalidVersion {
+            id: id.clone(),
+            version: version.clone(),
+            source: Arc::new(e),
+        })?;
+
+        let all: HashMap<&str, usize> = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.id(), index))
+            .collect();
+        let mut seen = BTreeSet::new();
+        for (index, rule) in rules.iter().enumerate() {
+            if !seen.insert(rule.id()) {
+                return Err(OpenTrustError::InvalidRule {
+                    rule_id: rule.id.clone(),
+                    message: "rule ID is defined more than once".to_string(),
+                });
+            }
+            for atom in rule.expr.atoms() {
+                if all.get(atom).is_some_and(|&referenced| referenced >= index) {
+                    return Err(OpenTrustError::InvalidRule {
+                        rule_id: rule.id.clone(),
+                        message: format!(
+                            "references rule '{}', which is not defined before it",
+                            atom
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            id,
+            version,
+            connectives,
+            description: None,
+            rules,
+        })
+    }
+
+    /// Rule set ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Rule set version
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Connective family used by every rule
+    pub fn connectives(&self) -> NormFamily {
+        self.connectives
+    }
+
+    /// The rules, in definition order
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// The rule with this ID
+    pub fn rule(&self, rule_id: &str) -> Option<&Rule> {
+        self.rules.iter().find(|rule| rule.id == rule_id)
+    }
+
+    /// Atom names that must be supplied to evaluate `rule_id`, including
+    /// those of the earlier rules it references
+    pub fn required_atoms(&self, rule_id: &str) -> Result<BTreeSet<String>> {
+        let rule = self.find(rule_id)?;
+        let mut atoms = BTreeSet::new();
+        for atom in rule.expr.atoms() {
+            if self.rule(atom).is_some() {
+                atoms.extend(self.required_atoms(atom)?);
+            } else {
+                atoms.insert(atom.to_string());
+            }
+        }
+        Ok(atoms)
+    }
+
+    /// Evaluate one rule against named atom judgments
+    ///
+    /// The result's provenance holds the chains of the referenced atoms (in
+    /// name order) followed by one sealed entry whose source ID is the
+    /// rule's operator ID (see [`RULE_OPERATOR_PREFIX`]) and whose metadata
+    /// records the rule set, the rule ID, the expression, the connective
+    /// family and each atom's judgment ID. The seal covers the referenced
+    /// judgments in name order with weight 1.0 each. Its judgment ID is
+    /// assigned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::RuleNotFound` for an unknown rule and
+    /// `OpenTrustError::MissingAtom` if an atom has no judgment.
+    pub fn evaluate(
+        &self,
+        rule_id: &str,
+        atoms: &HashMap<String, NeutrosophicJudgment>,
+    ) -> Result<NeutrosophicJudgment> {
+        self.evaluate_cached(rule_id, atoms, &mut HashMap::new())
+    }
+
+    /// Evaluate every rule, in definition order
+    pub fn evaluate_all(
+        &self,
+        atoms: &HashMap<String, NeutrosophicJudgment>,
+    ) -> Result<BTreeMap<String, NeutrosophicJudgment>> {
+        let mut results = HashMap::new();
+        for rule in &self.rules {
+            self.evaluate_cached(&rule.id, atoms, &mut results)?;
+        }
+        Ok(results.into_iter().collect())
+    }
+
+    /// The operator ID recorded for `rule_id`
+    pub fn operator_id(&self, rule_id: &str) -> String {
+        format!(
+            "{}:{}@{}#{}",
+            RULE_OPERATOR_PREFIX, self.id, self.version, rule_id
+        )
+    }
+
+    fn find(&self, rule_id: &str) -> Result<&Rule> {
+        self.rule(rule_id)
+            .ok_or_else(|| OpenTrustError::RuleNotFound {
+                rule_set: self.id.clone(),
+                rule_id: rule_id.to_string(),
+            })
+    }
+
+    fn evaluate_cached(
+        &self,
+        rule_id: &str,
+        atoms: &HashMap<String, NeutrosophicJudgment>,
+        results: &mut HashMap<String, NeutrosophicJudgment>,
+    ) -> Result<NeutrosophicJudgment> {
+        if let Some(result) = results.get(rule_id) {
+            return Ok(result.clone());
+        }
+        let rule = self.find(rule_id)?;
+
+        // Earlier rules referenced by this one are evaluated first
+        for name in rule.expr.atoms() {
+            if self.rule(name).is_some() && !results.contains_key(name) {
+                self.evaluate_cached(name, atoms, results)?;
+            }
+        }
+
+        let mut operands: BTreeMap<&str, &NeutrosophicJudgment> = BTreeMap::new();
+        for name in rule.expr.atoms() {
+            let judgment = match results.get(name) {
+                Some(result) if self.rule(name).is_some() => result,
+                _ => atoms.get(name).ok_or_else(|| OpenTrustError::MissingAtom {
+                    rule_id: rule.id.clone(),
+                    atom: name.to_string(),
+                })?,
+            };
+            operands.insert(name, judgment);
+        }
+
+        let (t, i, f) = rule.expr.evaluate(self.connectives, &operands);
+        let result = self.seal(rule, &operands, (t, i, f))?;
+        results.insert(rule.id.clone(), result.clone());
+        Ok(result)
+    }
+
+    fn seal(
+        &self,
+        rule: &Rule,
+        operands: &BTreeMap<&str, &NeutrosophicJudgment>,
+        (t, i, f): (f64, f64, f64),
+    ) -> Result<NeutrosophicJudgment> {
+        let operator = self.operator_id(&rule.id);
+
+        let mut atom_ids = serde_json::Map::new();
+        for (name, judgment) in operands {
+            let id = match &judgment.judgment_id {
+                Some(id) => id.clone(),
+                None => generate_judgment_id(judgment)?,
+            };
+            atom_ids.insert(name.to_string(), id.into());
+        }
+
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("operator".to_string(), operator.clone().into());
+        metadata.insert("rule_set".to_string(), self.id.clone().into());
+        metadata.insert("rule_set_version".to_string(), self.version.clone().into());
+        metadata.insert("rule_id".to_string(), rule.id.clone().into());
+        metadata.insert("expression".to_string(), rule.expression.clone().into());
+        metadata.insert("connectives".to_string(), self.connectives.name().into());
+        metadata.insert("atoms".to_string(), atom_ids.into());
+
+        let inputs: Vec<&NeutrosophicJudgment> = operands.values().copied().collect();
+        let seal = generate_conformance_seal(&inputs, &vec![1.0; inputs.len()], &operator)?;
+        let mut entry = create_fusion_provenance_entry(
+            &operator,
+            &chrono::Utc::now().to_rfc3339(),
+            &seal,
+            Some(format!("Rule {}", rule)),
+            Some(serde_json::Value::Object(metadata)),
+        );
+
+        let total = t + i + f;
+        if total > 1.0 {
+            note_normalization(&mut entry, Normalization::Proportional.name(), total);
+        }
+        let (t, i, f) = Normalization::Proportional.apply(t, i, f);
+
+        let mut provenance = Vec::new();
+        for judgment in &inputs {
+            provenance.extend(judgment.provenance_chain.clone());
+        }
+        provenance.push(entry);
+
+        ensure_judgment_id(NeutrosophicJudgment::new_with_entries(t, i, f, provenance)?)
+    }
+}
+
+/// Load the rule sets in a JSON file
+///
+/// The file holds a single rule set, an array of them, or an object with a
+/// `rule_sets` array (which may sit next to a `mappers` array).
+pub fn load_rule_set_file(
+    path: impl AsRef<Path>,
+) -> std::result::Result<Vec<RuleSet>, ConfigError> {
+    let path = path.as_ref();
+    let mut issues = Vec::new();
+    let rule_sets = match read_document(path, &mut issues)? {
+        Some(document) => match rule_set_entries(document) {
+            Some(entries) => parse_entries(path, entries, &mut issues),
+            None => {
+                issues.push(issue(path, "", "no rule sets found"));
+                Vec::new()
+            }
+        },
+        None => Vec::new(),
+    };
+
+    if issues.is_empty() {
+        Ok(rule_sets)
+    } else {
+        Err(ConfigError::Invalid { issues })
+    }
+}
+
+/// Load the rule sets in every JSON file of a directory
+///
+/// Files are read in file-name order. Files that hold only mapper
+/// definitions are skipped, so this can be pointed at the same directory as
+/// [`load_mapper_dir`](crate::load_mapper_dir). A rule set ID and version
+/// defined more than once is reported.
+pub fn load_rule_set_dir(dir: impl AsRef<Path>) -> std::result::Result<Vec<RuleSet>, ConfigError> {
+    let dir = dir.as_ref();
+    let io_error = |source| ConfigError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut issues = Vec::new();
+    let mut rule_sets = Vec::new();
+    let mut seen: HashMap<String, PathBuf> = HashMap::new();
+    for path in paths {
+        let Some(entries) = read_document(&path, &mut issues)?.and_then(rule_set_entries) else {
+            continue;
+        };
+        for rule_set in parse_entries(&path, entries, &mut issues) {
+            let key = format!("{}@{}", rule_set.id, rule_set.version);
+            if let Some(previous) = seen.get(&key) {
+                issues.push(issue(
+                    &path,
+                    "",
+                    &format!(
+                        "rule set '{}' version {} is already defined in {}",
+                        rule_set.id,
+                        rule_set.version,
+                        previous.display()
+                    ),
+                ));
+            } else {
+                seen.insert(key, path.clone());
+            }
+            rule_sets.push(rule_set);
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(rule_sets)
+    } else {
+        Err(ConfigError::Invalid { issues })
+    }
+}
+
+fn issue(path: &Path, field_path: &str, message: &str) -> ConfigIssue {
+    ConfigIssue {
+        file: path.to_path_buf(),
+        field_path: field_path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn read_document(
+    path: &Path,
+    issues: &mut Vec<ConfigIssue>,
+) -> std::result::Result<Option<serde_json::Value>, ConfigError> {
+    let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    match serde_json::from_str(&contents) {
+        Ok(document) => Ok(Some(document)),
+        Err(e) => {
+            issues.push(issue(path, "", &e.to_string()));
+            Ok(None)
+        }
+    }
+}
+
+/// Whether a document is a standalone rule set or an array of them, rather
+/// than mapper definitions
+pub(crate) fn is_rule_set_document(document: &serde_json::Value) -> bool {
+    let is_rule_set = |value: &serde_json::Value| value.get("rules").is_some();
+    match document {
+        serde_json::Value::Array(items) => !items.is_empty() && items.iter().all(is_rule_set),
+        document => is_rule_set(document),
+    }
+}
+
+/// Rule set definitions in a document with their field paths, or `None` if
+/// the document holds no rule sets
+fn rule_set_entries(document: serde_json::Value) -> Option<Vec<(String, serde_json::Value)>> {
+    match document {
+        serde_json::Value::Object(mut object) if object.contains_key("rule_sets") => {
+            match object.remove("rule_sets") {
+                Some(serde_json::Value::Array(items)) => Some(
+                    items
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, item)| (format!("rule_sets[{}]", index), item))
+                        .collect(),
+                ),
+                other => Some(vec![("rule_sets".to_string(), other.unwrap_or_default())]),
+            }
+        }
+        document if is_rule_set_document(&document) => match document {
+            serde_json::Value::Array(items) => Some(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| (format!("[{}]", index), item))
+                    .collect(),
+            ),
+            document => Some(vec![(String::new(), document)]),
+        },
+        _ => None,
+    }
+}
+
+fn parse_entries(
+    path: &Path,
+    entries: Vec<(String, serde_json::Value)>,
+    issues: &mut Vec<ConfigIssue>,
+) -> Vec<RuleSet> {
+    let mut rule_sets = Vec::new();
+    for (prefix, value) in entries {
+        match serde_path_to_error::deserialize::<_, RuleSet>(value) {
+            Ok(rule_set) => rule_sets.push(rule_set),
+            Err(e) => {
+                let inner = e.path().to_string();
+                let field_path = match (prefix.is_empty(), inner.as_str()) {
+                    (_, ".") => prefix,
+                    (true, inner) => inner.to_string(),
+                    (false, inner) => format!("{}.{}", prefix, inner),
+                };
+                issues.push(issue(path, &field_path, &e.into_inner().to_string()));
+            }
+        }
+    }
+    rule_sets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+    use crate::judgment::test_support::judgment;
+
+    fn loan_rules(connectives: NormFamily) -> RuleSet {
+        RuleSet::new(
+            "loan-approval",
+            "1.0.0",
+            connectives,
+            vec![
+                Rule::parse("approve := (identity_ok AND credit_ok) OR manual_override").unwrap(),
+                Rule::parse("escalate := NOT approve AND NOT fraud_flag").unwrap(),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn atoms() -> HashMap<String, NeutrosophicJudgment> {
+        HashMap::from([
+            ("identity_ok".to_string(), judgment(0.9, 0.1, 0.0, "kyc")),
+            ("credit_ok".to_string(), judgment(0.7, 0.2, 0.1, "bureau")),
+            (
+                "manual_override".to_string(),
+                judgment(0.0, 0.0, 1.0, "ops"),
+            ),
+            ("fraud_flag".to_string(), judgment(0.1, 0.1, 0.8, "fraud")),
+        ])
+    }
+
+    #[test]
+    fn test_parse_precedence_and_errors() {
+        let rule = Rule::parse("r := a OR NOT b AND c").unwrap();
+        assert_eq!(
+            rule.expr(),
+            &Expr::Or(vec![
+                Expr::Atom("a".to_string()),
+                Expr::And(vec![
+                    Expr::Not(Box::new(Expr::Atom("b".to_string()))),
+                    Expr::Atom("c".to_string()),
+                ]),
+            ])
+        );
+        assert_eq!(rule.expr().to_string(), "(a OR (NOT b AND c))");
+        assert_eq!(
+            Rule::new("r", "(a and b) or c").unwrap().expr(),
+            Rule::new("r", "(a AND b) OR c").unwrap().expr()
+        );
+
+        for bad in ["", "a AND", "(a OR b", "a b", "a & b", "AND a"] {
+            assert!(
+                matches!(Rule::new("r", bad), Err(OpenTrustError::InvalidRule { .. })),
+                "{:?} should not parse",
+                bad
+            );
+        }
+        assert!(Rule::parse("no definition").is_err());
+    }
+
+    #[test]
+    fn test_deep_nesting_is_rejected() {
+        let nested_not = format!("{}a", "NOT ".repeat(200_000));
+        let nested_parens = format!("{}a{}", "(".repeat(200_000), ")".repeat(200_000));
+        for expression in [nested_not, nested_parens] {
+            assert!(matches!(
+                Rule::new("r", expression),
+                Err(OpenTrustError::InvalidRule { .. })
+            ));
+        }
+
+        let limit = format!("{}a", "NOT ".repeat(MAX_EXPRESSION_DEPTH));
+        assert!(Rule::new("r", limit).is_ok());
+    }
+
+    #[test]
+    fn test_rule_set_validation() {
+        let forward = RuleSet::new(
+            "s",
+            "1.0.0",
+            NormFamily::Minimum,
+            vec![
+                Rule::new("a", "b AND x").unwrap(),
+                Rule::new("b", "y").unwrap(),
+            ],
+        );
+        assert!(
+            matches!(forward, Err(OpenTrustError::InvalidRule { ref rule_id, .. }) if rule_id == "a")
+        );
+        let duplicate = RuleSet::new(
+            "s",
+            "1.0.0",
+            NormFamily::Minimum,
+            vec![Rule::new("a", "x").unwrap(), Rule::new("a", "y").unwrap()],
+        );
+        assert!(duplicate.is_err());
+        assert!(matches!(
+            RuleSet::new("s", "one", NormFamily::Minimum, vec![]),
+            Err(OpenTrustError::InvalidVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_references_atoms_and_rule() {
+        let rules = loan_rules(NormFamily::Minimum);
+        let atoms = atoms();
+
+        let approve = rules.evaluate("approve", &atoms).unwrap();
+        // (0.9 ∧ 0.7) ∨ 0.0 = 0.7 in T; I: (0.1 ∨ 0.2) ∧ 0.0 = 0.0; F: (0.0 ∨ 0.1) ∧ 1.0 = 0.1
+        assert_eq!((approve.t, approve.i, approve.f), (0.7, 0.0, 0.1));
+        assert!(approve.judgment_id.is_some());
+
+        let entry = approve.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, "otp-rule-v1:loan-approval@1.0.0#approve");
+        let metadata = entry.metadata.as_ref().unwrap();
+        assert_eq!(metadata["rule_id"], "approve");
+        assert_eq!(metadata["connectives"], "minimum");
+        assert_eq!(
+            metadata["atoms"]["credit_ok"],
+            generate_judgment_id(&atoms["credit_ok"]).unwrap()
+        );
+        assert_eq!(metadata["atoms"].as_object().unwrap().len(), 3);
+
+        // Seal covers the atoms in name order
+        let inputs = [
+            &atoms["credit_ok"],
+            &atoms["identity_ok"],
+            &atoms["manual_override"],
+        ];
+        assert!(verify_conformance_seal_with_inputs(&approve, &inputs, &[1.0; 3]).unwrap());
+    }
+
+    #[test]
+    fn test_rules_reference_earlier_rules_and_families_differ() {
+        let rules = loan_rules(NormFamily::Minimum);
+        let atoms = atoms();
+        assert_eq!(
+            rules.required_atoms("escalate").unwrap(),
+            ["credit_ok", "fraud_flag", "identity_ok", "manual_override"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+
+        let results = rules.evaluate_all(&atoms).unwrap();
+        let escalate = &results["escalate"];
+        let metadata = escalate
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .clone()
+            .unwrap();
+        assert_eq!(
+            metadata["atoms"]["approve"],
+            serde_json::json!(results["approve"].judgment_id)
+        );
+        // NOT approve = (0.1, 0.0, 0.7), NOT fraud_flag = (0.8, 0.1, 0.1)
+        assert_eq!((escalate.t, escalate.f), (0.1, 0.7));
+
+        let product = loan_rules(NormFamily::Product)
+            .evaluate("approve", &atoms)
+            .unwrap();
+        assert!((product.t - 0.63).abs() < 1e-12);
+        assert_ne!(product.judgment_id, results["approve"].judgment_id);
+
+        let mut missing = atoms.clone();
+        missing.remove("credit_ok");
+        assert!(matches!(
+            rules.evaluate("approve", &missing),
+            Err(OpenTrustError::MissingAtom { ref atom, .. }) if atom == "credit_ok"
+        ));
+        assert!(matches!(
+            rules.evaluate("deny", &atoms),
+            Err(OpenTrustError::RuleNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_json_round_trip_and_loading_alongside_mappers() {
+        let rules = loan_rules(NormFamily::Lukasiewicz);
+        let json = serde_json::to_value(&rules).unwrap();
+        assert_eq!(json["connectives"], "lukasiewicz");
+        assert_eq!(
+            serde_json::from_value::<RuleSet>(json.clone()).unwrap(),
+            rules
+        );
+
+        let dir = std::env::temp_dir().join(format!("otp-rules-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("combined.json"),
+            serde_json::json!({"mappers": [], "rule_sets": [json]}).to_string(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("ssl.json"),
+            r#"{"mapper_type": "boolean", "params": {}}"#,
+        )
+        .unwrap();
+        assert_eq!(load_rule_set_dir(&dir).unwrap(), vec![rules.clone()]);
+        assert_eq!(
+            load_rule_set_file(dir.join("combined.json")).unwrap(),
+            vec![rules]
+        );
+
+        fs::write(
+            dir.join("bad.json"),
+            r#"{"id": "s", "version": "1.0.0", "rules": [{"id": "r", "expression": "a AND"}]}"#,
+        )
+        .unwrap();
+        match load_rule_set_dir(&dir).unwrap_err() {
+            ConfigError::Invalid { issues } => {
+                assert_eq!(issues.len(), 1);
+                assert!(issues[0].file.ends_with("bad.json"));
+                assert_eq!(issues[0].field_path, "rules[0]");
+            }
+            other => panic!("Expected Invalid error, got {:?}", other),
+        }
+        assert!(load_rule_set_file(dir.join("ssl.json")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,477 @@
+//! JSON Schemas for the OpenTrust Protocol wire formats
+//!
+//! The schemas describe the JSON produced by this crate's `Serialize`
+//! implementations, so producers written in other languages can validate their
+//! payloads against the same contract. They are published as files in the
+//! `schemas/` directory of the repository; [`write_schema_files`] regenerates
+//! them.
+//!
+//! JSON Schema cannot express every rule: the conservation constraint
+//! `T + I + F <= 1` and the distinct numerical mapper points are still checked
+//! in Rust by [`NeutrosophicJudgment::validate`](crate::NeutrosophicJudgment)
+//! and [`MapperValidator`](crate::MapperValidator).
+
+use jsonschema::JSONSchema;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+const DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+const BASE_ID: &str = "https://opentrustprotocol.com/schemas/";
+
+/// A single schema violation, located by a JSON pointer into the instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// JSON pointer (RFC 6901) to the offending value; empty for the root
+    pub instance_path: String,
+    /// The schema keyword that failed, e.g. `required` or `maximum`
+    pub keyword: String,
+    /// Human-readable description of the violation
+    pub message: String,
+}
+
+/// A compiled schema ready to validate JSON values
+pub struct CompiledSchema {
+    schema: Value,
+    compiled: JSONSchema,
+}
+
+impl CompiledSchema {
+    /// Compile a schema document
+    ///
+    /// # Panics
+    ///
+    /// Panics if the document is not a valid JSON Schema. The schemas built by
+    /// this module always compile.
+    pub fn compile(schema: Value) -> Self {
+        let compiled = JSONSchema::compile(&schema).expect("built-in schemas are valid");
+        Self { schema, compiled }
+    }
+
+    /// The schema document
+    pub fn schema(&self) -> &Value {
+        &self.schema
+    }
+
+    /// Validate a value, returning every violation found
+    pub fn validate(&self, instance: &Value) -> Vec<SchemaViolation> {
+        match self.compiled.validate(instance) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .map(|error| SchemaViolation {
+                    instance_path: error.instance_path.to_string(),
+                    keyword: error
+                        .schema_path
+                        .to_string()
+                        .rsplit('/')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    message: error.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Schema for a `{T, I, F}` triple as used in mapper definitions
+fn judgment_data_definition() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "T": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "I": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "F": {"type": "number", "minimum": 0.0, "maximum": 1.0}
+        },
+        "required": ["T", "I", "F"],
+        "additionalProperties": false
+    })
+}
+
+fn provenance_entry_definition() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "source_id": {"type": "string", "pattern": "\\S"},
+            "timestamp": {"type": "string"},
+            "description": {"type": ["string", "null"]},
+            "metadata": {},
+            "conformance_seal": {"type": ["string", "null"], "pattern": "^[0-9a-f]{64}$"}
+        },
+        "required": ["source_id", "timestamp"]
+    })
+}
+
+/// Schema for a [`ProvenanceEntry`](crate::ProvenanceEntry)
+pub fn provenance_entry_schema() -> Value {
+    let mut schema = provenance_entry_definition();
+    add_header(&mut schema, "provenance-entry", "ProvenanceEntry");
+    schema
+}
+
+/// Schema for a [`NeutrosophicJudgment`](crate::NeutrosophicJudgment)
+pub fn neutrosophic_judgment_schema() -> Value {
+    let mut schema = json!({
+        "description": "T, I and F must also satisfy T + I + F <= 1",
+        "type": "object",
+        "properties": {
+            "judgment_id": {"type": "string"},
+            "t": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "i": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "f": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "provenance_chain": {
+                "type": "array",
+                "items": {"$ref": "#/definitions/ProvenanceEntry"},
+                "minItems": 1
+            }
+        },
+        "required": ["t", "i", "f", "provenance_chain"],
+        "definitions": {"ProvenanceEntry": provenance_entry_definition()}
+    });
+    add_header(&mut schema, "neutrosophic-judgment", "NeutrosophicJudgment");
+    schema
+}
+
+/// Schema for an [`OutcomeJudgment`](crate::OutcomeJudgment)
+pub fn outcome_judgment_schema() -> Value {
+    let mut schema = json!({
+        "description": "T, I and F must also satisfy T + I + F <= 1",
+        "type": "object",
+        "properties": {
+            "judgment_id": {"type": "string"},
+            "links_to_judgment_id": {"type": "string", "minLength": 1},
+            "t": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "i": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "f": {"type": "number", "minimum": 0.0, "maximum": 1.0},
+            "outcome_type": {"enum": ["Success", "Failure", "Partial"]},
+            "oracle_source": {"type": "string", "minLength": 1},
+            "provenance_chain": {
+                "type": "array",
+                "items": {"$ref": "#/definitions/ProvenanceEntry"}
+            }
+        },
+        "required": [
+            "judgment_id", "links_to_judgment_id", "t", "i", "f",
+            "outcome_type", "oracle_source", "provenance_chain"
+        ],
+        "definitions": {"ProvenanceEntry": provenance_entry_definition()}
+    });
+    add_header(&mut schema, "outcome-judgment", "OutcomeJudgment");
+    schema
+}
+
+/// Wrap variant-specific parameter properties into the tagged mapper layout
+///
+/// `MapperParams` serializes as `{"mapper_type": "<variant>", "params": {...}}`
+/// where the params repeat the type as `"Numerical"`, `"Categorical"`,
+/// `"Boolean"` or `"Calibration"`.
+fn mapper_definition(tag: &str, type_name: &str, properties: Value, required: &[&str]) -> Value {
+    let mut params_properties = json!({
+        "id": {"type": "string", "minLength": 1},
+        "version": {
+            "type": "string",
+            "pattern": "^(0|[1-9][0-9]*)\\.(0|[1-9][0-9]*)\\.(0|[1-9][0-9]*)(-[0-9A-Za-z.-]+)?(\\+[0-9A-Za-z.-]+)?$"
+        },
+        "mapper_type": {"const": type_name},
+        "description": {"type": ["string", "null"]},
+        "metadata": {"type": ["object", "null"]},
+        "input_path": {"type": ["string", "null"], "pattern": "^(/.*)?$"},
+        "deprecated": {"type": "boolean"}
+    });
+    if let (Some(target), Value::Object(extra)) = (params_properties.as_object_mut(), properties) {
+        target.extend(extra);
+    }
+
+    let mut params_required = vec!["id", "version", "mapper_type"];
+    params_required.extend_from_slice(required);
+
+    json!({
+        "type": "object",
+        "properties": {
+            "mapper_type": {"const": tag},
+            "params": {
+                "type": "object",
+                "properties": params_properties,
+                "required": params_required
+            }
+        },
+        "required": ["mapper_type", "params"]
+    })
+}
+
+fn numerical_mapper_definition() -> Value {
+    mapper_definition(
+        "numerical",
+        "Numerical",
+        json!({
+            "falsity_point": {"type": "number"},
+            "indeterminacy_point": {"type": "number"},
+            "truth_point": {"type": "number"},
+            "clamp_to_range": {"type": ["boolean", "null"]}
+        }),
+        &["falsity_point", "indeterminacy_point", "truth_point"],
+    )
+}
+
+fn categorical_mapper_definition() -> Value {
+    mapper_definition(
+        "categorical",
+        "Categorical",
+        json!({
+            "mappings": {
+                "type": "object",
+                "minProperties": 1,
+                "additionalProperties": {"$ref": "#/definitions/JudgmentData"}
+            },
+            "default_judgment": {
+                "oneOf": [{"$ref": "#/definitions/JudgmentData"}, {"type": "null"}]
+            }
+        }),
+        &["mappings"],
+    )
+}
+
+fn boolean_mapper_definition() -> Value {
+    mapper_definition(
+        "boolean",
+        "Boolean",
+        json!({
+            "true_map": {"$ref": "#/definitions/JudgmentData"},
+            "false_map": {"$ref": "#/definitions/JudgmentData"}
+        }),
+        &["true_map", "false_map"],
+    )
+}
+
+fn calibration_mapper_definition() -> Value {
+    let number = json!({"type": "number"});
+    let probability = json!({"type": "number", "minimum": 0.0, "maximum": 1.0});
+    mapper_definition(
+        "calibration",
+        "Calibration",
+        json!({
+            "curve": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "method": {"const": "isotonic"},
+                            "points": {
+                                "type": "array",
+                                "minItems": 1,
+                                "items": {
+                                    "type": "object",
+                                    "properties": {"score": number, "probability": probability},
+                                    "required": ["score", "probability"]
+                                }
+                            }
+                        },
+                        "required": ["method", "points"]
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "method": {"const": "platt"},
+                            "a": number,
+                            "b": number
+                        },
+                        "required": ["method", "a", "b"]
+                    }
+                ]
+            },
+            "support": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "lower": number,
+                        "upper": number,
+                        "count": {"type": "integer", "minimum": 0}
+                    },
+                    "required": ["lower", "upper", "count"]
+                }
+            },
+            "prior_weight": {"type": "number", "exclusiveMinimum": 0.0},
+            "sample_count": {"type": "integer", "minimum": 1},
+            "training_data_hash": {"type": "string", "pattern": "^[0-9a-f]{64}$"}
+        }),
+        &[
+            "curve",
+            "support",
+            "prior_weight",
+            "sample_count",
+            "training_data_hash",
+        ],
+    )
+}
+
+fn with_judgment_data(mut schema: Value) -> Value {
+    schema["definitions"] = json!({"JudgmentData": judgment_data_definition()});
+    schema
+}
+
+/// Schema for a numerical mapper definition (`MapperParams::Numerical`)
+pub fn numerical_mapper_schema() -> Value {
+    let mut schema = numerical_mapper_definition();
+    add_header(&mut schema, "numerical-mapper", "NumericalMapper");
+    schema
+}
+
+/// Schema for a categorical mapper definition (`MapperParams::Categorical`)
+pub fn categorical_mapper_schema() -> Value {
+    let mut schema = with_judgment_data(categorical_mapper_definition());
+    add_header(&mut schema, "categorical-mapper", "CategoricalMapper");
+    schema
+}
+
+/// Schema for a boolean mapper definition (`MapperParams::Boolean`)
+pub fn boolean_mapper_schema() -> Value {
+    let mut schema = with_judgment_data(boolean_mapper_definition());
+    add_header(&mut schema, "boolean-mapper", "BooleanMapper");
+    schema
+}
+
+/// Schema for a calibration mapper definition (`MapperParams::Calibration`)
+pub fn calibration_mapper_schema() -> Value {
+    let mut schema = calibration_mapper_definition();
+    add_header(&mut schema, "calibration-mapper", "CalibrationMapper");
+    schema
+}
+
+/// Schema accepting any [`MapperParams`](crate::MapperParams) variant
+pub fn mapper_params_schema() -> Value {
+    let mut schema = with_judgment_data(json!({
+        "type": "object",
+        "properties": {
+            "mapper_type": {"enum": ["numerical", "categorical", "boolean", "calibration"]}
+        },
+        "required": ["mapper_type"],
+        "oneOf": [
+            numerical_mapper_definition(),
+            categorical_mapper_definition(),
+            boolean_mapper_definition(),
+            calibration_mapper_definition()
+        ]
+    }));
+    add_header(&mut schema, "mapper-params", "MapperParams");
+    schema
+}
+
+fn add_header(schema: &mut Value, name: &str, title: &str) {
+    if let Value::Object(object) = schema {
+        object.insert("$schema".to_string(), json!(DRAFT));
+        object.insert(
+            "$id".to_string(),
+            json!(format!("{}{}.schema.json", BASE_ID, name)),
+        );
+        object.insert("title".to_string(), json!(title));
+    }
+}
+
+/// Every published schema with its file name
+pub fn published_schemas() -> Vec<(&'static str, Value)> {
+    vec![
+        (
+            "neutrosophic-judgment.schema.json",
+            neutrosophic_judgment_schema(),
+        ),
+        ("outcome-judgment.schema.json", outcome_judgment_schema()),
+        ("provenance-entry.schema.json", provenance_entry_schema()),
+        ("mapper-params.schema.json", mapper_params_schema()),
+        ("numerical-mapper.schema.json", numerical_mapper_schema()),
+        (
+            "categorical-mapper.schema.json",
+            categorical_mapper_schema(),
+        ),
+        ("boolean-mapper.schema.json", boolean_mapper_schema()),
+        (
+            "calibration-mapper.schema.json",
+            calibration_mapper_schema(),
+        ),
+    ]
+}
+
+/// Render a schema as the pretty JSON written to the schema files
+pub fn schema_file_contents(schema: &Value) -> String {
+    let mut json = serde_json::to_string_pretty(schema).expect("JSON values serialize");
+    json.push('\n');
+    json
+}
+
+/// Write every published schema into `dir`, creating it if needed
+pub fn write_schema_files(dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (name, schema) in published_schemas() {
+        fs::write(dir.join(name), schema_file_contents(&schema))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::judgment_id::{OutcomeJudgment, OutcomeType};
+    use crate::NeutrosophicJudgment;
+
+    #[test]
+    fn test_published_schema_files_are_up_to_date() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas");
+        for (name, schema) in published_schemas() {
+            let on_disk = fs::read_to_string(dir.join(name)).unwrap_or_default();
+            assert_eq!(
+                on_disk,
+                schema_file_contents(&schema),
+                "schemas/{} is stale; regenerate with `cargo run --example export_schemas`",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialized_judgments_match_schemas() {
+        let judgment = NeutrosophicJudgment::new(
+            0.8,
+            0.1,
+            0.1,
+            vec![("sensor".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        let schema = CompiledSchema::compile(neutrosophic_judgment_schema());
+        assert!(schema
+            .validate(&serde_json::to_value(&judgment).unwrap())
+            .is_empty());
+
+        let outcome = OutcomeJudgment::new(
+            "a".repeat(64),
+            1.0,
+            0.0,
+            0.0,
+            OutcomeType::Success,
+            "oracle".to_string(),
+            judgment.provenance_chain.clone(),
+        )
+        .unwrap();
+        let schema = CompiledSchema::compile(outcome_judgment_schema());
+        assert!(schema
+            .validate(&serde_json::to_value(&outcome).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_violations_carry_json_pointers() {
+        let schema = CompiledSchema::compile(neutrosophic_judgment_schema());
+        let violations = schema.validate(&json!({
+            "t": 1.5,
+            "i": 0.0,
+            "f": 0.0,
+            "provenance_chain": [{"source_id": " ", "timestamp": "2023-01-01T00:00:00Z"}]
+        }));
+
+        let paths: Vec<&str> = violations
+            .iter()
+            .map(|v| v.instance_path.as_str())
+            .collect();
+        assert!(paths.contains(&"/t"));
+        assert!(paths.contains(&"/provenance_chain/0/source_id"));
+    }
+}
@@ -0,0 +1,608 @@
+//! Local HTTP service for mapping, fusion and verification
+//!
+//! [`Service`] answers REST/JSON requests against a [`MapperRegistry`] and an
+//! in-process store of judgments and outcomes; [`HttpServer`] serves it on a
+//! loopback address. The `otp-server` binary (feature `server`) wires the two
+//! together.
+//!
+//! | Method | Path                  | Body                                                      |
+//! |--------|-----------------------|-----------------------------------------------------------|
+//! | GET    | `/mappers`            |                                                           |
+//! | POST   | `/map/{mapper_id}`    | the raw input value                                       |
+//! | POST   | `/fuse/{operator}`    | `{"judgments": [...], "weights": [...]}`                  |
+//! | POST   | `/verify`             | `{"judgment": ..., "inputs": [...], "weights": [...]}`    |
+//! | POST   | `/judgments`          | a judgment to store                                       |
+//! | GET    | `/judgments/{id}`     |                                                           |
+//! | POST   | `/outcomes`           | an outcome (`links_to_judgment_id`, `t`, `i`, `f`, ...)   |
+//! | GET    | `/outcomes`           | optional query `?judgment_id=...`                         |
+//!
+//! Wherever a judgment is expected, a judgment ID string of a stored judgment
+//! may be given instead. Judgments produced by `/map` and `/fuse` are stored
+//! automatically. Judgments are stored under their recomputed judgment ID: a
+//! `judgment_id` that does not match the content is rejected with 400, and a
+//! stored judgment is never replaced. Errors are returned as
+//! `{"error": {"code": "...", "message": "..."}}`.
+
+use crate::conformance::{check_conformance_seal_with_inputs, recorded_seal_weights};
+use crate::error::OpenTrustError;
+use crate::fusion::fuse;
+use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::{generate_judgment_id, OutcomeJudgment, OutcomeType};
+use crate::mapper::types::MapperRegistry;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+/// A response produced by [`Service::handle`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// JSON body
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn created(body: Value) -> Self {
+        Self { status: 201, body }
+    }
+
+    fn error(status: u16, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({"error": {"code": code, "message": message.into()}}),
+        }
+    }
+
+    fn from_error(error: &OpenTrustError) -> Self {
+        let (status, code) = match error {
+            OpenTrustError::MapperNotFound { .. } => (404, "mapper_not_found"),
+            OpenTrustError::UnknownOperator { .. } => (404, "unknown_operator"),
+            OpenTrustError::InputType { .. } => (422, "input_type"),
+            OpenTrustError::UnknownCategory { .. } => (422, "unknown_category"),
+            OpenTrustError::Input(_) => (422, "invalid_input"),
+            OpenTrustError::ConservationViolation { .. } => (422, "conservation_violation"),
+            OpenTrustError::InvalidValue { .. } => (422, "invalid_value"),
+            OpenTrustError::EmptyProvenanceChain
+            | OpenTrustError::InvalidProvenanceEntry { .. } => (422, "invalid_provenance"),
+            OpenTrustError::MissingSeal => (422, "missing_seal"),
+            OpenTrustError::Deserialization { .. } => (400, "invalid_json"),
+            OpenTrustError::InvalidFusionInput { .. }
+            | OpenTrustError::EmptyInput
+            | OpenTrustError::WeightsLengthMismatch { .. }
+            | OpenTrustError::AllWeightsZero => (422, "invalid_fusion_input"),
+            _ => (500, "internal_error"),
+        };
+        Self::error(status, code, error.to_string())
+    }
+}
+
+/// In-process store of judgments (by judgment ID) and outcomes
+#[derive(Debug, Default)]
+struct Store {
+    judgments: HashMap<String, NeutrosophicJudgment>,
+    outcomes: Vec<OutcomeJudgment>,
+}
+
+/// A judgment given inline or by the ID of a stored judgment
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JudgmentRef {
+    Id(String),
+    Inline(Box<NeutrosophicJudgment>),
+}
+
+#[derive(Deserialize)]
+struct FuseRequest {
+    judgments: Vec<JudgmentRef>,
+    weights: Option<Vec<f64>>,
+}
+
+#[derive(Deserialize)]
+struct VerifyRequest {
+    judgment: JudgmentRef,
+    inputs: Vec<JudgmentRef>,
+    weights: Option<Vec<f64>>,
+}
+
+#[derive(Deserialize)]
+struct OutcomeRequest {
+    links_to_judgment_id: String,
+    t: f64,
+    i: f64,
+    f: f64,
+    outcome_type: OutcomeType,
+    oracle_source: String,
+    #[serde(default)]
+    provenance_chain: Vec<ProvenanceEntry>,
+}
+
+/// Request handler backed by a mapper registry and an in-process store
+pub struct Service {
+    registry: Arc<dyn MapperRegistry>,
+    store: Mutex<Store>,
+}
+
+impl Service {
+    /// Create a service with an empty store
+    pub fn new(registry: Arc<dyn MapperRegistry>) -> Self {
+        Self {
+            registry,
+            store: Mutex::new(Store::default()),
+        }
+    }
+
+    /// Handle one request
+    ///
+    /// `target` is the request path, optionally followed by a query string.
+    pub fn handle(&self, method: &str, target: &str, body: &[u8]) -> Response {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["mappers"]) => Response::ok(json!(self.registry.list())),
+            ("POST", ["map", mapper_id]) => self.map(mapper_id, body),
+            ("POST", ["fuse", operator]) => self.fuse(operator, body),
+            ("POST", ["verify"]) => self.verify(body),
+            ("POST", ["judgments"]) => self.store_judgment(body),
+            ("GET", ["judgments", id]) => self.get_judgment(id),
+            ("POST", ["outcomes"]) => self.record_outcome(body),
+            ("GET", ["outcomes"]) => self.list_outcomes(query),
+            (_, ["mappers"] | ["map", _] | ["fuse", _] | ["verify"] | ["judgments", ..])
+            | (_, ["outcomes"]) => Response::error(405, "method_not_allowed", "Method not allowed"),
+            _ => Response::error(404, "not_found", format!("No route for {}", path)),
+        }
+    }
+
+    fn map(&self, mapper_id: &str, body: &[u8]) -> Response {
+        let input: Value = match parse_body(body) {
+            Ok(input) => input,
+            Err(response) => return response,
+        };
+        match self.registry.apply(mapper_id, &input) {
+            Ok(judgment) => self.store_and_respond(judgment),
+            Err(e) => Response::from_error(&e),
+        }
+    }
+
+    fn fuse(&self, operator: &str, body: &[u8]) -> Response {
+        let request: FuseRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(response) => return response,
+        };
+        let judgments = match self.resolve_all(request.judgments) {
+            Ok(judgments) => judgments,
+            Err(response) => return response,
+        };
+        let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+
+        match fuse(operator, &inputs, request.weights.as_deref()) {
+            Ok(fused) => self.store_and_respond(fused),
+            Err(e) => Response::from_error(&e),
+        }
+    }
+
+    fn verify(&self, body: &[u8]) -> Response {
+        let request: VerifyRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(response) => return response,
+        };
+        let judgment = match self.resolve(request.judgment) {
+            Ok(judgment) => judgment,
+            Err(response) => return response,
+        };
+        let judgments = match self.resolve_all(request.inputs) {
+            Ok(judgments) => judgments,
+            Err(response) => return response,
+        };
+        let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+
+        let weights = match request.weights.or_else(|| recorded_seal_weights(&judgment)) {
+            Some(weights) => weights,
+            None => {
+                return Response::error(
+                    422,
+                    "missing_weights",
+                    "No weights given and none recorded in the fusion metadata",
+                )
+            }
+        };
+
+        match check_conformance_seal_with_inputs(&judgment, &inputs, &weights) {
+            Ok(()) => Response::ok(json!({"valid": true})),
+            Err(OpenTrustError::SealMismatch {
+                operator_id,
+                stored,
+                computed,
+            }) => Response::ok(json!({
+                "valid": false,
+                "operator_id": operator_id,
+                "stored_seal": stored,
+                "computed_seal": computed,
+            })),
+            Err(e) => Response::from_error(&e),
+        }
+    }
+
+    fn store_judgment(&self, body: &[u8]) -> Response {
+        let judgment: NeutrosophicJudgment = match parse_body(body) {
+            Ok(judgment) => judgment,
+            Err(response) => return response,
+        };
+        // Run the constructor checks before accepting the judgment
+        if let Err(e) = NeutrosophicJudgment::new_with_entries(
+            judgment.t,
+            judgment.i,
+            judgment.f,
+            judgment.provenance_chain.clone(),
+        ) {
+            return Response::from_error(&e);
+        }
+        let mut response = self.store_and_respond(judgment);
+        if response.status == 200 {
+            response.status = 201;
+        }
+        response
+    }
+
+    fn get_judgment(&self, id: &str) -> Response {
+        match self.store.lock().unwrap().judgments.get(id) {
+            Some(judgment) => Response::ok(json!(judgment)),
+            None => Response::error(
+                404,
+                "judgment_not_found",
+                format!("No judgment stored with ID '{}'", id),
+            ),
+        }
+    }
+
+    fn record_outcome(&self, body: &[u8]) -> Response {
+        let request: OutcomeRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(response) => return response,
+        };
+        let outcome = OutcomeJudgment::new(
+            request.links_to_judgment_id,
+            request.t,
+            request.i,
+            request.f,
+            request.outcome_type,
+            request.oracle_source,
+            request.provenance_chain,
+        );
+        match outcome {
+            Ok(outcome) => {
+                let body = json!(outcome);
+                self.store.lock().unwrap().outcomes.push(outcome);
+                Response::created(body)
+            }
+            Err(e) => Response::from_error(&e),
+        }
+    }
+
+    fn list_outcomes(&self, query: &str) -> Response {
+        let judgment_id = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == "judgment_id")
+            .map(|(_, value)| value);
+
+        let store = self.store.lock().unwrap();
+        let outcomes: Vec<&OutcomeJudgment> = store
+            .outcomes
+            .iter()
+            .filter(|outcome| judgment_id.is_none_or(|id| outcome.links_to_judgment_id == id))
+            .collect();
+        Response::ok(json!(outcomes))
+    }
+
+    /// Store a judgment under its recomputed judgment ID
+    ///
+    /// A supplied ID that does not match the content is rejected; an
+    /// existing entry is never replaced.
+    fn store_and_respond(&self, mut judgment: NeutrosophicJudgment) -> Response {
+        let id = match generate_judgment_id(&judgment) {
+            Ok(id) => id,
+            Err(e) => return Response::from_error(&e),
+        };
+        if let Some(supplied) = judgment.judgment_id.as_deref() {
+            if supplied != id {
+                return Response::error(
+                    400,
+                    "judgment_id_mismatch",
+                    format!(
+                        "Supplied judgment ID '{}' does not match the computed ID '{}'",
+                        supplied, id
+                    ),
+                );
+            }
+        }
+        judgment.judgment_id = Some(id.clone());
+        let mut store = self.store.lock().unwrap();
+        Response::ok(json!(store.judgments.entry(id).or_insert(judgment)))
+    }
+
+    fn resolve(&self, reference: JudgmentRef) -> Result<NeutrosophicJudgment, Response> {
+        match reference {
+            JudgmentRef::Inline(judgment) => Ok(*judgment),
+            JudgmentRef::Id(id) => match self.store.lock().unwrap().judgments.get(&id) {
+                Some(judgment) => Ok(judgment.clone()),
+                None => Err(Response::error(
+                    404,
+                    "judgment_not_found",
+                    format!("No judgment stored with ID '{}'", id),
+                )),
+            },
+        }
+    }
+
+    fn resolve_all(
+        &self,
+        references: Vec<JudgmentRef>,
+    ) -> Result<Vec<NeutrosophicJudgment>, Response> {
+        references
+            .into_iter()
+            .map(|reference| self.resolve(reference))
+            .collect()
+    }
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, Response> {
+    serde_json::from_slice(body).map_err(|e| Response::error(400, "invalid_json", e.to_string()))
+}
+
+/// HTTP server for a [`Service`], bound to a loopback address
+pub struct HttpServer {
+    server: tiny_http::Server,
+    service: Arc<Service>,
+}
+
+impl HttpServer {
+    /// Bind to `addr`, which must be a loopback address such as `127.0.0.1:8787`
+    ///
+    /// Port 0 picks a free port; see [`local_addr`](Self::local_addr).
+    pub fn bind(addr: SocketAddr, service: Arc<Service>) -> crate::Result<Self> {
+        if !addr.ip().is_loopback() {
+            return Err(OpenTrustError::io(
+                addr.to_string(),
+                std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "refusing to listen on a non-loopback address",
+                ),
+            ));
+        }
+        let server = tiny_http::Server::http(addr).map_err(|e| {
+            OpenTrustError::io(
+                addr.to_string(),
+                std::io::Error::new(std::io::ErrorKind::AddrNotAvailable, e.to_string()),
+            )
+        })?;
+        Ok(Self { server, service })
+    }
+
+    /// The address the server is listening on
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Serve requests on `threads` worker threads until the process exits
+    pub fn run(self, threads: usize) {
+        let server = Arc::new(self.server);
+        let workers: Vec<_> = (0..threads.max(1))
+            .map(|_| {
+                let server = Arc::clone(&server);
+                let service = Arc::clone(&self.service);
+                std::thread::spawn(move || {
+                    while let Ok(request) = server.recv() {
+                        respond(&service, request);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn respond(service: &Service, mut request: tiny_http::Request) {
+    let mut body = Vec::new();
+    let response = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => service.handle(request.method().as_str(), request.url(), &body),
+        Err(e) => Response::error(400, "invalid_body", e.to_string()),
+    };
+
+    let content_type =
+        tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            .expect("static header is valid");
+    let http_response = tiny_http::Response::from_data(response.body.to_string())
+        .with_status_code(response.status)
+        .with_header(content_type);
+    let _ = request.respond(http_response);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapper::registry::DefaultMapperRegistry;
+    use crate::mapper::types::{
+        BaseMapperParams, BooleanParams, JudgmentData, MapperParams, MapperType,
+    };
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    fn service() -> Service {
+        let registry = DefaultMapperRegistry::new();
+        registry
+            .import(vec![MapperParams::Boolean(BooleanParams {
+                base: BaseMapperParams {
+                    id: "ssl".to_string(),
+                    version: "1.0.0".to_string(),
+                    mapper_type: MapperType::Boolean,
+                    description: None,
+                    metadata: None,
+                    input_path: None,
+                    deprecated: false,
+                },
+                true_map: JudgmentData {
+                    T: 0.9,
+                    I: 0.1,
+                    F: 0.0,
+                },
+                false_map: JudgmentData {
+                    T: 0.0,
+                    I: 0.2,
+                    F: 0.8,
+                },
+            })])
+            .unwrap();
+        Service::new(Arc::new(registry))
+    }
+
+    fn post(service: &Service, path: &str, body: Value) -> Response {
+        service.handle("POST", path, body.to_string().as_bytes())
+    }
+
+    #[test]
+    fn test_map_fuse_verify_and_lookup() {
+        let service = service();
+
+        let mapped_true = post(&service, "/map/ssl", json!(true));
+        assert_eq!(mapped_true.status, 200);
+        let mapped_false = post(&service, "/map/ssl@1.0.0", json!("no"));
+        let first_id = mapped_true.body["judgment_id"].as_str().unwrap();
+        let second_id = mapped_false.body["judgment_id"].as_str().unwrap();
+
+        let fused = post(
+            &service,
+            "/fuse/cawa",
+            json!({"judgments": [first_id, second_id], "weights": [0.7, 0.3]}),
+        );
+        assert_eq!(fused.status, 200);
+        let fused_id = fused.body["judgment_id"].as_str().unwrap();
+
+        let looked_up = service.handle("GET", &format!("/judgments/{}", fused_id), b"");
+        assert_eq!(looked_up.body, fused.body);
+
+        let verified = post(
+            &service,
+            "/verify",
+            json!({"judgment": fused_id, "inputs": [first_id, second_id]}),
+        );
+        assert_eq!(verified.body["valid"], true);
+
+        let tampered = post(
+            &service,
+            "/verify",
+            json!({"judgment": fused_id, "inputs": [first_id, second_id], "weights": [0.5, 0.5]}),
+        );
+        assert_eq!(tampered.body["valid"], false);
+    }
+
+    #[test]
+    fn test_store_rejects_forged_judgment_id() {
+        let service = service();
+        let mapped = post(&service, "/map/ssl", json!(true));
+        let mapped_id = mapped.body["judgment_id"].as_str().unwrap().to_string();
+
+        let mut forged = mapped.body.clone();
+        forged["t"] = json!(0.1);
+        let rejected = post(&service, "/judgments", forged);
+        assert_eq!(rejected.status, 400);
+        assert_eq!(rejected.body["error"]["code"], "judgment_id_mismatch");
+
+        let stored = service.handle("GET", &format!("/judgments/{}", mapped_id), b"");
+        assert_eq!(stored.body, mapped.body);
+
+        let resubmitted = post(&service, "/judgments", mapped.body.clone());
+        assert_eq!(resubmitted.status, 201);
+        assert_eq!(resubmitted.body, mapped.body);
+
+        let mut unidentified = mapped.body.clone();
+        unidentified.as_object_mut().unwrap().remove("judgment_id");
+        unidentified["t"] = json!(0.5);
+        let accepted = post(&service, "/judgments", unidentified);
+        assert_eq!(accepted.status, 201);
+        assert_ne!(accepted.body["judgment_id"], mapped.body["judgment_id"]);
+    }
+
+    #[test]
+    fn test_outcomes_and_errors() {
+        let service = service();
+
+        let recorded = post(
+            &service,
+            "/outcomes",
+            json!({
+                "links_to_judgment_id": "abc",
+                "t": 1.0, "i": 0.0, "f": 0.0,
+                "outcome_type": "Success",
+                "oracle_source": "settlement-oracle"
+            }),
+        );
+        assert_eq!(recorded.status, 201);
+        post(
+            &service,
+            "/outcomes",
+            json!({
+                "links_to_judgment_id": "def",
+                "t": 0.0, "i": 0.0, "f": 1.0,
+                "outcome_type": "Failure",
+                "oracle_source": "settlement-oracle"
+            }),
+        );
+
+        let listed = service.handle("GET", "/outcomes?judgment_id=abc", b"");
+        assert_eq!(listed.body.as_array().unwrap().len(), 1);
+        assert_eq!(
+            service
+                .handle("GET", "/outcomes", b"")
+                .body
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let missing = post(&service, "/map/unknown", json!(true));
+        assert_eq!(missing.status, 404);
+        assert_eq!(missing.body["error"]["code"], "mapper_not_found");
+
+        let bad_operator = post(&service, "/fuse/median", json!({"judgments": []}));
+        assert_eq!(bad_operator.body["error"]["code"], "unknown_operator");
+
+        let bad_json = service.handle("POST", "/verify", b"{");
+        assert_eq!(bad_json.status, 400);
+
+        assert_eq!(service.handle("DELETE", "/verify", b"").status, 405);
+        assert_eq!(service.handle("GET", "/nothing", b"").status, 404);
+    }
+
+    #[test]
+    fn test_http_round_trip() {
+        let server = HttpServer::bind("127.0.0.1:0".parse().unwrap(), Arc::new(service())).unwrap();
+        let addr = server.local_addr().unwrap();
+        std::thread::spawn(move || server.run(1));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /map/ssl HTTP/1.1\r\nHost: localhost\r\nContent-Length: 4\r\nConnection: close\r\n\r\ntrue"
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("application/json"));
+        assert!(response.contains("\"judgment_id\""));
+
+        assert!(HttpServer::bind("0.0.0.0:0".parse().unwrap(), Arc::new(service())).is_err());
+    }
+}
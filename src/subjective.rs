@@ -0,0 +1,519 @@
+//! Subjective Logic interoperability
+//!
+//! Jøsang's subjective logic describes a binomial opinion by belief `b`,
+//! disbelief `d`, uncertainty `u` (with b + d + u = 1) and a base rate `a`,
+//! the prior probability used when nothing is known. An [`Opinion`] maps to
+//! a [`NeutrosophicJudgment`] as
+//!
+//! ```text
+//! T = b,  F = d,  I = u        (opinion → judgment)
+//! b = T,  d = F,  u = 1 - T - F  (judgment → opinion)
+//! ```
+//!
+//! Opinion → judgment → opinion is lossless: the base rate, which a
+//! judgment has no place for, is recorded in the conversion's provenance
+//! entry and recovered by [`Opinion::from_judgment`]. Judgment → opinion is
+//! lossless when T + I + F = 1; otherwise the unassigned mass joins the
+//! uncertainty, since subjective logic does not distinguish it from I.
+//!
+//! Opinions support the cumulative and averaging fusion operators and the
+//! projected probability P = b + a·u. [`subjective_cumulative_fusion`] and
+//! [`subjective_averaging_fusion`] apply them to judgments, with a seal and
+//! the base rate in the fusion entry:
+//!
+//! ```rust
+//! use opentrustprotocol::Opinion;
+//!
+//! let partner = Opinion::new(0.6, 0.1, 0.3, 0.5)?;
+//! let judgment = partner.to_judgment("partner-feed")?;
+//! assert_eq!((judgment.t, judgment.i, judgment.f), (0.6, 0.3, 0.1));
+//!
+//! let back = Opinion::from_judgment(&judgment)?;
+//! assert_eq!(back, partner);
+//! assert!((back.projected_probability() - 0.75).abs() < 1e-12);
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::conformance::{create_fusion_provenance_entry, generate_conformance_seal};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{validate_inputs, FUSION_METADATA_VERSION};
+use crate::judgment::{NeutrosophicJudgment, ProvenanceEntry};
+use crate::judgment_id::ensure_judgment_id;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Conversion ID recorded by [`Opinion::to_judgment`]
+pub const OPINION_CONVERSION_ID: &str = "otp-sl-opinion-v1";
+/// Operator ID recorded by [`subjective_cumulative_fusion`]
+pub const SL_CUMULATIVE_OPERATOR_ID: &str = "otp-sl-cumulative-v1";
+/// Operator ID recorded by [`subjective_averaging_fusion`]
+pub const SL_AVERAGING_OPERATOR_ID: &str = "otp-sl-averaging-v1";
+
+/// Base rate used when a judgment carries none (no prior preference)
+pub const DEFAULT_BASE_RATE: f64 = 0.5;
+
+/// Tolerance for b + d + u = 1 and for treating an uncertainty as zero
+const EPSILON: f64 = 1e-9;
+
+/// A binomial subjective opinion (b, d, u, a)
+///
+/// Serializes as `{"belief", "disbelief", "uncertainty", "base_rate"}`;
+/// deserializing checks the components.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "OpinionData")]
+pub struct Opinion {
+    belief: f64,
+    disbelief: f64,
+    uncertainty: f64,
+    base_rate: f64,
+}
+
+/// Unchecked wire form of [`Opinion`]
+#[derive(Deserialize)]
+struct OpinionData {
+    belief: f64,
+    disbelief: f64,
+    uncertainty: f64,
+    base_rate: f64,
+}
+
+impl TryFrom<OpinionData> for Opinion {
+    type Error = OpenTrustError;
+
+    fn try_from(data: OpinionData) -> Result<Self> {
+        Opinion::new(
+            data.belief,
+            data.disbelief,
+            data.uncertainty,
+            data.base_rate,
+        )
+    }
+}
+
+impl Opinion {
+    /// Create an opinion
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidValue` if a component is outside
+    /// [0, 1] or b + d + u differs from 1.
+    pub fn new(belief: f64, disbelief: f64, uncertainty: f64, base_rate: f64) -> Result<Self> {
+        for (field, value) in [
+            ("belief", belief),
+            ("disbelief", disbelief),
+            ("uncertainty", uncertainty),
+            ("base_rate", base_rate),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(OpenTrustError::InvalidValue {
+                    field: field.to_string(),
+                    value,
+                    message: "must be between 0.0 and 1.0".to_string(),
+                });
+            }
+        }
+        let sum = belief + disbelief + uncertainty;
+        if (sum - 1.0).abs() > EPSILON {
+            return Err(OpenTrustError::InvalidValue {
+                field: "belief + disbelief + uncertainty".to_string(),
+                value: sum,
+                message: "must equal 1.0".to_string(),
+            });
+        }
+        Ok(Self {
+            belief,
+            disbelief,
+            uncertainty,
+            base_rate,
+        })
+    }
+
+    /// The vacuous opinion (0, 0, 1, a): nothing is known
+    pub fn vacuous(base_rate: f64) -> Result<Self> {
+        Opinion::new(0.0, 0.0, 1.0, base_rate)
+    }
+
+    /// Belief (b)
+    pub fn belief(&self) -> f64 {
+        self.belief
+    }
+
+    /// Disbelief (d)
+    pub fn disbelief(&self) -> f64 {
+        self.disbelief
+    }
+
+    /// Uncertainty (u)
+    pub fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    /// Base rate (a)
+    pub fn base_rate(&self) -> f64 {
+        self.base_rate
+    }
+
+    /// Whether the opinion has no uncertainty
+    pub fn is_dogmatic(&self) -> bool {
+        self.uncertainty <= EPSILON
+    }
+
+    /// Projected probability P = b + a·u
+    pub fn projected_probability(&self) -> f64 {
+        self.belief + self.base_rate * self.uncertainty
+    }
+
+    /// Convert a judgment, with the base rate recorded in its provenance
+    ///
+    /// The base rate comes from the latest subjective-logic entry (a
+    /// conversion or fusion) in the chain, or is [`DEFAULT_BASE_RATE`].
+    pub fn from_judgment(judgment: &NeutrosophicJudgment) -> Result<Self> {
+        Opinion::from_judgment_with_base_rate(
+            judgment,
+            recorded_base_rate(judgment).unwrap_or(DEFAULT_BASE_RATE),
+        )
+    }
+
+    /// Convert a judgment with an explicit base rate
+    pub fn from_judgment_with_base_rate(
+        judgment: &NeutrosophicJudgment,
+        base_rate: f64,
+    ) -> Result<Self> {
+        // Use I itself when nothing is unassigned, so round trips are exact
+        let uncertainty = if (1.0 - judgment.t - judgment.i - judgment.f).abs() <= EPSILON {
+            judgment.i
+        } else {
+            (1.0 - judgment.t - judgment.f).max(0.0)
+        };
+        Opinion::new(judgment.t, judgment.f, uncertainty, base_rate)
+    }
+
+    /// Convert to a judgment from `source_id`
+    ///
+    /// The judgment has one provenance entry for `source_id` whose metadata
+    /// records `conversion` ([`OPINION_CONVERSION_ID`]), the four opinion
+    /// components and the projected probability. Its judgment ID is
+    /// assigned.
+    pub fn to_judgment(&self, source_id: &str) -> Result<NeutrosophicJudgment> {
+        let mut metadata = self.metadata();
+        metadata.insert("conversion".to_string(), OPINION_CONVERSION_ID.into());
+        let entry = ProvenanceEntry {
+            source_id: source_id.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            description: Some("Subjective logic opinion".to_string()),
+            metadata: Some(serde_json::Value::Object(metadata)),
+            conformance_seal: None,
+        };
+        let (t, i, f) = self.components();
+        ensure_judgment_id(NeutrosophicJudgment::new_with_entries(
+            t,
+            i,
+            f,
+            vec![entry],
+        )?)
+    }
+
+    /// Cumulative (aleatory) fusion: evidence from independent sources adds up
+    ///
+    /// Uncertainty shrinks with every source. Dogmatic opinions dominate
+    /// non-dogmatic ones and are averaged with each other.
+    pub fn cumulative_fuse(opinions: &[Opinion]) -> Result<Self> {
+        let (first, rest) =
+            opinions
+                .split_first()
+                .ok_or_else(|| OpenTrustError::InvalidFusionInput {
+                    message: "Cannot fuse an empty list of opinions".to_string(),
+                })?;
+        // Cumulative fusion is associative, so the pairwise operator folds
+        rest.iter().try_fold(*first, |a, b| a.cumulative_pair(b))
+    }
+
+    fn cumulative_pair(&self, other: &Opinion) -> Result<Self> {
+        let (ua, ub) = (self.uncertainty, other.uncertainty);
+        if self.is_dogmatic() && other.is_dogmatic() {
+            return Opinion::new(
+                (self.belief + other.belief) / 2.0,
+                (self.disbelief + other.disbelief) / 2.0,
+                0.0,
+                (self.base_rate + other.base_rate) / 2.0,
+            );
+        }
+        let k = ua + ub - ua * ub;
+        let belief = (self.belief * ub + other.belief * ua) / k;
+        let disbelief = (self.disbelief * ub + other.disbelief * ua) / k;
+        let base_rate = if (ua - 1.0).abs() <= EPSILON && (ub - 1.0).abs() <= EPSILON {
+            (self.base_rate + other.base_rate) / 2.0
+        } else {
+            (self.base_rate * ub + other.base_rate * ua
+                - (self.base_rate + other.base_rate) * ua * ub)
+                / (ua + ub - 2.0 * ua * ub)
+        };
+        Opinion::from_parts(belief, disbelief, base_rate)
+    }
+
+    /// Averaging (epistemic) fusion: dependent sources, such as several
+    /// views of the same evidence
+    ///
+    /// Uses the multi-source form, which unlike the pairwise operator does
+    /// not depend on order. If any opinion is dogmatic, the dogmatic
+    /// opinions are averaged.
+    pub fn averaging_fuse(opinions: &[Opinion]) -> Result<Self> {
+        if opinions.is_empty() {
+            return Err(OpenTrustError::InvalidFusionInput {
+                message: "Cannot fuse an empty list of opinions".to_string(),
+            });
+        }
+        let count = opinions.len() as f64;
+        let base_rate = opinions.iter().map(|o| o.base_rate).sum::<f64>() / count;
+
+        let dogmatic: Vec<&Opinion> = opinions.iter().filter(|o| o.is_dogmatic()).collect();
+        if !dogmatic.is_empty() {
+            let n = dogmatic.len() as f64;
+            let belief = dogmatic.iter().map(|o| o.belief).sum::<f64>() / n;
+            let disbelief = dogmatic.iter().map(|o| o.disbelief).sum::<f64>() / n;
+            return Opinion::from_parts(belief, disbelief, base_rate);
+        }
+
+        // Product of the other opinions' uncertainties, for each opinion
+        let others: Vec<f64> = (0..opinions.len())
+            .map(|index| {
+                opinions
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != index)
+                    .map(|(_, o)| o.uncertainty)
+                    .product()
+            })
+            .collect();
+        let denominator: f64 = others.iter().sum();
+        let weighted = |component: fn(&Opinion) -> f64| {
+            opinions
+                .iter()
+                .zip(&others)
+                .map(|(o, &weight)| component(o) * weight)
+                .sum::<f64>()
+                / denominator
+        };
+        Opinion::from_parts(weighted(|o| o.belief), weighted(|o| o.disbelief), base_rate)
+    }
+
+    /// Builds an opinion from b and d, deriving u and absorbing rounding
+    fn from_parts(belief: f64, disbelief: f64, base_rate: f64) -> Result<Self> {
+        let belief = belief.clamp(0.0, 1.0);
+        let disbelief = disbelief.clamp(0.0, 1.0 - belief);
+        Opinion::new(
+            belief,
+            disbelief,
+            (1.0 - belief - disbelief).max(0.0),
+            base_rate.clamp(0.0, 1.0),
+        )
+    }
+
+    /// (T, I, F), with I absorbing rounding so the sum stays within 1
+    fn components(&self) -> (f64, f64, f64) {
+        let i = self
+            .uncertainty
+            .min(1.0 - self.belief - self.disbelief)
+            .max(0.0);
+        (self.belief, i, self.disbelief)
+    }
+
+    fn metadata(&self) -> serde_json::Map<String, serde_json::Value> {
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("belief".to_string(), self.belief.into());
+        metadata.insert("disbelief".to_string(), self.disbelief.into());
+        metadata.insert("uncertainty".to_string(), self.uncertainty.into());
+        metadata.insert("base_rate".to_string(), self.base_rate.into());
+        metadata.insert(
+            "projected_probability".to_string(),
+            self.projected_probability().into(),
+        );
+        metadata
+    }
+}
+
+impl fmt::Display for Opinion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Opinion(b={:.3}, d={:.3}, u={:.3}, a={:.3})",
+            self.belief, self.disbelief, self.uncertainty, self.base_rate
+        )
+    }
+}
+
+/// Base rate recorded by the latest subjective-logic entry in the chain
+pub fn recorded_base_rate(judgment: &NeutrosophicJudgment) -> Option<f64> {
+    judgment.provenance_chain.iter().rev().find_map(|entry| {
+        let metadata = entry.metadata.as_ref()?;
+        let is_subjective = metadata.get("conversion").and_then(|v| v.as_str())
+            == Some(OPINION_CONVERSION_ID)
+            || matches!(
+                entry.source_id.as_str(),
+                SL_CUMULATIVE_OPERATOR_ID | SL_AVERAGING_OPERATOR_ID
+            );
+        if is_subjective {
+            metadata.get("base_rate")?.as_f64()
+        } else {
+            None
+        }
+    })
+}
+
+/// Fuse judgments with subjective-logic cumulative fusion
+///
+/// Each judgment is converted with `base_rate` (so I and any unassigned
+/// mass count as uncertainty), the opinions are fused with
+/// [`Opinion::cumulative_fuse`] and the result is converted back. The
+/// fusion entry is sealed over the inputs with weight 1.0 each and records
+/// the conversion, the base rate and the projected probability.
+pub fn subjective_cumulative_fusion(
+    judgments: &[&NeutrosophicJudgment],
+    base_rate: f64,
+) -> Result<NeutrosophicJudgment> {
+    subjective_fusion(
+        SL_CUMULATIVE_OPERATOR_ID,
+        judgments,
+        base_rate,
+        Opinion::cumulative_fuse,
+    )
+}
+
+/// Fuse judgments with subjective-logic averaging fusion
+///
+/// As [`subjective_cumulative_fusion`], with [`Opinion::averaging_fuse`].
+pub fn subjective_averaging_fusion(
+    judgments: &[&NeutrosophicJudgment],
+    base_rate: f64,
+) -> Result<NeutrosophicJudgment> {
+    subjective_fusion(
+        SL_AVERAGING_OPERATOR_ID,
+        judgments,
+        base_rate,
+        Opinion::averaging_fuse,
+    )
+}
+
+fn subjective_fusion(
+    operator: &str,
+    judgments: &[&NeutrosophicJudgment],
+    base_rate: f64,
+    fuse: fn(&[Opinion]) -> Result<Opinion>,
+) -> Result<NeutrosophicJudgment> {
+    validate_inputs(judgments, None)?;
+    let opinions = judgments
+        .iter()
+        .map(|judgment| Opinion::from_judgment_with_base_rate(judgment, base_rate))
+        .collect::<Result<Vec<_>>>()?;
+    let fused = fuse(&opinions)?;
+
+    let mut metadata = fused.metadata();
+    metadata.insert("operator".to_string(), operator.into());
+    metadata.insert("input_count".to_string(), judgments.len().into());
+    metadata.insert("weights".to_string(), serde_json::Value::Null);
+    metadata.insert("conversion".to_string(), "b=T, d=F, u=1-T-F".into());
+    metadata.insert("version".to_string(), FUSION_METADATA_VERSION.into());
+
+    let seal = generate_conformance_seal(judgments, &vec![1.0; judgments.len()], operator)?;
+    let mut provenance = Vec::new();
+    for judgment in judgments {
+        provenance.extend(judgment.provenance_chain.clone());
+    }
+    provenance.push(create_fusion_provenance_entry(
+        operator,
+        &chrono::Utc::now().to_rfc3339(),
+        &seal,
+        Some(format!(
+            "Fusion operation using {} with Conformance Seal",
+            operator
+        )),
+        Some(serde_json::Value::Object(metadata)),
+    ));
+
+    let (t, i, f) = fused.components();
+    ensure_judgment_id(NeutrosophicJudgment::new_with_entries(t, i, f, provenance)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+    use crate::judgment::test_support::judgment;
+
+    fn close(a: &Opinion, (b, d, u): (f64, f64, f64)) -> bool {
+        (a.belief - b).abs() < 1e-12
+            && (a.disbelief - d).abs() < 1e-12
+            && (a.uncertainty - u).abs() < 1e-12
+    }
+
+    #[test]
+    fn test_opinion_validation_and_round_trips() {
+        assert!(Opinion::new(0.5, 0.2, 0.2, 0.5).is_err());
+        assert!(Opinion::new(0.5, 0.5, 0.0, 1.5).is_err());
+        assert!(serde_json::from_str::<Opinion>(
+            r#"{"belief": 0.9, "disbelief": 0.9, "uncertainty": 0.0, "base_rate": 0.5}"#
+        )
+        .is_err());
+
+        let opinion = Opinion::new(0.2, 0.5, 0.3, 0.8).unwrap();
+        let converted = opinion.to_judgment("partner").unwrap();
+        let metadata = converted.provenance_chain[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata["conversion"], OPINION_CONVERSION_ID);
+        assert_eq!(metadata["base_rate"], 0.8);
+        assert_eq!(Opinion::from_judgment(&converted).unwrap(), opinion);
+        assert!((opinion.projected_probability() - 0.44).abs() < 1e-12);
+
+        // Unassigned mass joins the uncertainty; a plain judgment gets the default base rate
+        let partial = Opinion::from_judgment(&judgment(0.5, 0.2, 0.1, "sensor")).unwrap();
+        assert!(close(&partial, (0.5, 0.1, 0.4)));
+        assert_eq!(partial.base_rate(), DEFAULT_BASE_RATE);
+    }
+
+    #[test]
+    fn test_cumulative_and_averaging_fusion() {
+        let a = Opinion::new(0.6, 0.2, 0.2, 0.5).unwrap();
+        let b = Opinion::new(0.2, 0.4, 0.4, 0.5).unwrap();
+
+        // k = 0.2 + 0.4 - 0.08 = 0.52
+        let cumulative = Opinion::cumulative_fuse(&[a, b]).unwrap();
+        assert!(close(&cumulative, (0.28 / 0.52, 0.16 / 0.52, 0.08 / 0.52)));
+        assert!(cumulative.uncertainty() < a.uncertainty().min(b.uncertainty()));
+
+        // Denominator 0.4 + 0.2 = 0.6
+        let averaging = Opinion::averaging_fuse(&[a, b]).unwrap();
+        assert!(close(&averaging, (0.28 / 0.6, 0.16 / 0.6, 0.16 / 0.6)));
+
+        // Vacuous opinions are neutral for cumulative fusion
+        let vacuous = Opinion::vacuous(0.5).unwrap();
+        assert!(close(
+            &Opinion::cumulative_fuse(&[a, vacuous]).unwrap(),
+            (0.6, 0.2, 0.2)
+        ));
+
+        // Dogmatic opinions dominate
+        let dogmatic = Opinion::new(1.0, 0.0, 0.0, 0.5).unwrap();
+        assert!(close(
+            &Opinion::averaging_fuse(&[a, dogmatic]).unwrap(),
+            (1.0, 0.0, 0.0)
+        ));
+        assert!(Opinion::cumulative_fuse(&[]).is_err());
+    }
+
+    #[test]
+    fn test_judgment_fusion_is_sealed_and_records_base_rate() {
+        let a = judgment(0.6, 0.2, 0.2, "a");
+        let b = judgment(0.2, 0.4, 0.4, "b");
+
+        let fused = subjective_cumulative_fusion(&[&a, &b], 0.3).unwrap();
+        assert!((fused.t - 0.28 / 0.52).abs() < 1e-12);
+        assert!(fused.judgment_id.is_some());
+        let entry = fused.provenance_chain.last().unwrap();
+        assert_eq!(entry.source_id, SL_CUMULATIVE_OPERATOR_ID);
+        assert_eq!(entry.metadata.as_ref().unwrap()["base_rate"], 0.3);
+        assert_eq!(recorded_base_rate(&fused), Some(0.3));
+        assert!(verify_conformance_seal_with_inputs(&fused, &[&a, &b], &[1.0, 1.0]).unwrap());
+
+        let averaged = subjective_averaging_fusion(&[&a, &b], 0.5).unwrap();
+        assert!((averaged.i - 0.16 / 0.6).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,405 @@
+//! Sequential updating of a judgment with new evidence
+//!
+//! Fusion treats its inputs symmetrically. [`update`] instead moves a prior
+//! judgment towards new evidence, the way a long-lived trust score should
+//! evolve: indeterminacy shrinks as evidence accumulates.
+//!
+//! The model is the subjective-logic evidence mapping. A judgment stands
+//! for `r = W·T / u` positive and `s = W·F / u` negative observations, where
+//! `u = 1 - T - F` (indeterminacy plus any unassigned mass) and `W` is the
+//! prior weight of [`UpdateModel`]. Evidence adds `strength·T` positive and
+//! `strength·F` negative observations, so fully indeterminate evidence adds
+//! nothing. The posterior is
+//!
+//! ```text
+//! T = r / (r + s + W),  I = W / (r + s + W),  F = s / (r + s + W)
+//! ```
+//!
+//! [`UpdateModel::Decayed`] first multiplies the prior's observations by
+//! `0.5^(elapsed / half_life)`, where `elapsed` runs from the prior's
+//! `as_of` time to the evidence's latest provenance timestamp, so old
+//! evidence fades and indeterminacy grows back when nothing new arrives.
+//!
+//! Every update appends one sealed entry recording the model, both judgment
+//! IDs, the strength and the resulting `as_of` time:
+//!
+//! ```rust
+//! use opentrustprotocol::{update, NeutrosophicJudgment};
+//!
+//! let prior = NeutrosophicJudgment::new(0.4, 0.5, 0.1, vec![("history".to_string(), "2023-01-01T00:00:00Z".to_string())])?;
+//! let evidence = NeutrosophicJudgment::new(0.9, 0.1, 0.0, vec![("audit".to_string(), "2023-02-01T00:00:00Z".to_string())])?;
+//!
+//! let posterior = update(&prior, &evidence, 3.0)?;
+//! assert!(posterior.t > prior.t && posterior.i < prior.i);
+//! assert_eq!(posterior.provenance_chain.last().unwrap().source_id, "otp-update-count-v1");
+//! # Ok::<(), opentrustprotocol::OpenTrustError>(())
+//! ```
+
+use crate::conformance::{create_fusion_provenance_entry, generate_conformance_seal};
+use crate::error::{OpenTrustError, Result};
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::{ensure_judgment_id, generate_judgment_id};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+/// Operator ID recorded by [`UpdateModel::Count`]
+pub const COUNT_UPDATE_OPERATOR_ID: &str = "otp-update-count-v1";
+/// Operator ID recorded by [`UpdateModel::Decayed`]
+pub const DECAYED_UPDATE_OPERATOR_ID: &str = "otp-update-decayed-v1";
+
+/// Prior weight of the default model (the non-informative prior of
+/// subjective logic)
+pub const DEFAULT_PRIOR_WEIGHT: f64 = 2.0;
+
+/// How a prior is combined with new evidence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpdateModel {
+    /// Observations accumulate without bound
+    Count {
+        /// Weight of the non-informative prior (`W`)
+        prior_weight: f64,
+    },
+    /// The prior's observations halve every `half_life`
+    Decayed {
+        /// Weight of the non-informative prior (`W`)
+        prior_weight: f64,
+        /// Time after which the prior's observations count half
+        half_life: Duration,
+    },
+}
+
+impl Default for UpdateModel {
+    fn default() -> Self {
+        UpdateModel::Count {
+            prior_weight: DEFAULT_PRIOR_WEIGHT,
+        }
+    }
+}
+
+impl UpdateModel {
+    /// Count-based model with the given prior weight
+    pub fn count(prior_weight: f64) -> Self {
+        UpdateModel::Count { prior_weight }
+    }
+
+    /// Time-decayed model with the default prior weight
+    pub fn decayed(half_life: Duration) -> Self {
+        UpdateModel::Decayed {
+            prior_weight: DEFAULT_PRIOR_WEIGHT,
+            half_life,
+        }
+    }
+
+    /// Name recorded in provenance
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpdateModel::Count { .. } => "count",
+            UpdateModel::Decayed { .. } => "decayed",
+        }
+    }
+
+    /// Operator ID recorded in provenance
+    pub fn operator_id(&self) -> &'static str {
+        match self {
+            UpdateModel::Count { .. } => COUNT_UPDATE_OPERATOR_ID,
+            UpdateModel::Decayed { .. } => DECAYED_UPDATE_OPERATOR_ID,
+        }
+    }
+
+    fn prior_weight(&self) -> f64 {
+        match *self {
+            UpdateModel::Count { prior_weight } | UpdateModel::Decayed { prior_weight, .. } => {
+                prior_weight
+            }
+        }
+    }
+
+    /// Update `prior` with `evidence` observed `strength` times
+    ///
+    /// The result carries the prior's and the evidence's provenance followed
+    /// by one entry sealed over `[prior, evidence]` with weights
+    /// `[decay, strength]` (decay is 1 for the count model). Its metadata
+    /// holds the model, `prior_judgment_id`, `evidence_judgment_id`,
+    /// `strength`, `prior_weight`, the decay factor and `as_of`, the later of
+    /// the prior's and the evidence's times (omitted by the count model when
+    /// a timestamp is not RFC 3339). Its judgment ID is assigned.
+    ///
+    /// A dogmatic prior (T + F = 1) stands for unlimited observations and is
+    /// returned with its values unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenTrustError::InvalidValue` for a negative or non-finite
+    /// strength, a non-positive prior weight or half-life (in seconds), and
+    /// `OpenTrustError::InvalidProvenanceEntry` if the decayed model meets a
+    /// timestamp that is not RFC 3339.
+    pub fn update(
+        &self,
+        prior: &NeutrosophicJudgment,
+        evidence: &NeutrosophicJudgment,
+        strength: f64,
+    ) -> Result<NeutrosophicJudgment> {
+        let prior_weight = self.prior_weight();
+        if !strength.is_finite() || strength < 0.0 {
+            return Err(invalid(
+                "strength",
+                strength,
+                "Strength must be finite and non-negative",
+            ));
+        }
+        if !prior_weight.is_finite() || prior_weight <= 0.0 {
+            return Err(invalid(
+                "prior_weight",
+                prior_weight,
+                "Prior weight must be finite and positive",
+            ));
+        }
+
+        // Times only matter to the decayed model; the count model records
+        // `as_of` when the timestamps allow it
+        let times = match *self {
+            UpdateModel::Count { .. } => as_of(prior)
+                .and_then(|p| Ok((p, latest_timestamp(evidence)?)))
+                .ok(),
+            UpdateModel::Decayed { .. } => Some((as_of(prior)?, latest_timestamp(evidence)?)),
+        };
+        let decay = match (*self, times) {
+            (UpdateModel::Decayed { half_life, .. }, Some((prior_time, evidence_time))) => {
+                if half_life <= Duration::zero() {
+                    return Err(invalid(
+                        "half_life",
+                        half_life.num_milliseconds() as f64 / 1000.0,
+                        "Half-life must be positive",
+                    ));
+                }
+                let elapsed = (evidence_time - prior_time).max(Duration::zero());
+                0.5_f64
+                    .powf(elapsed.num_milliseconds() as f64 / half_life.num_milliseconds() as f64)
+            }
+            _ => 1.0,
+        };
+
+        let uncertainty = 1.0 - prior.t - prior.f;
+        let (t, i, f) = if uncertainty <= f64::EPSILON {
+            (prior.t, prior.i, prior.f)
+        } else {
+            let r = decay * prior_weight * prior.t / uncertainty + strength * evidence.t;
+            let s = decay * prior_weight * prior.f / uncertainty + strength * evidence.f;
+            let total = r + s + prior_weight;
+            let (t, f) = (r / total, s / total);
+            (t, (1.0 - t - f).max(0.0), f)
+        };
+
+        let operator = self.operator_id();
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("operator".to_string(), operator.into());
+        metadata.insert("model".to_string(), self.name().into());
+        metadata.insert("prior_judgment_id".to_string(), judgment_id(prior)?.into());
+        metadata.insert(
+            "evidence_judgment_id".to_string(),
+            judgment_id(evidence)?.into(),
+        );
+        metadata.insert("strength".to_string(), strength.into());
+        metadata.insert("prior_weight".to_string(), prior_weight.into());
+        if let UpdateModel::Decayed { half_life, .. } = self {
+            metadata.insert(
+                "half_life_seconds".to_string(),
+                half_life.num_seconds().into(),
+            );
+        }
+        metadata.insert("decay".to_string(), decay.into());
+        if let Some((prior_time, evidence_time)) = times {
+            metadata.insert(
+                "as_of".to_string(),
+                prior_time
+                    .max(evidence_time)
+                    .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                    .into(),
+            );
+        }
+
+        let seal = generate_conformance_seal(&[prior, evidence], &[decay, strength], operator)?;
+        let entry = create_fusion_provenance_entry(
+            operator,
+            &Utc::now().to_rfc3339(),
+            &seal,
+            Some(format!("Evidence update using {}", operator)),
+            Some(serde_json::Value::Object(metadata)),
+        );
+
+        let mut provenance = prior.provenance_chain.clone();
+        provenance.extend(evidence.provenance_chain.clone());
+        provenance.push(entry);
+
+        ensure_judgment_id(NeutrosophicJudgment::new_with_entries(t, i, f, provenance)?)
+    }
+}
+
+/// Update `prior` with `evidence` observed `strength` times, using the
+/// default count-based model
+///
+/// See [`UpdateModel::update`].
+pub fn update(
+    prior: &NeutrosophicJudgment,
+    evidence: &NeutrosophicJudgment,
+    strength: f64,
+) -> Result<NeutrosophicJudgment> {
+    UpdateModel::default().update(prior, evidence, strength)
+}
+
+fn invalid(field: &str, value: f64, message: &str) -> OpenTrustError {
+    OpenTrustError::InvalidValue {
+        field: field.to_string(),
+        value,
+        message: message.to_string(),
+    }
+}
+
+fn judgment_id(judgment: &NeutrosophicJudgment) -> Result<String> {
+    match &judgment.judgment_id {
+        Some(id) => Ok(id.clone()),
+        None => generate_judgment_id(judgment),
+    }
+}
+
+fn parse_timestamp(index: usize, timestamp: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|e| OpenTrustError::InvalidProvenanceEntry {
+            index,
+            message: format!("timestamp '{}' is not RFC 3339: {}", timestamp, e),
+        })
+}
+
+/// Latest timestamp in the judgment's provenance chain
+fn latest_timestamp(judgment: &NeutrosophicJudgment) -> Result<DateTime<Utc>> {
+    let mut latest = None;
+    for (index, entry) in judgment.provenance_chain.iter().enumerate() {
+        let time = parse_timestamp(index, &entry.timestamp)?;
+        latest = latest.max(Some(time));
+    }
+    latest.ok_or(OpenTrustError::EmptyProvenanceChain)
+}
+
+/// Time a judgment reflects: the `as_of` of its last update, or else its
+/// latest provenance timestamp
+fn as_of(judgment: &NeutrosophicJudgment) -> Result<DateTime<Utc>> {
+    let last = judgment.provenance_chain.len().saturating_sub(1);
+    let recorded = judgment
+        .provenance_chain
+        .last()
+        .filter(|entry| {
+            matches!(
+                entry.source_id.as_str(),
+                COUNT_UPDATE_OPERATOR_ID | DECAYED_UPDATE_OPERATOR_ID
+            )
+        })
+        .and_then(|entry| entry.metadata.as_ref())
+        .and_then(|metadata| metadata.get("as_of"))
+        .and_then(|as_of| as_of.as_str());
+    match recorded {
+        Some(timestamp) => parse_timestamp(last, timestamp),
+        None => latest_timestamp(judgment),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::verify_conformance_seal_with_inputs;
+    use crate::judgment::test_support::judgment_at;
+
+    #[test]
+    fn test_count_update_accumulates_evidence() {
+        let prior = judgment_at(0.0, 1.0, 0.0, "prior", "2023-01-01T00:00:00Z");
+        let positive = judgment_at(1.0, 0.0, 0.0, "check", "2023-01-02T00:00:00Z");
+
+        // 8 positive observations on a vacuous prior: (8/10, 2/10, 0)
+        let posterior = update(&prior, &positive, 8.0).unwrap();
+        assert!((posterior.t - 0.8).abs() < 1e-12);
+        assert!((posterior.i - 0.2).abs() < 1e-12);
+        assert_eq!(posterior.f, 0.0);
+
+        // Sequential updates equal one combined update
+        let once = update(&update(&prior, &positive, 3.0).unwrap(), &positive, 5.0).unwrap();
+        assert!((once.t - posterior.t).abs() < 1e-12);
+
+        // Indeterminacy shrinks with every update; indeterminate evidence adds nothing
+        let negative = judgment_at(0.0, 0.0, 1.0, "check", "2023-01-03T00:00:00Z");
+        let next = update(&posterior, &negative, 2.0).unwrap();
+        assert!(next.i < posterior.i && next.f > 0.0);
+        let unknown = judgment_at(0.0, 1.0, 0.0, "check", "2023-01-03T00:00:00Z");
+        let same = update(&posterior, &unknown, 10.0).unwrap();
+        assert!((same.t - posterior.t).abs() < 1e-12);
+
+        assert!(matches!(
+            update(&prior, &positive, -1.0),
+            Err(OpenTrustError::InvalidValue { ref field, .. }) if field == "strength"
+        ));
+        assert!(matches!(
+            UpdateModel::count(0.0).update(&prior, &positive, 1.0),
+            Err(OpenTrustError::InvalidValue { ref field, .. }) if field == "prior_weight"
+        ));
+    }
+
+    #[test]
+    fn test_update_is_sealed_and_links_inputs() {
+        let prior = judgment_at(0.4, 0.5, 0.1, "history", "2023-01-01T00:00:00Z");
+        let evidence = judgment_at(0.9, 0.1, 0.0, "audit", "2023-02-01T00:00:00Z");
+        let posterior = update(&prior, &evidence, 3.0).unwrap();
+
+        assert!(posterior.judgment_id.is_some());
+        assert_eq!(posterior.provenance_chain.len(), 3);
+        let metadata = posterior.provenance_chain[2].metadata.as_ref().unwrap();
+        assert_eq!(metadata["model"], "count");
+        assert_eq!(
+            metadata["prior_judgment_id"],
+            generate_judgment_id(&prior).unwrap()
+        );
+        assert_eq!(
+            metadata["evidence_judgment_id"],
+            generate_judgment_id(&evidence).unwrap()
+        );
+        assert_eq!(metadata["as_of"], "2023-02-01T00:00:00Z");
+        assert!(
+            verify_conformance_seal_with_inputs(&posterior, &[&prior, &evidence], &[1.0, 3.0])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decayed_update_fades_old_evidence() {
+        let prior = judgment_at(0.8, 0.2, 0.0, "history", "2023-01-01T00:00:00Z");
+        let model = UpdateModel::decayed(Duration::days(30));
+        let soon = judgment_at(0.0, 1.0, 0.0, "ping", "2023-01-01T00:00:00Z");
+        let later = judgment_at(0.0, 1.0, 0.0, "ping", "2023-01-31T00:00:00Z");
+
+        // No time passed: same as the count model
+        let fresh = model.update(&prior, &soon, 1.0).unwrap();
+        assert!((fresh.t - 0.8).abs() < 1e-12);
+
+        // One half-life: the prior's 8 observations count as 4
+        let faded = model.update(&prior, &later, 1.0).unwrap();
+        assert!((faded.t - 4.0 / 6.0).abs() < 1e-12);
+        assert!(faded.i > prior.i);
+        let metadata = faded
+            .provenance_chain
+            .last()
+            .unwrap()
+            .metadata
+            .clone()
+            .unwrap();
+        assert_eq!(metadata["decay"], 0.5);
+        assert_eq!(metadata["half_life_seconds"], 30 * 24 * 3600);
+
+        // The next update measures elapsed time from the recorded as_of
+        let again = model.update(&faded, &later, 1.0).unwrap();
+        assert!((again.t - faded.t).abs() < 1e-12);
+
+        let bad = judgment_at(0.5, 0.5, 0.0, "ping", "yesterday");
+        assert!(matches!(
+            model.update(&prior, &bad, 1.0),
+            Err(OpenTrustError::InvalidProvenanceEntry { .. })
+        ));
+        assert!(update(&prior, &bad, 1.0).is_ok());
+    }
+}
@@ -0,0 +1,336 @@
+//! Validated value types
+//!
+//! [`NeutrosophicJudgment`] keeps public fields for compatibility, so a
+//! validated judgment can later be mutated into an invalid one and
+//! deserializing it checks nothing. The types here enforce their invariants
+//! for their whole lifetime:
+//!
+//! - [`Tif`] is a (T, I, F) triple with each component in [0, 1] and
+//!   T + I + F <= 1.
+//! - [`Judgment`] is a judgment whose components form a valid [`Tif`] and
+//!   whose provenance chain is non-empty with a source ID and timestamp on
+//!   every entry.
+//!
+//! Both have private fields, checked constructors, and a `Deserialize` that
+//! runs the same checks, so invalid judgments arriving over the wire are
+//! rejected at parse time. They serialize exactly like their unvalidated
+//! counterparts, and [`Judgment`] dereferences to a read-only
+//! [`NeutrosophicJudgment`] so it can be passed to fusion, seal and ID
+//! functions directly.
+//!
+//! ```rust
+//! use opentrustprotocol::{conflict_aware_weighted_average, Judgment, Tif};
+//!
+//! let a: Judgment = serde_json::from_str(
+//!     r#"{"t": 0.8, "i": 0.2, "f": 0.0,
+//!         "provenance_chain": [{"source_id": "sensor1", "timestamp": "2023-01-01T00:00:00Z"}]}"#,
+//! )?;
+//! let b = Judgment::from_source(Tif::new(0.6, 0.3, 0.1)?, "sensor2", "2023-01-01T00:00:00Z")?;
+//!
+//! let fused = Judgment::try_from(conflict_aware_weighted_average(&[&a, &b], &[0.6, 0.4])?)?;
+//! assert!(fused.tif().t() > 0.6);
+//!
+//! // T + I + F = 1.3 is rejected while parsing
+//! assert!(serde_json::from_str::<Tif>(r#"{"t": 0.5, "i": 0.5, "f": 0.3}"#).is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::error::{OpenTrustError, Result};
+use crate::judgment::{
+    validate_components, validate_provenance, NeutrosophicJudgment, ProvenanceEntry,
+};
+use crate::judgment_id::ensure_judgment_id;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+
+/// A validated (T, I, F) triple
+///
+/// Each component is in [0, 1] and T + I + F <= 1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "TifData")]
+pub struct Tif {
+    t: f64,
+    i: f64,
+    f: f64,
+}
+
+/// Unchecked wire form of [`Tif`]
+#[derive(Deserialize)]
+struct TifData {
+    t: f64,
+    i: f64,
+    f: f64,
+}
+
+impl TryFrom<TifData> for Tif {
+    type Error = OpenTrustError;
+
+    fn try_from(data: TifData) -> Result<Self> {
+        Tif::new(data.t, data.i, data.f)
+    }
+}
+
+impl Tif {
+    /// Create a triple, checking ranges and the conservation constraint
+    pub fn new(t: f64, i: f64, f: f64) -> Result<Self> {
+        validate_components(t, i, f)?;
+        Ok(Self { t, i, f })
+    }
+
+    /// Truth degree
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Indeterminacy degree
+    pub fn i(&self) -> f64 {
+        self.i
+    }
+
+    /// Falsity degree
+    pub fn f(&self) -> f64 {
+        self.f
+    }
+
+    /// T + I + F
+    pub fn sum(&self) -> f64 {
+        self.t + self.i + self.f
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for Tif {
+    type Error = OpenTrustError;
+
+    fn try_from((t, i, f): (f64, f64, f64)) -> Result<Self> {
+        Tif::new(t, i, f)
+    }
+}
+
+impl From<Tif> for (f64, f64, f64) {
+    fn from(tif: Tif) -> Self {
+        (tif.t, tif.i, tif.f)
+    }
+}
+
+impl fmt::Display for Tif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(T={:.3}, I={:.3}, F={:.3})", self.t, self.i, self.f)
+    }
+}
+
+/// A judgment whose invariants are checked on construction and deserialization
+///
+/// Dereferences to a read-only [`NeutrosophicJudgment`]; there is no mutable
+/// access, so a `Judgment` stays valid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "NeutrosophicJudgment", into = "NeutrosophicJudgment")]
+pub struct Judgment {
+    inner: NeutrosophicJudgment,
+}
+
+impl Judgment {
+    /// Create a judgment from a validated triple and a provenance chain
+    pub fn new(tif: Tif, provenance_chain: Vec<ProvenanceEntry>) -> Result<Self> {
+        validate_provenance(&provenance_chain)?;
+        Ok(Self {
+            inner: NeutrosophicJudgment {
+                judgment_id: None,
+                t: tif.t,
+                i: tif.i,
+                f: tif.f,
+                provenance_chain,
+            },
+        })
+    }
+
+    /// Create a judgment with a single provenance entry
+    pub fn from_source(
+        tif: Tif,
+        source_id: impl Into<String>,
+        timestamp: impl Into<String>,
+    ) -> Result<Self> {
+        Self::new(
+            tif,
+            vec![ProvenanceEntry::new(source_id.into(), timestamp.into())],
+        )
+    }
+
+    /// The (T, I, F) components
+    pub fn tif(&self) -> Tif {
+        Tif {
+            t: self.inner.t,
+            i: self.inner.i,
+            f: self.inner.f,
+        }
+    }
+
+    /// The provenance chain
+    pub fn provenance_chain(&self) -> &[ProvenanceEntry] {
+        &self.inner.provenance_chain
+    }
+
+    /// The stored judgment ID, if one was assigned
+    pub fn judgment_id(&self) -> Option<&str> {
+        self.inner.judgment_id.as_deref()
+    }
+
+    /// Assign the judgment ID if it is missing
+    pub fn with_id(self) -> Result<Self> {
+        Ok(Self {
+            inner: ensure_judgment_id(self.inner)?,
+        })
+    }
+
+    /// Borrow as an unvalidated [`NeutrosophicJudgment`]
+    pub fn as_neutrosophic(&self) -> &NeutrosophicJudgment {
+        &self.inner
+    }
+
+    /// Convert into an unvalidated [`NeutrosophicJudgment`]
+    pub fn into_inner(self) -> NeutrosophicJudgment {
+        self.inner
+    }
+}
+
+impl TryFrom<NeutrosophicJudgment> for Judgment {
+    type Error = OpenTrustError;
+
+    fn try_from(judgment: NeutrosophicJudgment) -> Result<Self> {
+        judgment.validate()?;
+        Ok(Self { inner: judgment })
+    }
+}
+
+impl From<Judgment> for NeutrosophicJudgment {
+    fn from(judgment: Judgment) -> Self {
+        judgment.inner
+    }
+}
+
+impl Deref for Judgment {
+    type Target = NeutrosophicJudgment;
+
+    fn deref(&self) -> &NeutrosophicJudgment {
+        &self.inner
+    }
+}
+
+impl AsRef<NeutrosophicJudgment> for Judgment {
+    fn as_ref(&self) -> &NeutrosophicJudgment {
+        &self.inner
+    }
+}
+
+impl fmt::Display for Judgment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::judgment_id::{generate_judgment_id, OutcomeJudgment, OutcomeType};
+
+    const SENSOR: &str = r#"{"source_id": "sensor1", "timestamp": "2023-01-01T00:00:00Z"}"#;
+
+    fn judgment_json(t: f64, i: f64, f: f64, chain: &str) -> String {
+        format!(
+            r#"{{"t": {}, "i": {}, "f": {}, "provenance_chain": [{}]}}"#,
+            t, i, f, chain
+        )
+    }
+
+    #[test]
+    fn test_tif_checks_ranges_and_conservation() {
+        let tif = Tif::new(0.6, 0.3, 0.1).unwrap();
+        assert_eq!(<(f64, f64, f64)>::from(tif), (0.6, 0.3, 0.1));
+        assert!(matches!(
+            Tif::new(1.2, 0.0, 0.0),
+            Err(OpenTrustError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            Tif::try_from((0.5, 0.5, 0.3)),
+            Err(OpenTrustError::ConservationViolation { .. })
+        ));
+        assert!(Tif::new(f64::NAN, 0.0, 0.0).is_err());
+
+        let parsed: Tif = serde_json::from_str(r#"{"t": 0.6, "i": 0.3, "f": 0.1}"#).unwrap();
+        assert_eq!(parsed, tif);
+        assert_eq!(
+            serde_json::to_value(tif).unwrap(),
+            serde_json::json!({"t": 0.6, "i": 0.3, "f": 0.1})
+        );
+        let error = serde_json::from_str::<Tif>(r#"{"t": 0.5, "i": 0.5, "f": 0.3}"#).unwrap_err();
+        assert!(error.to_string().contains("Conservation"));
+    }
+
+    #[test]
+    fn test_judgment_rejects_invalid_input_at_parse_time() {
+        let valid: Judgment = serde_json::from_str(&judgment_json(0.8, 0.2, 0.0, SENSOR)).unwrap();
+        assert_eq!(valid.tif().t(), 0.8);
+        assert_eq!(valid.provenance_chain().len(), 1);
+
+        assert!(serde_json::from_str::<Judgment>(&judgment_json(0.8, 0.5, 0.0, SENSOR)).is_err());
+        assert!(serde_json::from_str::<Judgment>(&judgment_json(0.8, 0.2, 0.0, "")).is_err());
+        let blank_source = r#"{"source_id": " ", "timestamp": "2023-01-01T00:00:00Z"}"#;
+        assert!(
+            serde_json::from_str::<Judgment>(&judgment_json(0.8, 0.2, 0.0, blank_source)).is_err()
+        );
+
+        // The unvalidated type accepts it; conversion rejects it
+        let unchecked: NeutrosophicJudgment =
+            serde_json::from_str(&judgment_json(0.8, 0.5, 0.0, SENSOR)).unwrap();
+        assert!(!unchecked.is_valid());
+        assert!(Judgment::try_from(unchecked).is_err());
+    }
+
+    #[test]
+    fn test_judgment_round_trips_and_keeps_ids() {
+        let judgment = Judgment::from_source(
+            Tif::new(0.8, 0.2, 0.0).unwrap(),
+            "sensor1",
+            "2023-01-01T00:00:00Z",
+        )
+        .unwrap()
+        .with_id()
+        .unwrap();
+        let plain = NeutrosophicJudgment::new(
+            0.8,
+            0.2,
+            0.0,
+            vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+
+        assert_eq!(
+            judgment.judgment_id(),
+            Some(generate_judgment_id(&plain).unwrap().as_str())
+        );
+        let json = serde_json::to_string(&judgment).unwrap();
+        assert_eq!(
+            json,
+            serde_json::to_string(judgment.as_neutrosophic()).unwrap()
+        );
+        assert_eq!(serde_json::from_str::<Judgment>(&json).unwrap(), judgment);
+    }
+
+    #[test]
+    fn test_outcome_to_judgment_validates() {
+        let outcome = OutcomeJudgment::new(
+            "a".repeat(64),
+            1.0,
+            0.0,
+            0.0,
+            OutcomeType::Success,
+            "oracle".to_string(),
+            vec![],
+        )
+        .unwrap();
+        assert!(matches!(
+            outcome.to_judgment(),
+            Err(OpenTrustError::EmptyProvenanceChain)
+        ));
+    }
+}
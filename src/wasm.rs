@@ -0,0 +1,246 @@
+//! JavaScript bindings (feature `wasm`)
+//!
+//! Lets a browser verify judgment IDs and conformance seals itself instead of
+//! trusting a backend. Build the `cdylib` with `cargo rustc --lib --release
+//! --target wasm32-unknown-unknown --features wasm --crate-type cdylib` and
+//! run `wasm-bindgen` over the resulting `.wasm`.
+//!
+//! Judgments cross the boundary as JSON text, not JavaScript objects:
+//! `JSON.parse` turns `1.0` into `1`, which changes the canonical form that IDs
+//! and seals are computed over. Pass the JSON exactly as received and parse it
+//! on the JavaScript side only for display.
+//!
+//! ```js
+//! import init, { verifyConformanceSealWithInputs } from "opentrustprotocol";
+//!
+//! await init();
+//! const ok = verifyConformanceSealWithInputs(fusedJson, inputsJson);
+//! ```
+
+use crate::conformance::{recorded_seal_weights, verify_conformance_seal_with_inputs};
+use crate::error::{OpenTrustError, Result};
+use crate::fusion::{
+    conflict_aware_weighted_average, fuse as fuse_by_operator, optimistic_fusion,
+    pessimistic_fusion,
+};
+use crate::judgment::NeutrosophicJudgment;
+use crate::judgment_id::{ensure_judgment_id, generate_judgment_id as judgment_id};
+use crate::mapper::registry::{build_mapper, DefaultMapperRegistry};
+use crate::mapper::types::{MapperParams, MapperRegistry as _};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+fn parse_judgment(json: &str) -> Result<NeutrosophicJudgment> {
+    NeutrosophicJudgment::from_json(json)
+}
+
+fn parse_judgments(json: &str) -> Result<Vec<NeutrosophicJudgment>> {
+    serde_json::from_str(json).map_err(|e| OpenTrustError::deserialization("judgment array", e))
+}
+
+/// Parse one mapper configuration or an array of them
+fn parse_configs(json: &str) -> Result<Vec<MapperParams>> {
+    let deserialize = |e| OpenTrustError::deserialization("mapper configuration", e);
+    match serde_json::from_str(json).map_err(deserialize)? {
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| serde_json::from_value(item).map_err(deserialize))
+            .collect(),
+        value => Ok(vec![serde_json::from_value(value).map_err(deserialize)?]),
+    }
+}
+
+fn to_json(judgment: &NeutrosophicJudgment) -> Result<String> {
+    serde_json::to_string(judgment).map_err(|e| OpenTrustError::serialization("judgment", e))
+}
+
+fn input_value(input: JsValue) -> std::result::Result<Value, JsError> {
+    serde_wasm_bindgen::from_value(input).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Compute the judgment ID of a judgment given as JSON
+#[wasm_bindgen(js_name = generateJudgmentId)]
+pub fn generate_judgment_id(judgment_json: &str) -> std::result::Result<String, JsError> {
+    Ok(judgment_id(&parse_judgment(judgment_json)?)?)
+}
+
+/// Return the judgment JSON with its `judgment_id` set
+#[wasm_bindgen(js_name = assignJudgmentId)]
+pub fn assign_judgment_id(judgment_json: &str) -> std::result::Result<String, JsError> {
+    Ok(to_json(&ensure_judgment_id(parse_judgment(
+        judgment_json,
+    )?)?)?)
+}
+
+/// Verify a fused judgment's conformance seal against its inputs (a JSON array)
+///
+/// Without `weights`, the weights recorded in the fusion metadata are used.
+#[wasm_bindgen(js_name = verifyConformanceSealWithInputs)]
+pub fn verify_seal_with_inputs(
+    fused_json: &str,
+    inputs_json: &str,
+    weights: Option<Vec<f64>>,
+) -> std::result::Result<bool, JsError> {
+    let fused = parse_judgment(fused_json)?;
+    let judgments = parse_judgments(inputs_json)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    let weights = match weights.or_else(|| recorded_seal_weights(&fused)) {
+        Some(weights) => weights,
+        None => {
+            return Err(JsError::new(
+                "no weights given and none recorded in the fusion metadata",
+            ))
+        }
+    };
+    Ok(verify_conformance_seal_with_inputs(
+        &fused, &inputs, &weights,
+    )?)
+}
+
+/// Conflict-aware weighted average of a JSON array of judgments
+#[wasm_bindgen(js_name = conflictAwareWeightedAverage)]
+pub fn cawa(judgments_json: &str, weights: Vec<f64>) -> std::result::Result<String, JsError> {
+    let judgments = parse_judgments(judgments_json)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    Ok(to_json(&conflict_aware_weighted_average(
+        &inputs, &weights,
+    )?)?)
+}
+
+/// Optimistic fusion of a JSON array of judgments
+#[wasm_bindgen(js_name = optimisticFusion)]
+pub fn optimistic(judgments_json: &str) -> std::result::Result<String, JsError> {
+    let judgments = parse_judgments(judgments_json)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    Ok(to_json(&optimistic_fusion(&inputs)?)?)
+}
+
+/// Pessimistic fusion of a JSON array of judgments
+#[wasm_bindgen(js_name = pessimisticFusion)]
+pub fn pessimistic(judgments_json: &str) -> std::result::Result<String, JsError> {
+    let judgments = parse_judgments(judgments_json)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    Ok(to_json(&pessimistic_fusion(&inputs)?)?)
+}
+
+/// Fuse with an operator given by short name (`cawa`, `optimistic`,
+/// `pessimistic`) or full operator ID
+#[wasm_bindgen]
+pub fn fuse(
+    operator: &str,
+    judgments_json: &str,
+    weights: Option<Vec<f64>>,
+) -> std::result::Result<String, JsError> {
+    let judgments = parse_judgments(judgments_json)?;
+    let inputs: Vec<&NeutrosophicJudgment> = judgments.iter().collect();
+    Ok(to_json(&fuse_by_operator(
+        operator,
+        &inputs,
+        weights.as_deref(),
+    )?)?)
+}
+
+/// Apply a single mapper configuration (JSON) to a raw input value
+#[wasm_bindgen(js_name = applyMapper)]
+pub fn apply_mapper(config_json: &str, input: JsValue) -> std::result::Result<String, JsError> {
+    let config = serde_json::from_str(config_json)
+        .map_err(|e| OpenTrustError::deserialization("mapper configuration", e))?;
+    let mapper = build_mapper(config)?;
+    Ok(to_json(&mapper.apply(&input_value(input)?)?)?)
+}
+
+/// A mapper registry owned by JavaScript
+#[wasm_bindgen(js_name = MapperRegistry)]
+pub struct WasmMapperRegistry {
+    inner: DefaultMapperRegistry,
+}
+
+#[wasm_bindgen(js_class = MapperRegistry)]
+impl WasmMapperRegistry {
+    /// Create an empty registry
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: DefaultMapperRegistry::new(),
+        }
+    }
+
+    /// Register one mapper configuration or a JSON array of them
+    pub fn register(&self, configs_json: &str) -> std::result::Result<(), JsError> {
+        Ok(self.inner.import(parse_configs(configs_json)?)?)
+    }
+
+    /// Apply the mapper `id` (or `id@version`) to a raw input value
+    pub fn apply(&self, id: &str, input: JsValue) -> std::result::Result<String, JsError> {
+        Ok(to_json(&self.inner.apply(id, &input_value(input)?)?)?)
+    }
+
+    /// IDs of the registered mappers
+    pub fn list(&self) -> Vec<String> {
+        self.inner.list()
+    }
+
+    /// Registered versions of a mapper ID, oldest first
+    pub fn versions(&self, id: &str) -> Vec<String> {
+        self.inner.versions(id)
+    }
+}
+
+impl Default for WasmMapperRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs_json() -> String {
+        let a = NeutrosophicJudgment::new(
+            0.8,
+            0.2,
+            0.0,
+            vec![("sensor1".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        let b = NeutrosophicJudgment::new(
+            0.6,
+            0.3,
+            0.1,
+            vec![("sensor2".to_string(), "2023-01-01T00:00:00Z".to_string())],
+        )
+        .unwrap();
+        serde_json::to_string(&[a, b]).unwrap()
+    }
+
+    // Only success paths run natively; errors construct a JavaScript `Error`
+    // and are covered by the headless tests in tests/wasm.rs.
+    #[test]
+    fn test_json_round_trip_keeps_seals_valid() {
+        let inputs = inputs_json();
+        let fused = cawa(&inputs, vec![0.6, 0.4]).unwrap();
+        assert!(verify_seal_with_inputs(&fused, &inputs, None).unwrap());
+        assert!(!verify_seal_with_inputs(&fused, &inputs, Some(vec![0.5, 0.5])).unwrap());
+
+        let optimistic = fuse("optimistic", &inputs, None).unwrap();
+        assert!(verify_seal_with_inputs(&optimistic, &inputs, None).unwrap());
+
+        let with_id = assign_judgment_id(&fused).unwrap();
+        let id = generate_judgment_id(&fused).unwrap();
+        assert!(with_id.contains(&id));
+        assert_eq!(generate_judgment_id(&with_id).unwrap(), id);
+    }
+
+    #[test]
+    fn test_registry_accepts_single_config_or_array() {
+        let registry = WasmMapperRegistry::new();
+        let config = r#"{"mapper_type":"boolean","params":{"id":"ssl","version":"1.0.0","mapper_type":"Boolean","true_map":{"T":0.9,"I":0.1,"F":0.0},"false_map":{"T":0.0,"I":0.0,"F":1.0}}}"#;
+        registry.register(config).unwrap();
+        registry
+            .register(&format!("[{}]", config.replace("1.0.0", "1.1.0")))
+            .unwrap();
+        assert_eq!(registry.list(), vec!["ssl".to_string()]);
+        assert_eq!(registry.versions("ssl"), vec!["1.0.0", "1.1.0"]);
+    }
+}
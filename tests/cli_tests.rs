@@ -0,0 +1,96 @@
+//! End-to-end tests for the `otp` command-line tool
+
+#![cfg(feature = "cli")]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+const INPUTS: &str = r#"{"t":0.8,"i":0.2,"f":0.0,"provenance_chain":[{"source_id":"sensor1","timestamp":"2023-01-01T00:00:00Z"}]}
+{"t":0.6,"i":0.3,"f":0.1,"provenance_chain":[{"source_id":"sensor2","timestamp":"2023-01-01T00:00:00Z"}]}
+"#;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("otp-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn otp(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_otp"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_fuse_then_verify_and_id() {
+    let dir = temp_dir("fuse");
+    let inputs = dir.join("inputs.jsonl");
+    fs::write(&inputs, INPUTS).unwrap();
+    let inputs = inputs.to_str().unwrap();
+
+    let fused = otp(&["fuse", "--weights", "0.6,0.4", inputs]);
+    assert!(fused.status.success());
+    let fused_path = dir.join("fused.json");
+    fs::write(&fused_path, &fused.stdout).unwrap();
+    let fused_path = fused_path.to_str().unwrap();
+
+    // Weights are taken from the fusion metadata by default
+    let verified = otp(&["verify", fused_path, "--inputs", inputs]);
+    assert_eq!(verified.status.code(), Some(0));
+
+    let tampered = otp(&[
+        "verify",
+        fused_path,
+        "--inputs",
+        inputs,
+        "--weights",
+        "0.5,0.5",
+    ]);
+    assert_eq!(tampered.status.code(), Some(1));
+
+    let ids = otp(&["id", fused_path]);
+    assert_eq!(ids.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&ids.stdout)
+        .trim_end()
+        .ends_with("ok"));
+
+    let inspected = otp(&["inspect", fused_path]);
+    assert!(inspected.status.success());
+    assert!(String::from_utf8_lossy(&inspected.stdout).contains("└─ otp-cawa-v1.1"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_map_values_through_config() {
+    let dir = temp_dir("map");
+    let config = dir.join("ssl.json");
+    fs::write(
+        &config,
+        r#"{"mapper_type":"boolean","params":{"id":"ssl","version":"1.0.0","mapper_type":"Boolean",
+            "true_map":{"T":0.9,"I":0.1,"F":0.0},"false_map":{"T":0.0,"I":0.0,"F":1.0}}}"#,
+    )
+    .unwrap();
+
+    let mapped = otp(&["map", "--config", config.to_str().unwrap(), "true", "false"]);
+    assert!(mapped.status.success());
+    let lines: Vec<serde_json::Value> = String::from_utf8_lossy(&mapped.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["t"], 0.9);
+    assert_eq!(lines[1]["f"], 1.0);
+    assert!(lines[0]["judgment_id"].is_string());
+
+    let inputs = dir.join("inputs.jsonl");
+    fs::write(&inputs, INPUTS).unwrap();
+    let unknown = otp(&["fuse", "--operator", "median", inputs.to_str().unwrap()]);
+    assert_eq!(unknown.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&unknown.stderr).contains("'median'"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -15,6 +15,8 @@ fn create_base_params(id: &str, mapper_type: MapperType) -> BaseMapperParams {
         mapper_type,
         description: Some(format!("Test mapper for {}", id)),
         metadata: None,
+        input_path: None,
+        deprecated: false,
     }
 }
 
@@ -448,3 +450,165 @@ fn test_provenance_chain_integrity() {
     assert_eq!(provenance.source_id, "provenance-test");
     assert!(!provenance.timestamp.is_empty()); // Timestamp should not be empty
 }
+
+#[test]
+fn test_mappers_with_json_event_document() {
+    let event = serde_json::json!({
+        "account": {"health_factor": 1.8},
+        "kyc": {"status": "VERIFIED"},
+        "tls": {"valid": true}
+    });
+
+    let mut health_base = create_base_params("json-health-factor", MapperType::Numerical);
+    health_base.input_path = Some("/account/health_factor".to_string());
+    let health_mapper = NumericalMapper::new(NumericalParams {
+        base: health_base,
+        falsity_point: 1.0,
+        indeterminacy_point: 1.5,
+        truth_point: 2.0,
+        clamp_to_range: Some(true),
+    })
+    .unwrap();
+
+    let mut mappings = HashMap::new();
+    mappings.insert(
+        "VERIFIED".to_string(),
+        JudgmentData {
+            T: 0.9,
+            I: 0.1,
+            F: 0.0,
+        },
+    );
+    let mut kyc_base = create_base_params("json-kyc", MapperType::Categorical);
+    kyc_base.input_path = Some("/kyc/status".to_string());
+    let kyc_mapper = CategoricalMapper::new(CategoricalParams {
+        base: kyc_base,
+        mappings,
+        default_judgment: None,
+    })
+    .unwrap();
+
+    let mut tls_base = create_base_params("json-tls", MapperType::Boolean);
+    tls_base.input_path = Some("/tls/valid".to_string());
+    let tls_mapper = BooleanMapper::new(BooleanParams {
+        base: tls_base,
+        true_map: JudgmentData {
+            T: 0.9,
+            I: 0.1,
+            F: 0.0,
+        },
+        false_map: JudgmentData {
+            T: 0.0,
+            I: 0.1,
+            F: 0.9,
+        },
+    })
+    .unwrap();
+
+    let health_judgment = health_mapper.apply_json(&event).unwrap();
+    let kyc_judgment = kyc_mapper.apply_json(&event).unwrap();
+    let tls_judgment = tls_mapper.apply_json(&event).unwrap();
+
+    assert!(health_judgment.t > 0.5);
+    assert_eq!(kyc_judgment.t, 0.9);
+    assert_eq!(tls_judgment.t, 0.9);
+
+    // A pointer that does not resolve is reported as an input error
+    let result = health_mapper.apply_json(&serde_json::json!({"account": {}}));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_registry_export_import_round_trip() {
+    use opentrustprotocol::mapper::registry::DefaultMapperRegistry;
+    use opentrustprotocol::{MapperParams, MapperRegistry};
+
+    let source = DefaultMapperRegistry::new();
+    source
+        .register(Box::new(
+            NumericalMapper::new(NumericalParams {
+                base: create_base_params("round-trip-numerical", MapperType::Numerical),
+                falsity_point: 1.0,
+                indeterminacy_point: 1.5,
+                truth_point: 3.0,
+                clamp_to_range: Some(false),
+            })
+            .unwrap(),
+        ))
+        .unwrap();
+    source
+        .register(Box::new(
+            BooleanMapper::new(BooleanParams {
+                base: create_base_params("round-trip-boolean", MapperType::Boolean),
+                true_map: JudgmentData {
+                    T: 0.9,
+                    I: 0.1,
+                    F: 0.0,
+                },
+                false_map: JudgmentData {
+                    T: 0.0,
+                    I: 0.1,
+                    F: 0.9,
+                },
+            })
+            .unwrap(),
+        ))
+        .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("otp-registry-round-trip-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    opentrustprotocol::save_mapper_dir(&dir, &source.export()).unwrap();
+
+    let target = DefaultMapperRegistry::new();
+    assert_eq!(target.import_dir(&dir).unwrap(), 2);
+    assert_eq!(target.export(), source.export());
+
+    // Importing the same definitions again is rejected without partial registration
+    let exported: Vec<MapperParams> = source.export();
+    assert!(target.import(exported).is_err());
+    assert_eq!(target.list().len(), 2);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_mapper_errors_are_structured() {
+    use opentrustprotocol::{create_judgment, OpenTrustError};
+
+    // Conservation errors keep the offending components
+    match create_judgment(0.6, 0.5, 0.1, vec![]) {
+        Err(OpenTrustError::ConservationViolation { t, i, f, sum }) => {
+            assert_eq!((t, i, f), (0.6, 0.5, 0.1));
+            assert!((sum - 1.2).abs() < 1e-10);
+        }
+        other => panic!("Expected ConservationViolation, got {:?}", other),
+    }
+
+    let mut mappings = HashMap::new();
+    mappings.insert(
+        "KNOWN".to_string(),
+        JudgmentData {
+            T: 1.0,
+            I: 0.0,
+            F: 0.0,
+        },
+    );
+    let mapper = CategoricalMapper::new(CategoricalParams {
+        base: create_base_params("structured-errors", MapperType::Categorical),
+        mappings,
+        default_judgment: None,
+    })
+    .unwrap();
+
+    match mapper.apply("UNKNOWN") {
+        Err(OpenTrustError::UnknownCategory { category }) => assert_eq!(category, "UNKNOWN"),
+        other => panic!("Expected UnknownCategory, got {:?}", other),
+    }
+    match mapper.apply_json(&serde_json::json!(42)) {
+        Err(OpenTrustError::InputType { expected, actual }) => {
+            assert_eq!(expected, "string");
+            assert_eq!(actual, "number");
+        }
+        other => panic!("Expected InputType, got {:?}", other),
+    }
+}
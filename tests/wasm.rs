@@ -0,0 +1,67 @@
+//! Headless browser tests for the JavaScript bindings
+//!
+//! Run with `wasm-pack test --headless --firefox --features wasm`
+//! (or `--chrome`, or `--node`).
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use opentrustprotocol::wasm::{
+    apply_mapper, cawa, generate_judgment_id, pessimistic, verify_seal_with_inputs,
+    WasmMapperRegistry,
+};
+use opentrustprotocol::{create_timestamp, NeutrosophicJudgment};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SSL_MAPPER: &str = r#"{"mapper_type":"boolean","params":{"id":"ssl","version":"1.0.0","mapper_type":"Boolean","true_map":{"T":0.9,"I":0.1,"F":0.0},"false_map":{"T":0.0,"I":0.0,"F":1.0}}}"#;
+
+fn inputs_json() -> String {
+    let a = NeutrosophicJudgment::new(
+        0.8,
+        0.2,
+        0.0,
+        vec![("sensor1".to_string(), create_timestamp())],
+    )
+    .unwrap();
+    let b = NeutrosophicJudgment::new(
+        0.6,
+        0.3,
+        0.1,
+        vec![("sensor2".to_string(), create_timestamp())],
+    )
+    .unwrap();
+    serde_json::to_string(&[a, b]).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn verifies_seals_and_detects_tampering() {
+    let inputs = inputs_json();
+    let fused = cawa(&inputs, vec![0.6, 0.4]).unwrap();
+    assert!(verify_seal_with_inputs(&fused, &inputs, None).unwrap());
+
+    assert!(!verify_seal_with_inputs(&fused, &inputs, Some(vec![0.5, 0.5])).unwrap());
+
+    let pessimistic = pessimistic(&inputs).unwrap();
+    assert!(verify_seal_with_inputs(&pessimistic, &inputs, None).unwrap());
+    assert_eq!(generate_judgment_id(&fused).unwrap().len(), 64);
+}
+
+#[wasm_bindgen_test]
+fn clock_works_without_system_time() {
+    let seconds: i64 = create_timestamp().parse().unwrap();
+    assert!(seconds > 1_600_000_000);
+}
+
+#[wasm_bindgen_test]
+fn applies_mappers_to_javascript_values() {
+    let judgment = apply_mapper(SSL_MAPPER, JsValue::from_bool(true)).unwrap();
+    assert!(judgment.contains("\"t\":0.9"));
+
+    let registry = WasmMapperRegistry::new();
+    registry.register(SSL_MAPPER).unwrap();
+    assert!(registry.apply("ssl", JsValue::from_str("no")).is_ok());
+    assert!(registry.apply("missing", JsValue::from_bool(true)).is_err());
+    assert!(cawa("[", vec![]).is_err());
+}